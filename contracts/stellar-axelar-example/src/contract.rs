@@ -136,6 +136,7 @@ impl AxelarExampleInterface for AxelarExample {
                 &destination_address,
                 &message,
                 &caller,
+                &caller,
                 &gas_token,
                 &Bytes::new(env),
             );
@@ -132,6 +132,7 @@ fn gmp_example() {
             destination_address.clone(),
             payload.clone(),
             &user,
+            &user,
             gas_token.clone(),
             &Bytes::new(&env)
         ),
@@ -31,6 +31,7 @@ impl AxelarGasServiceInterface for AxelarGasService {
         destination_address: String,
         payload: Bytes,
         spender: Address,
+        refund_address: Address,
         token: Token,
         metadata: Bytes,
     ) -> Result<(), ContractError> {
@@ -50,6 +51,7 @@ impl AxelarGasServiceInterface for AxelarGasService {
             destination_address,
             payload_hash: env.crypto().keccak256(&payload).into(),
             spender,
+            refund_address,
             token,
             metadata,
         }
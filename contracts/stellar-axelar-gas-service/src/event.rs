@@ -8,6 +8,7 @@ pub struct GasPaidEvent {
     pub destination_address: String,
     pub payload_hash: BytesN<32>,
     pub spender: Address,
+    pub refund_address: Address,
     pub token: Token,
     #[data]
     pub metadata: Bytes,
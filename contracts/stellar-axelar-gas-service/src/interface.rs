@@ -17,7 +17,8 @@ pub trait AxelarGasServiceInterface: OperatableInterface {
     /// * `destination_chain` - The destination chain for the message.
     /// * `destination_address` - The destination contract address for the message.
     /// * `payload` - The payload data associated with the message.
-    /// * `spender` - The address of the spender paying for the gas. Might differ from the `sender`. Excess gas will be refunded to this address.
+    /// * `spender` - The address of the spender paying for the gas. Might differ from the `sender`.
+    /// * `refund_address` - The address excess gas will be refunded to. Might differ from the `spender`.
     /// * `token` - The token used to pay for the gas, including the address and amount.
     /// * `metadata` - Additional metadata associated with the gas payment.
     ///
@@ -33,6 +34,7 @@ pub trait AxelarGasServiceInterface: OperatableInterface {
         destination_address: String,
         payload: Bytes,
         spender: Address,
+        refund_address: Address,
         token: Token,
         metadata: Bytes,
     ) -> Result<(), ContractError>;
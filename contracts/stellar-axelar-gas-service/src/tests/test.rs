@@ -91,6 +91,7 @@ fn pay_gas_fails_with_zero_amount() {
             &destination_address,
             &payload,
             &spender,
+            &spender,
             &token,
             &Bytes::new(&env),
         ),
@@ -129,6 +130,7 @@ fn pay_gas_fails_with_insufficient_user_balance() {
             destination_address,
             payload,
             spender,
+            spender,
             token,
             Bytes::new(&env)
         ),
@@ -141,6 +143,7 @@ fn pay_gas_fails_with_insufficient_user_balance() {
         &destination_address,
         &payload,
         &spender,
+        &spender,
         &token,
         &Bytes::new(&env),
     );
@@ -171,6 +174,7 @@ fn pay_gas() {
             destination_address,
             payload,
             spender,
+            spender,
             token,
             Bytes::new(&env)
         ),
@@ -183,6 +187,7 @@ fn pay_gas() {
         &destination_address,
         &payload,
         &spender,
+        &spender,
         &token,
         &Bytes::new(&env),
     );
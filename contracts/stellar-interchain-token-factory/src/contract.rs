@@ -0,0 +1,323 @@
+use soroban_token_sdk::metadata::TokenMetadata;
+use stellar_axelar_std::types::Token;
+use stellar_axelar_std::{
+    contract, contractimpl, ensure, interfaces, soroban_sdk, token, Address, Bytes, BytesN, Env,
+    Ownable, String, Upgradable, Vec,
+};
+use stellar_interchain_token_service::types::GasPayment;
+use stellar_interchain_token_service::InterchainTokenServiceClient;
+
+use crate::error::ContractError;
+use crate::interface::InterchainTokenFactoryInterface;
+use crate::{salt, storage};
+
+#[contract]
+#[derive(Ownable, Upgradable)]
+#[migratable]
+pub struct InterchainTokenFactory;
+
+#[contractimpl]
+impl InterchainTokenFactory {
+    pub fn __constructor(env: &Env, owner: Address, interchain_token_service: Address) {
+        interfaces::set_owner(env, &owner);
+        storage::set_interchain_token_service(env, &interchain_token_service);
+    }
+}
+
+#[contractimpl]
+impl InterchainTokenFactoryInterface for InterchainTokenFactory {
+    fn interchain_token_service(env: &Env) -> Address {
+        storage::interchain_token_service(env)
+    }
+
+    fn interchain_token_deploy_salt(env: &Env, deployer: Address, salt: BytesN<32>) -> BytesN<32> {
+        let chain_name_hash = salt::chain_name_hash(env, Self::its(env).chain_name());
+
+        salt::interchain_token_deploy_salt(env, chain_name_hash, deployer, salt)
+    }
+
+    fn interchain_token_id(env: &Env, deployer: Address, salt: BytesN<32>) -> BytesN<32> {
+        Self::its(env).interchain_token_id(
+            &env.current_contract_address(),
+            &Self::interchain_token_deploy_salt(env, deployer, salt),
+        )
+    }
+
+    fn canonical_interchain_token_id(env: &Env, token_address: Address) -> BytesN<32> {
+        Self::its(env).canonical_interchain_token_id(&token_address)
+    }
+
+    fn deploy_interchain_token(
+        env: &Env,
+        deployer: Address,
+        salt: BytesN<32>,
+        token_metadata: TokenMetadata,
+        initial_supply: i128,
+        minter: Option<Address>,
+    ) -> Result<BytesN<32>, ContractError> {
+        deployer.require_auth();
+
+        // The token ID is only compatible with the EVM factory if the service derives it for this factory
+        ensure!(
+            Self::its(env).interchain_token_factory() == Some(env.current_contract_address()),
+            ContractError::NotInterchainTokenFactory
+        );
+
+        // The factory cannot act as a minter on behalf of the deployer
+        ensure!(
+            minter.as_ref() != Some(&env.current_contract_address()),
+            ContractError::InvalidMinter
+        );
+
+        let its = Self::its(env);
+        let token_id = its.deploy_interchain_token(
+            &env.current_contract_address(),
+            &Self::interchain_token_deploy_salt(env, deployer.clone(), salt),
+            &token_metadata,
+            &initial_supply,
+            &minter,
+        );
+
+        // The interchain token service mints the initial supply to the factory as the caller
+        if initial_supply > 0 {
+            token::TokenClient::new(env, &its.registered_token_address(&token_id)).transfer(
+                &env.current_contract_address(),
+                &deployer,
+                &initial_supply,
+            );
+        }
+
+        Ok(token_id)
+    }
+
+    fn deploy_remote_interchain_token(
+        env: &Env,
+        deployer: Address,
+        salt: BytesN<32>,
+        destination_chain: String,
+        gas_token: Option<Token>,
+    ) -> Result<BytesN<32>, ContractError> {
+        deployer.require_auth();
+
+        let its = Self::its(env);
+        let deploy_salt = Self::interchain_token_deploy_salt(env, deployer.clone(), salt);
+
+        let token_id = gas_token.map_or_else(
+            || {
+                its.deploy_remote_interchain_token(
+                    &env.current_contract_address(),
+                    &deploy_salt,
+                    &destination_chain,
+                    &None,
+                )
+            },
+            |gas_token| {
+                its.deploy_remote_token_with_gas(
+                    &env.current_contract_address(),
+                    &deploy_salt,
+                    &destination_chain,
+                    &None,
+                    &Self::gas_payment(env, deployer, gas_token),
+                )
+            },
+        );
+
+        Ok(token_id)
+    }
+
+    fn approve_remote_minter(
+        env: &Env,
+        minter: Address,
+        deployer: Address,
+        salt: BytesN<32>,
+        destination_chain: String,
+        destination_minter: Bytes,
+    ) -> Result<(), ContractError> {
+        minter.require_auth();
+
+        Self::its(env).approve_remote_minter(
+            &minter,
+            &env.current_contract_address(),
+            &Self::interchain_token_deploy_salt(env, deployer, salt),
+            &destination_chain,
+            &destination_minter,
+        );
+
+        Ok(())
+    }
+
+    fn deploy_remote_token_with_minter(
+        env: &Env,
+        deployer: Address,
+        salt: BytesN<32>,
+        minter: Address,
+        destination_chain: String,
+        destination_minter: Bytes,
+        gas_token: Option<Token>,
+    ) -> Result<BytesN<32>, ContractError> {
+        deployer.require_auth();
+
+        let its = Self::its(env);
+        let deploy_salt = Self::interchain_token_deploy_salt(env, deployer.clone(), salt);
+
+        let token_id = match gas_token {
+            Some(gas_token) => its.deploy_remote_token_with_gas(
+                &env.current_contract_address(),
+                &deploy_salt,
+                &destination_chain,
+                &Some((minter, destination_minter)),
+                &Self::gas_payment(env, deployer, gas_token),
+            ),
+            None => its.deploy_remote_token_with_minter(
+                &env.current_contract_address(),
+                &deploy_salt,
+                &minter,
+                &destination_chain,
+                &destination_minter,
+                &None,
+            ),
+        };
+
+        Ok(token_id)
+    }
+
+    fn register_canonical_token(
+        env: &Env,
+        token_address: Address,
+        spender: Address,
+        destinations: Vec<(String, Option<Token>)>,
+    ) -> Result<BytesN<32>, ContractError> {
+        let its = Self::its(env);
+        let token_id = its.canonical_interchain_token_id(&token_address);
+
+        if !its.is_token_registered(&token_id) {
+            its.register_canonical_token(&token_address);
+        }
+
+        if !destinations.is_empty() {
+            spender.require_auth();
+
+            its.deploy_remote_canonical_tokens(&token_address, &spender, &destinations);
+        }
+
+        Ok(token_id)
+    }
+
+    fn add_flow_limiter(
+        env: &Env,
+        deployer: Address,
+        salt: BytesN<32>,
+        flow_limiter: Address,
+    ) -> Result<(), ContractError> {
+        deployer.require_auth();
+
+        Self::its(env).add_flow_limiter(
+            &env.current_contract_address(),
+            &Self::interchain_token_id(env, deployer, salt),
+            &flow_limiter,
+        );
+
+        Ok(())
+    }
+
+    fn remove_flow_limiter(
+        env: &Env,
+        deployer: Address,
+        salt: BytesN<32>,
+        flow_limiter: Address,
+    ) -> Result<(), ContractError> {
+        deployer.require_auth();
+
+        Self::its(env).remove_flow_limiter(
+            &env.current_contract_address(),
+            &Self::interchain_token_id(env, deployer, salt),
+            &flow_limiter,
+        );
+
+        Ok(())
+    }
+
+    fn set_compliance_hook(
+        env: &Env,
+        deployer: Address,
+        salt: BytesN<32>,
+        hook: Option<Address>,
+    ) -> Result<(), ContractError> {
+        deployer.require_auth();
+
+        Self::its(env).set_compliance_hook(
+            &env.current_contract_address(),
+            &Self::interchain_token_id(env, deployer, salt),
+            &hook,
+        );
+
+        Ok(())
+    }
+
+    fn set_remote_token_decimals(
+        env: &Env,
+        deployer: Address,
+        salt: BytesN<32>,
+        chain: String,
+        decimals: u32,
+    ) -> Result<(), ContractError> {
+        deployer.require_auth();
+
+        Self::its(env).set_remote_token_decimals(
+            &env.current_contract_address(),
+            &Self::interchain_token_id(env, deployer, salt),
+            &chain,
+            &decimals,
+        );
+
+        Ok(())
+    }
+
+    fn set_decimal_scaling(
+        env: &Env,
+        deployer: Address,
+        salt: BytesN<32>,
+        enabled: bool,
+    ) -> Result<(), ContractError> {
+        deployer.require_auth();
+
+        Self::its(env).set_decimal_scaling(
+            &env.current_contract_address(),
+            &Self::interchain_token_id(env, deployer, salt),
+            &enabled,
+        );
+
+        Ok(())
+    }
+
+    fn deregister_token(
+        env: &Env,
+        deployer: Address,
+        salt: BytesN<32>,
+    ) -> Result<(), ContractError> {
+        deployer.require_auth();
+
+        let its = Self::its(env);
+        let token_id = Self::interchain_token_id(env, deployer, salt);
+
+        its.deregister_token(&env.current_contract_address(), &token_id);
+
+        Ok(())
+    }
+}
+
+impl InterchainTokenFactory {
+    fn its(env: &Env) -> InterchainTokenServiceClient<'_> {
+        InterchainTokenServiceClient::new(env, &storage::interchain_token_service(env))
+    }
+
+    /// The factory is the caller of remote deployments, so the gas is paid by the `deployer` explicitly.
+    fn gas_payment(env: &Env, deployer: Address, gas_token: Token) -> GasPayment {
+        GasPayment {
+            spender: deployer.clone(),
+            token: gas_token,
+            metadata: Bytes::new(env),
+            refund_address: deployer,
+        }
+    }
+}
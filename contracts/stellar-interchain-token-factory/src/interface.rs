@@ -0,0 +1,276 @@
+use soroban_token_sdk::metadata::TokenMetadata;
+use stellar_axelar_std::interfaces::{OwnableInterface, UpgradableInterface};
+use stellar_axelar_std::types::Token;
+use stellar_axelar_std::{contractclient, soroban_sdk, Address, Bytes, BytesN, Env, String, Vec};
+
+use crate::error::ContractError;
+
+#[contractclient(name = "InterchainTokenFactoryClient")]
+pub trait InterchainTokenFactoryInterface: OwnableInterface + UpgradableInterface {
+    /// Returns the address of the interchain token service.
+    fn interchain_token_service(env: &Env) -> Address;
+
+    /// Returns the factory-scoped salt under which the factory deploys the interchain token
+    /// of the `deployer` with the given `salt`, derived as by `interchainTokenDeploySalt` of the EVM factory.
+    fn interchain_token_deploy_salt(env: &Env, deployer: Address, salt: BytesN<32>) -> BytesN<32>;
+
+    /// Returns the token ID of the interchain token deployed through the factory by the `deployer` with the given `salt`,
+    /// derived as by `interchainTokenId` of the EVM factory.
+    fn interchain_token_id(env: &Env, deployer: Address, salt: BytesN<32>) -> BytesN<32>;
+
+    /// Returns the token ID of the canonical interchain token registered for the `token_address`.
+    ///
+    /// This is the same token ID as when registering the token with the interchain token service directly.
+    fn canonical_interchain_token_id(env: &Env, token_address: Address) -> BytesN<32>;
+
+    /// Deploys a new interchain token on the current chain with specified metadata and optional
+    /// initial supply. If initial supply is provided, it is minted to the deployer.
+    ///
+    /// # Arguments
+    /// - `deployer`: Address of the deployer of the interchain token.
+    /// - `salt`: A 32-byte salt, unique per deployer.
+    /// - `token_metadata`: Metadata for the new token (name, symbol, decimals).
+    /// - `initial_supply`: Initial amount to mint to the deployer, if greater than 0.
+    /// - `minter`: Optional address that will have a minter role for the deployed interchain token.
+    ///
+    /// # Returns
+    /// - `Ok(BytesN<32>)`: Returns the token ID.
+    ///
+    /// # Errors
+    /// - [`ContractError::NotInterchainTokenFactory`]: If the factory is not set as the interchain token factory
+    ///   of the interchain token service.
+    /// - [`ContractError::InvalidMinter`]: If the minter is the factory.
+    /// - Any error propagated from the interchain token service.
+    ///
+    /// # Authorization
+    /// - The `deployer` must authorize.
+    fn deploy_interchain_token(
+        env: &Env,
+        deployer: Address,
+        salt: BytesN<32>,
+        token_metadata: TokenMetadata,
+        initial_supply: i128,
+        minter: Option<Address>,
+    ) -> Result<BytesN<32>, ContractError>;
+
+    /// Initiates the deployment of an interchain token, deployed through the factory, to a specified destination chain.
+    ///
+    /// # Arguments
+    /// - `deployer`: Address of the deployer of the interchain token.
+    /// - `salt`: The salt used to deploy the interchain token.
+    /// - `destination_chain`: The name of the destination chain where the token will be deployed.
+    /// - `gas_token`: An optional gas token used to pay for the gas cost of the cross-chain call.
+    ///
+    /// # Returns
+    /// - `Ok(BytesN<32>)`: Returns the token ID.
+    ///
+    /// # Errors
+    /// - Any error propagated from the interchain token service.
+    ///
+    /// # Authorization
+    /// - The `deployer` must authorize, including the payment of the `gas_token` to the gas service.
+    fn deploy_remote_interchain_token(
+        env: &Env,
+        deployer: Address,
+        salt: BytesN<32>,
+        destination_chain: String,
+        gas_token: Option<Token>,
+    ) -> Result<BytesN<32>, ContractError>;
+
+    /// Approves a destination minter for a remote deployment of an interchain token deployed through the factory.
+    ///
+    /// The approval is consumed by [`InterchainTokenFactoryInterface::deploy_remote_token_with_minter`].
+    ///
+    /// # Arguments
+    /// - `minter`: Address of a minter of the interchain token.
+    /// - `deployer`: Address of the deployer of the interchain token.
+    /// - `salt`: The salt used to deploy the interchain token.
+    /// - `destination_chain`: The name of the destination chain.
+    /// - `destination_minter`: The minter address on the destination chain, encoded as bytes.
+    ///
+    /// # Errors
+    /// - Any error propagated from the interchain token service.
+    ///
+    /// # Authorization
+    /// - The `minter` must authorize.
+    fn approve_remote_minter(
+        env: &Env,
+        minter: Address,
+        deployer: Address,
+        salt: BytesN<32>,
+        destination_chain: String,
+        destination_minter: Bytes,
+    ) -> Result<(), ContractError>;
+
+    /// Initiates the deployment of an interchain token, deployed through the factory, to a specified destination chain,
+    /// with a minter on the destination chain.
+    ///
+    /// # Arguments
+    /// - `deployer`: Address of the deployer of the interchain token.
+    /// - `salt`: The salt used to deploy the interchain token.
+    /// - `minter`: Address of a minter of the interchain token, which approved the `destination_minter`.
+    /// - `destination_chain`: The name of the destination chain where the token will be deployed.
+    /// - `destination_minter`: The minter address on the destination chain, encoded as bytes.
+    /// - `gas_token`: An optional gas token used to pay for the gas cost of the cross-chain call.
+    ///
+    /// # Returns
+    /// - `Ok(BytesN<32>)`: Returns the token ID.
+    ///
+    /// # Errors
+    /// - Any error propagated from the interchain token service.
+    ///
+    /// # Authorization
+    /// - The `deployer` must authorize, including the payment of the `gas_token` to the gas service.
+    /// - The `minter` must have approved the `destination_minter` via [`InterchainTokenFactoryInterface::approve_remote_minter`].
+    fn deploy_remote_token_with_minter(
+        env: &Env,
+        deployer: Address,
+        salt: BytesN<32>,
+        minter: Address,
+        destination_chain: String,
+        destination_minter: Bytes,
+        gas_token: Option<Token>,
+    ) -> Result<BytesN<32>, ContractError>;
+
+    /// Registers a canonical token as an interchain token, if it is not registered yet,
+    /// and deploys it to the destination chains in the same call.
+    ///
+    /// # Arguments
+    /// - `token_address`: The address of the canonical token.
+    /// - `spender`: The spender of the cross-chain gas.
+    /// - `destinations`: The destination chains, each with an optional gas token used to pay for the deployment.
+    ///
+    /// # Returns
+    /// - `Ok(BytesN<32>)`: Returns the token ID.
+    ///
+    /// # Errors
+    /// - Any error propagated from the interchain token service.
+    ///
+    /// # Authorization
+    /// - The `spender` must authorize, if there are any `destinations`.
+    fn register_canonical_token(
+        env: &Env,
+        token_address: Address,
+        spender: Address,
+        destinations: Vec<(String, Option<Token>)>,
+    ) -> Result<BytesN<32>, ContractError>;
+
+    /// Grants the flow limiter role of an interchain token deployed through the factory to the specified address.
+    ///
+    /// The factory is the deployer of the token on the interchain token service, so it forwards the call on behalf of the `deployer`.
+    ///
+    /// # Arguments
+    /// - `deployer`: Address of the deployer of the interchain token.
+    /// - `salt`: The salt used to deploy the interchain token.
+    /// - `flow_limiter`: The address receiving the flow limiter role.
+    ///
+    /// # Errors
+    /// - Any error propagated from the interchain token service.
+    ///
+    /// # Authorization
+    /// - The `deployer` must authorize.
+    fn add_flow_limiter(
+        env: &Env,
+        deployer: Address,
+        salt: BytesN<32>,
+        flow_limiter: Address,
+    ) -> Result<(), ContractError>;
+
+    /// Revokes the flow limiter role of an interchain token deployed through the factory from the specified address.
+    ///
+    /// # Arguments
+    /// - `deployer`: Address of the deployer of the interchain token.
+    /// - `salt`: The salt used to deploy the interchain token.
+    /// - `flow_limiter`: The address losing the flow limiter role.
+    ///
+    /// # Errors
+    /// - Any error propagated from the interchain token service.
+    ///
+    /// # Authorization
+    /// - The `deployer` must authorize.
+    fn remove_flow_limiter(
+        env: &Env,
+        deployer: Address,
+        salt: BytesN<32>,
+        flow_limiter: Address,
+    ) -> Result<(), ContractError>;
+
+    /// Sets or removes the compliance hook of an interchain token deployed through the factory.
+    ///
+    /// # Arguments
+    /// - `deployer`: Address of the deployer of the interchain token.
+    /// - `salt`: The salt used to deploy the interchain token.
+    /// - `hook`: The address of the compliance hook contract, or `None` to remove it.
+    ///
+    /// # Errors
+    /// - Any error propagated from the interchain token service.
+    ///
+    /// # Authorization
+    /// - The `deployer` must authorize.
+    fn set_compliance_hook(
+        env: &Env,
+        deployer: Address,
+        salt: BytesN<32>,
+        hook: Option<Address>,
+    ) -> Result<(), ContractError>;
+
+    /// Sets the decimals of an interchain token deployed through the factory on the specified remote chain.
+    ///
+    /// # Arguments
+    /// - `deployer`: Address of the deployer of the interchain token.
+    /// - `salt`: The salt used to deploy the interchain token.
+    /// - `chain`: The name of the remote chain.
+    /// - `decimals`: The decimals of the token on the remote chain.
+    ///
+    /// # Errors
+    /// - Any error propagated from the interchain token service.
+    ///
+    /// # Authorization
+    /// - The `deployer` must authorize.
+    fn set_remote_token_decimals(
+        env: &Env,
+        deployer: Address,
+        salt: BytesN<32>,
+        chain: String,
+        decimals: u32,
+    ) -> Result<(), ContractError>;
+
+    /// Enables or disables decimal scaling for an interchain token deployed through the factory.
+    ///
+    /// # Arguments
+    /// - `deployer`: Address of the deployer of the interchain token.
+    /// - `salt`: The salt used to deploy the interchain token.
+    /// - `enabled`: Whether decimal scaling is enabled.
+    ///
+    /// # Errors
+    /// - Any error propagated from the interchain token service.
+    ///
+    /// # Authorization
+    /// - The `deployer` must authorize.
+    fn set_decimal_scaling(
+        env: &Env,
+        deployer: Address,
+        salt: BytesN<32>,
+        enabled: bool,
+    ) -> Result<(), ContractError>;
+
+    /// Deregisters an interchain token deployed through the factory from the interchain token service.
+    ///
+    /// The interchain token service only allows the token deployer to deregister tokens that have never been transferred.
+    /// The ownership of the token is not handed to the `deployer`, so no minters can be added to the token afterwards.
+    ///
+    /// # Arguments
+    /// - `deployer`: Address of the deployer of the interchain token.
+    /// - `salt`: The salt used to deploy the interchain token.
+    ///
+    /// # Errors
+    /// - Any error propagated from the interchain token service.
+    ///
+    /// # Authorization
+    /// - The `deployer` must authorize.
+    fn deregister_token(
+        env: &Env,
+        deployer: Address,
+        salt: BytesN<32>,
+    ) -> Result<(), ContractError>;
+}
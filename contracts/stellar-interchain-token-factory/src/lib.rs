@@ -0,0 +1,25 @@
+#![no_std]
+
+#[cfg(test)]
+extern crate std;
+
+pub mod error;
+
+mod interface;
+
+#[cfg(test)]
+mod tests;
+
+cfg_if::cfg_if! {
+    if #[cfg(all(feature = "library", not(test)))] {
+        pub use interface::{InterchainTokenFactoryClient, InterchainTokenFactoryInterface};
+    } else {
+        mod contract;
+        mod migrate;
+        mod salt;
+        mod storage;
+
+        pub use contract::{InterchainTokenFactory, InterchainTokenFactoryClient};
+        pub use interface::InterchainTokenFactoryInterface;
+    }
+}
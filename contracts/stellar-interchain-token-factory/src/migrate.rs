@@ -0,0 +1,17 @@
+use stellar_axelar_std::interfaces::CustomMigratableInterface;
+use stellar_axelar_std::Env;
+
+use crate::error::ContractError;
+use crate::InterchainTokenFactory;
+
+/// The factory has not been deployed before, so there are no legacy keys to migrate.
+pub mod legacy_storage {}
+
+impl CustomMigratableInterface for InterchainTokenFactory {
+    type MigrationData = ();
+    type Error = ContractError;
+
+    fn __migrate(_env: &Env, _migration_data: Self::MigrationData) -> Result<(), Self::Error> {
+        Ok(())
+    }
+}
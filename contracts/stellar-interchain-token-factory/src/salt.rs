@@ -0,0 +1,48 @@
+extern crate alloc;
+
+use alloc::vec;
+
+use alloy_primitives::{Bytes as AlloyBytes, FixedBytes};
+use alloy_sol_types::SolValue;
+use stellar_axelar_std::address::AddressExt;
+use stellar_axelar_std::{Address, Bytes, BytesN, Env, String};
+
+const PREFIX_INTERCHAIN_TOKEN_SALT: &str = "interchain-token-salt";
+
+/// Hashes the `chain_name` like the `chainNameHash` of the EVM factory, i.e. the hash of the raw name.
+pub fn chain_name_hash(env: &Env, chain_name: String) -> BytesN<32> {
+    let mut chain_name_bytes = vec![0u8; chain_name.len() as usize];
+    chain_name.copy_into_slice(&mut chain_name_bytes);
+
+    env.crypto()
+        .keccak256(&Bytes::from_slice(env, &chain_name_bytes))
+        .into()
+}
+
+/// Scopes the `salt` of a `deployer` to the factory, like `interchainTokenDeploySalt` of the EVM factory.
+///
+/// The hashed prefix, chain name hash, deployer and salt are `abi.encode`d, with the deployer encoded
+/// as the bytes of its string representation, as Stellar addresses are encoded in ITS messages.
+pub fn interchain_token_deploy_salt(
+    env: &Env,
+    chain_name_hash: BytesN<32>,
+    deployer: Address,
+    salt: BytesN<32>,
+) -> BytesN<32> {
+    let prefix_hash = env.crypto().keccak256(&Bytes::from_slice(
+        env,
+        PREFIX_INTERCHAIN_TOKEN_SALT.as_bytes(),
+    ));
+
+    let encoded = (
+        FixedBytes::<32>::new(prefix_hash.to_array()),
+        FixedBytes::<32>::new(chain_name_hash.to_array()),
+        AlloyBytes::copy_from_slice(&deployer.to_raw_bytes()),
+        FixedBytes::<32>::new(salt.to_array()),
+    )
+        .abi_encode_params();
+
+    env.crypto()
+        .keccak256(&Bytes::from_slice(env, &encoded))
+        .into()
+}
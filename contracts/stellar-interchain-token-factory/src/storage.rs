@@ -0,0 +1,9 @@
+use stellar_axelar_std::{contractstorage, soroban_sdk, Address};
+
+#[contractstorage]
+#[derive(Clone, Debug)]
+enum DataKey {
+    #[instance]
+    #[value(Address)]
+    InterchainTokenService,
+}
@@ -0,0 +1,60 @@
+use stellar_axelar_std::testutils::Address as _;
+use stellar_axelar_std::{assert_auth, assert_contract_err, Address, Env};
+
+use crate::error::ContractError;
+use crate::{InterchainTokenFactory, InterchainTokenFactoryClient};
+use migrating::MigratingDataKey;
+
+mod migrating {
+    #![allow(non_camel_case_types)]
+
+    use stellar_axelar_std::{contracttype, soroban_sdk};
+
+    /// Mirrors the data key used by the upgradable interface to track an ongoing migration.
+    #[contracttype]
+    pub enum MigratingDataKey {
+        Interfaces_Migrating,
+    }
+}
+
+fn setup(env: &Env) -> InterchainTokenFactoryClient<'_> {
+    let factory = env.register(
+        InterchainTokenFactory,
+        (Address::generate(env), Address::generate(env)),
+    );
+
+    InterchainTokenFactoryClient::new(env, &factory)
+}
+
+/// Puts the contract in the migrating state, as after an upgrade.
+fn start_migration(env: &Env, client: &InterchainTokenFactoryClient) {
+    env.as_contract(&client.address, || {
+        env.storage()
+            .instance()
+            .set(&MigratingDataKey::Interfaces_Migrating, &());
+    });
+}
+
+#[test]
+fn migrate_succeeds() {
+    let env = Env::default();
+    let client = setup(&env);
+    let interchain_token_service = client.interchain_token_service();
+
+    start_migration(&env, &client);
+
+    assert_auth!(client.owner(), client.migrate(&()));
+
+    assert_eq!(client.interchain_token_service(), interchain_token_service);
+}
+
+#[test]
+fn migrate_fails_if_not_migrating() {
+    let env = Env::default();
+    let client = setup(&env);
+
+    assert_contract_err!(
+        client.mock_all_auths().try_migrate(&()),
+        ContractError::MigrationNotAllowed
+    );
+}
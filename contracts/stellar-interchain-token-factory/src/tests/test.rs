@@ -0,0 +1,511 @@
+use soroban_token_sdk::metadata::TokenMetadata;
+use stellar_axelar_gas_service::testutils::{setup_gas_service, setup_gas_token};
+use stellar_axelar_gas_service::AxelarGasServiceClient;
+use stellar_axelar_gateway::testutils::setup_gateway;
+use stellar_axelar_std::interfaces::OwnableClient;
+use stellar_axelar_std::testutils::Address as _;
+use stellar_axelar_std::token::{StellarAssetClient, TokenClient};
+use stellar_axelar_std::{
+    assert_auth, assert_auth_err, assert_contract_err, vec, Address, Bytes, BytesN, Env, String,
+};
+use stellar_interchain_token_service::testutils::setup_its;
+use stellar_interchain_token_service::InterchainTokenServiceClient;
+
+use crate::error::ContractError;
+use crate::{InterchainTokenFactory, InterchainTokenFactoryClient};
+
+const DESTINATION_CHAIN: &str = "ethereum";
+
+struct TestConfig<'a> {
+    env: Env,
+    its: InterchainTokenServiceClient<'a>,
+    gas_service: AxelarGasServiceClient<'a>,
+    factory: InterchainTokenFactoryClient<'a>,
+}
+
+fn setup<'a>() -> TestConfig<'a> {
+    let env = Env::default();
+
+    let (_, gateway) = setup_gateway(&env, 0, 5);
+    let gas_service = setup_gas_service(&env);
+    let its = setup_its(&env, &gateway, &gas_service, None);
+    its.mock_all_auths()
+        .set_trusted_chain(&String::from_str(&env, DESTINATION_CHAIN));
+
+    let factory = env.register(
+        InterchainTokenFactory,
+        (Address::generate(&env), its.address.clone()),
+    );
+    let factory = InterchainTokenFactoryClient::new(&env, &factory);
+    its.mock_all_auths()
+        .set_interchain_token_factory(&factory.address);
+
+    TestConfig {
+        env,
+        its,
+        gas_service,
+        factory,
+    }
+}
+
+fn token_metadata(env: &Env) -> TokenMetadata {
+    TokenMetadata {
+        name: String::from_str(env, "Test"),
+        symbol: String::from_str(env, "TEST"),
+        decimal: 7,
+    }
+}
+
+#[test]
+fn register_factory_succeeds() {
+    let TestConfig { its, factory, .. } = setup();
+
+    assert_eq!(factory.interchain_token_service(), its.address);
+}
+
+#[test]
+fn interchain_token_deploy_salt_is_scoped_to_deployer() {
+    let TestConfig { env, factory, .. } = setup();
+    let salt = BytesN::<32>::from_array(&env, &[1; 32]);
+
+    let deployer = Address::generate(&env);
+    let other_deployer = Address::generate(&env);
+
+    assert_ne!(
+        factory.interchain_token_deploy_salt(&deployer, &salt),
+        factory.interchain_token_deploy_salt(&other_deployer, &salt)
+    );
+    assert_ne!(factory.interchain_token_deploy_salt(&deployer, &salt), salt);
+}
+
+#[test]
+fn interchain_token_id_matches_its_token_id_of_factory() {
+    let TestConfig {
+        env, its, factory, ..
+    } = setup();
+    let deployer = Address::generate(&env);
+    let salt = BytesN::<32>::from_array(&env, &[1; 32]);
+
+    let deploy_salt = factory.interchain_token_deploy_salt(&deployer, &salt);
+
+    assert_eq!(
+        factory.interchain_token_id(&deployer, &salt),
+        its.interchain_token_id(&factory.address, &deploy_salt)
+    );
+    assert_ne!(
+        factory.interchain_token_id(&deployer, &salt),
+        its.interchain_token_id(&deployer, &salt)
+    );
+}
+
+// The expected values are derived as by `interchainTokenDeploySalt` and `interchainTokenId` of the EVM factory
+#[test]
+fn interchain_token_id_is_compatible_with_evm_factory() {
+    let TestConfig { env, factory, .. } = setup();
+    let deployer = Address::from_str(
+        &env,
+        "GDUITDF2LI3R5HM4KYRLLNRLEWKYBFVZVOEB6HSL7EOW2KO2LD6V4GPM",
+    );
+    let salt = BytesN::<32>::from_array(&env, &[1; 32]);
+
+    assert_eq!(
+        hex::encode(
+            factory
+                .interchain_token_deploy_salt(&deployer, &salt)
+                .to_array()
+        ),
+        "e34094834030287145942f1ef48453ffd4594b4224bdab42c0758b70484b5b85"
+    );
+    assert_eq!(
+        hex::encode(factory.interchain_token_id(&deployer, &salt).to_array()),
+        "e6ff5af2477ff6665fd3b2bf49d9fe2861ccf14df20606d04b7cd4034288f893"
+    );
+}
+
+#[test]
+fn deploy_interchain_token_succeeds() {
+    let TestConfig {
+        env, its, factory, ..
+    } = setup();
+    let deployer = Address::generate(&env);
+    let minter = Address::generate(&env);
+    let salt = BytesN::<32>::from_array(&env, &[1; 32]);
+    let initial_supply = 100;
+
+    let token_id = factory.mock_all_auths().deploy_interchain_token(
+        &deployer,
+        &salt,
+        &token_metadata(&env),
+        &initial_supply,
+        &Some(minter),
+    );
+
+    assert_eq!(token_id, factory.interchain_token_id(&deployer, &salt));
+    assert_eq!(its.token_deployer(&token_id), Some(factory.address.clone()));
+
+    let token = TokenClient::new(&env, &its.registered_token_address(&token_id));
+    assert_eq!(token.balance(&deployer), initial_supply);
+    assert_eq!(token.balance(&factory.address), 0);
+}
+
+#[test]
+fn deploy_interchain_token_fails_without_deployer_auth() {
+    let TestConfig { env, factory, .. } = setup();
+    let deployer = Address::generate(&env);
+    let salt = BytesN::<32>::from_array(&env, &[1; 32]);
+
+    assert!(factory
+        .try_deploy_interchain_token(&deployer, &salt, &token_metadata(&env), &100, &None)
+        .is_err());
+}
+
+#[test]
+fn deploy_interchain_token_fails_if_not_interchain_token_factory() {
+    let TestConfig { env, its, .. } = setup();
+    let factory = env.register(
+        InterchainTokenFactory,
+        (Address::generate(&env), its.address),
+    );
+    let factory = InterchainTokenFactoryClient::new(&env, &factory);
+
+    assert_contract_err!(
+        factory.mock_all_auths().try_deploy_interchain_token(
+            &Address::generate(&env),
+            &BytesN::<32>::from_array(&env, &[1; 32]),
+            &token_metadata(&env),
+            &100,
+            &None,
+        ),
+        ContractError::NotInterchainTokenFactory
+    );
+}
+
+#[test]
+fn deploy_interchain_token_fails_with_factory_as_minter() {
+    let TestConfig { env, factory, .. } = setup();
+    let deployer = Address::generate(&env);
+    let salt = BytesN::<32>::from_array(&env, &[1; 32]);
+
+    assert_contract_err!(
+        factory.mock_all_auths().try_deploy_interchain_token(
+            &deployer,
+            &salt,
+            &token_metadata(&env),
+            &0,
+            &Some(factory.address.clone()),
+        ),
+        ContractError::InvalidMinter
+    );
+}
+
+#[test]
+fn deploy_interchain_token_with_same_salt_by_different_deployers_succeeds() {
+    let TestConfig { env, factory, .. } = setup();
+    let salt = BytesN::<32>::from_array(&env, &[1; 32]);
+
+    let token_id = factory.mock_all_auths().deploy_interchain_token(
+        &Address::generate(&env),
+        &salt,
+        &token_metadata(&env),
+        &100,
+        &None,
+    );
+    let other_token_id = factory.mock_all_auths().deploy_interchain_token(
+        &Address::generate(&env),
+        &salt,
+        &token_metadata(&env),
+        &100,
+        &None,
+    );
+
+    assert_ne!(token_id, other_token_id);
+}
+
+#[test]
+fn deploy_remote_interchain_token_succeeds() {
+    let TestConfig {
+        env,
+        factory,
+        gas_service,
+        ..
+    } = setup();
+    let deployer = Address::generate(&env);
+    let salt = BytesN::<32>::from_array(&env, &[1; 32]);
+    let gas_token = setup_gas_token(&env, &deployer);
+
+    let token_id = factory.mock_all_auths().deploy_interchain_token(
+        &deployer,
+        &salt,
+        &token_metadata(&env),
+        &100,
+        &None,
+    );
+
+    let deployed_token_id = factory.mock_all_auths().deploy_remote_interchain_token(
+        &deployer,
+        &salt,
+        &String::from_str(&env, DESTINATION_CHAIN),
+        &Some(gas_token.clone()),
+    );
+
+    assert_eq!(deployed_token_id, token_id);
+
+    let gas_token_client = gas_token.client(&env);
+    assert_eq!(gas_token_client.balance(&deployer), 0);
+    assert_eq!(gas_token_client.balance(&factory.address), 0);
+    assert_eq!(
+        gas_token_client.balance(&gas_service.address),
+        gas_token.amount
+    );
+}
+
+#[test]
+fn deploy_remote_token_with_minter_succeeds() {
+    let TestConfig {
+        env,
+        factory,
+        gas_service,
+        ..
+    } = setup();
+    let deployer = Address::generate(&env);
+    let minter = Address::generate(&env);
+    let salt = BytesN::<32>::from_array(&env, &[1; 32]);
+    let destination_chain = String::from_str(&env, DESTINATION_CHAIN);
+    let destination_minter = Bytes::from_array(&env, &[1; 20]);
+    let gas_token = setup_gas_token(&env, &deployer);
+
+    let token_id = factory.mock_all_auths().deploy_interchain_token(
+        &deployer,
+        &salt,
+        &token_metadata(&env),
+        &0,
+        &Some(minter.clone()),
+    );
+
+    factory.mock_all_auths().approve_remote_minter(
+        &minter,
+        &deployer,
+        &salt,
+        &destination_chain,
+        &destination_minter,
+    );
+    assert_eq!(env.auths()[0].0, minter);
+
+    let deployed_token_id = factory.mock_all_auths().deploy_remote_token_with_minter(
+        &deployer,
+        &salt,
+        &minter,
+        &destination_chain,
+        &destination_minter,
+        &Some(gas_token.clone()),
+    );
+
+    assert_eq!(deployed_token_id, token_id);
+    assert_eq!(
+        gas_token.client(&env).balance(&gas_service.address),
+        gas_token.amount
+    );
+}
+
+#[test]
+fn deploy_remote_token_with_minter_fails_without_approval() {
+    let TestConfig { env, factory, .. } = setup();
+    let deployer = Address::generate(&env);
+    let minter = Address::generate(&env);
+    let salt = BytesN::<32>::from_array(&env, &[1; 32]);
+
+    factory.mock_all_auths().deploy_interchain_token(
+        &deployer,
+        &salt,
+        &token_metadata(&env),
+        &0,
+        &Some(minter.clone()),
+    );
+
+    assert!(factory
+        .mock_all_auths()
+        .try_deploy_remote_token_with_minter(
+            &deployer,
+            &salt,
+            &minter,
+            &String::from_str(&env, DESTINATION_CHAIN),
+            &Bytes::from_array(&env, &[1; 20]),
+            &None,
+        )
+        .is_err());
+}
+
+#[test]
+fn register_canonical_token_succeeds() {
+    let TestConfig {
+        env,
+        its,
+        factory,
+        gas_service,
+    } = setup();
+    let spender = Address::generate(&env);
+    let gas_token = setup_gas_token(&env, &spender);
+    let token_address = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+
+    let token_id = factory.mock_all_auths().register_canonical_token(
+        &token_address,
+        &spender,
+        &vec![
+            &env,
+            (
+                String::from_str(&env, DESTINATION_CHAIN),
+                Some(gas_token.clone()),
+            ),
+        ],
+    );
+
+    assert_eq!(token_id, its.canonical_interchain_token_id(&token_address));
+    assert_eq!(
+        token_id,
+        factory.canonical_interchain_token_id(&token_address)
+    );
+    assert_eq!(its.registered_token_address(&token_id), token_address);
+    assert_eq!(
+        gas_token.client(&env).balance(&gas_service.address),
+        gas_token.amount
+    );
+}
+
+#[test]
+fn register_canonical_token_succeeds_if_already_registered() {
+    let TestConfig {
+        env, its, factory, ..
+    } = setup();
+    let spender = Address::generate(&env);
+    let token_address = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    StellarAssetClient::new(&env, &token_address)
+        .mock_all_auths()
+        .mint(&spender, &1);
+
+    let token_id = its.register_canonical_token(&token_address);
+
+    assert_eq!(
+        factory.register_canonical_token(&token_address, &spender, &vec![&env]),
+        token_id
+    );
+}
+
+fn deploy_token(
+    env: &Env,
+    factory: &InterchainTokenFactoryClient,
+) -> (Address, BytesN<32>, BytesN<32>) {
+    let deployer = Address::generate(env);
+    let salt = BytesN::<32>::from_array(env, &[1; 32]);
+
+    let token_id = factory.mock_all_auths().deploy_interchain_token(
+        &deployer,
+        &salt,
+        &token_metadata(env),
+        &100,
+        &None,
+    );
+
+    (deployer, salt, token_id)
+}
+
+#[test]
+fn add_flow_limiter_succeeds() {
+    let TestConfig {
+        env, its, factory, ..
+    } = setup();
+    let (deployer, salt, token_id) = deploy_token(&env, &factory);
+    let flow_limiter = Address::generate(&env);
+
+    assert_auth!(
+        deployer,
+        factory.add_flow_limiter(&deployer, &salt, &flow_limiter)
+    );
+
+    assert!(its.is_flow_limiter(&token_id, &flow_limiter));
+
+    assert_auth!(
+        deployer,
+        factory.remove_flow_limiter(&deployer, &salt, &flow_limiter)
+    );
+
+    assert!(!its.is_flow_limiter(&token_id, &flow_limiter));
+}
+
+#[test]
+fn add_flow_limiter_fails_with_invalid_auth() {
+    let TestConfig { env, factory, .. } = setup();
+    let (deployer, salt, _) = deploy_token(&env, &factory);
+    let flow_limiter = Address::generate(&env);
+
+    assert_auth_err!(
+        Address::generate(&env),
+        factory.add_flow_limiter(&deployer, &salt, &flow_limiter)
+    );
+}
+
+#[test]
+fn add_flow_limiter_fails_for_token_of_other_deployer() {
+    let TestConfig { env, factory, .. } = setup();
+    let (_, salt, _) = deploy_token(&env, &factory);
+
+    assert!(factory
+        .mock_all_auths()
+        .try_add_flow_limiter(&Address::generate(&env), &salt, &Address::generate(&env))
+        .is_err());
+}
+
+#[test]
+fn set_compliance_hook_succeeds() {
+    let TestConfig {
+        env, its, factory, ..
+    } = setup();
+    let (deployer, salt, token_id) = deploy_token(&env, &factory);
+    let hook = Some(Address::generate(&env));
+
+    assert_auth!(
+        deployer,
+        factory.set_compliance_hook(&deployer, &salt, &hook)
+    );
+
+    assert_eq!(its.compliance_hook(&token_id), hook);
+}
+
+#[test]
+fn set_decimal_scaling_succeeds() {
+    let TestConfig {
+        env, its, factory, ..
+    } = setup();
+    let (deployer, salt, token_id) = deploy_token(&env, &factory);
+    let chain = String::from_str(&env, DESTINATION_CHAIN);
+
+    assert_auth!(
+        deployer,
+        factory.set_remote_token_decimals(&deployer, &salt, &chain, &18_u32)
+    );
+    assert_auth!(
+        deployer,
+        factory.set_decimal_scaling(&deployer, &salt, &true)
+    );
+
+    assert_eq!(its.remote_token_decimals(&token_id, &chain), Some(18));
+    assert!(its.is_decimal_scaling_enabled(&token_id));
+}
+
+#[test]
+fn deregister_token_succeeds() {
+    let TestConfig {
+        env, its, factory, ..
+    } = setup();
+    let (deployer, salt, token_id) = deploy_token(&env, &factory);
+
+    let token_address = its.registered_token_address(&token_id);
+
+    assert_auth!(deployer, factory.deregister_token(&deployer, &salt));
+
+    assert!(its.is_token_deregistered(&token_id));
+    assert_ne!(OwnableClient::new(&env, &token_address).owner(), deployer);
+}
@@ -18,6 +18,7 @@ sol! {
         ReceiveFromHub,
         LinkToken,
         RegisterTokenMetadata,
+        UpdateTokenMetadata, // note, this case is not supported by the ITS hub yet
     }
 
     struct InterchainTransfer {
@@ -56,6 +57,13 @@ sol! {
         uint8 decimals;
     }
 
+    struct UpdateTokenMetadata {
+        uint256 messageType;
+        bytes32 tokenId;
+        string name;
+        string symbol;
+    }
+
     struct LinkToken {
         uint256 messageType;
         bytes32 tokenId;
@@ -123,6 +131,17 @@ impl Message {
                 }
                 .abi_encode_params()
             }
+            Self::UpdateTokenMetadata(types::UpdateTokenMetadata {
+                token_id,
+                name,
+                symbol,
+            }) => UpdateTokenMetadata {
+                messageType: MessageType::UpdateTokenMetadata.into(),
+                tokenId: FixedBytes::<32>::new(token_id.into()),
+                name: to_std_string(name)?,
+                symbol: to_std_string(symbol)?,
+            }
+            .abi_encode_params(),
         };
         Ok(Bytes::from_slice(env, &msg))
     }
@@ -175,6 +194,16 @@ impl Message {
                     params: from_vec(env, decoded.params.as_ref()),
                 }))
             }
+            MessageType::UpdateTokenMetadata => {
+                let decoded = UpdateTokenMetadata::abi_decode_params(&payload, true)
+                    .map_err(|_| ContractError::AbiDecodeFailed)?;
+
+                Ok(Self::UpdateTokenMetadata(types::UpdateTokenMetadata {
+                    token_id: BytesN::from_array(env, &decoded.tokenId.into()),
+                    name: String::from_str(env, &decoded.name),
+                    symbol: String::from_str(env, &decoded.symbol),
+                }))
+            }
             _ => Err(ContractError::InvalidMessageType),
         }
     }
@@ -754,6 +783,50 @@ mod tests {
         }
     }
 
+    #[test]
+    fn update_token_metadata_encode_decode() {
+        let env = Env::default();
+        let remote_chain = String::from_str(&env, "chain");
+
+        let cases = vec![
+            types::HubMessage::SendToHub {
+                destination_chain: remote_chain.clone(),
+                message: types::Message::UpdateTokenMetadata(types::UpdateTokenMetadata {
+                    token_id: BytesN::from_array(&env, &[0u8; 32]),
+                    name: String::from_str(&env, "t"),
+                    symbol: String::from_str(&env, "T"),
+                }),
+            },
+            types::HubMessage::ReceiveFromHub {
+                source_chain: remote_chain,
+                message: types::Message::UpdateTokenMetadata(types::UpdateTokenMetadata {
+                    token_id: BytesN::from_array(&env, &[1u8; 32]),
+                    name: String::from_str(&env, "USD Coin (Stellar)"),
+                    symbol: String::from_str(&env, "USDC"),
+                }),
+            },
+        ];
+
+        let encoded: Vec<_> = cases
+            .iter()
+            .map(|original| {
+                hex::encode(
+                    assert_ok!(original.clone().abi_encode(&env))
+                        .to_buffer::<1024>()
+                        .as_slice(),
+                )
+            })
+            .collect();
+
+        goldie::assert_json!(encoded);
+
+        for original in cases {
+            let encoded = assert_ok!(original.clone().abi_encode(&env));
+            let decoded = HubMessage::abi_decode(&env, &encoded);
+            assert_eq!(original, decoded.unwrap());
+        }
+    }
+
     #[test]
     fn to_token_manager_type_succeeds_valid_types() {
         let mint_burn_from_type: Uint<256, 4> = Uint::from(1u32);
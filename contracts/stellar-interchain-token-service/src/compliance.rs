@@ -0,0 +1,35 @@
+//! ComplianceHook
+//!
+//! This is the interface of a compliance hook contract that is consulted by the interchain token service
+//! before tokens are sent to or received from another chain, e.g. to block sanctioned addresses.
+
+use stellar_axelar_std::{contractclient, soroban_sdk, Address, Bytes, BytesN, Env, String};
+
+/// Interface for a compliance hook contract.
+///
+/// A hook vetoes a transfer by returning an error or panicking, in which case the interchain token service
+/// rejects the transfer with [`ContractError::TransferBlocked`](crate::error::ContractError::TransferBlocked).
+#[contractclient(name = "ComplianceHookClient")]
+pub trait ComplianceHookInterface {
+    /// Checks an outbound interchain transfer of `amount` tokens from the `sender` on this chain
+    /// to the `destination_address` on the `destination_chain`.
+    fn check_outbound_transfer(
+        env: &Env,
+        token_id: BytesN<32>,
+        sender: Address,
+        destination_chain: String,
+        destination_address: Bytes,
+        amount: i128,
+    ) -> Result<(), stellar_axelar_std::Error>;
+
+    /// Checks an inbound interchain transfer of `amount` tokens from the `source_address` on the `source_chain`
+    /// to the `recipient` on this chain.
+    fn check_inbound_transfer(
+        env: &Env,
+        token_id: BytesN<32>,
+        source_chain: String,
+        source_address: Bytes,
+        recipient: Address,
+        amount: i128,
+    ) -> Result<(), stellar_axelar_std::Error>;
+}
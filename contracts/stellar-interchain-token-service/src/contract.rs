@@ -1,42 +1,61 @@
 use soroban_token_sdk::metadata::TokenMetadata;
 use stellar_axelar_gas_service::AxelarGasServiceClient;
-use stellar_axelar_gateway::executable::{AxelarExecutableInterface, CustomAxelarExecutable};
+use stellar_axelar_gateway::executable::{
+    validate_message, AxelarExecutableInterface, CustomAxelarExecutable,
+};
 use stellar_axelar_gateway::AxelarGatewayMessagingClient;
 use stellar_axelar_std::address::AddressExt;
 use stellar_axelar_std::events::Event;
-use stellar_axelar_std::token::StellarAssetClient;
+use stellar_axelar_std::token::{StellarAssetClient, TokenClient};
 use stellar_axelar_std::types::Token;
-use stellar_axelar_std::xdr::ToXdr;
+use stellar_axelar_std::xdr::{ScErrorCode, ScErrorType, ToXdr};
 use stellar_axelar_std::{
-    contract, contractimpl, ensure, interfaces, only_operator, only_owner, soroban_sdk, vec,
-    when_not_paused, Address, AxelarExecutable, Bytes, BytesN, Env, IntoVal, Operatable, Ownable,
-    Pausable, String, Symbol, Upgradable, Val,
+    contract, contractimpl, ensure, interfaces, only_operator, only_owner, soroban_sdk,
+    when_not_paused, Address, AxelarExecutable, Bytes, BytesN, Env, Error, InvokeError,
+    MuxedAddress, Operatable, Ownable, Pausable, String, Upgradable, Vec,
 };
+use stellar_interchain_token::InterchainTokenClient;
 use stellar_token_manager::TokenManagerClient;
 use token_id::UnregisteredTokenId;
 
+use crate::compliance::ComplianceHookClient;
 use crate::error::ContractError;
 use crate::event::{
-    InterchainTokenDeploymentStartedEvent, InterchainTransferReceivedEvent,
-    InterchainTransferSentEvent, LinkTokenReceivedEvent, LinkTokenStartedEvent,
-    TokenMetadataRegisteredEvent, TrustedChainRemovedEvent, TrustedChainSetEvent,
+    ComplianceHookSetEvent, ContractExecutionFailedEvent, ExpressExecutedEvent,
+    ExpressExecutionFulfilledEvent, GlobalComplianceHookSetEvent,
+    InterchainTokenDeploymentStartedEvent, InterchainTokenFactorySetEvent,
+    InterchainTransferReceivedEvent, InterchainTransferRecoveredEvent,
+    InterchainTransferReturnedEvent, InterchainTransferSentEvent, LinkTokenReceivedEvent,
+    LinkTokenStartedEvent, MintershipRenouncedEvent, MintershipTransferredEvent,
+    NonRevertingExecutionSetEvent, RecoveryAddressSetEvent, RemoteMinterApprovedEvent,
+    RemoteMinterRevokedEvent, TokenDeregisteredEvent, TokenExitedEvent,
+    TokenManagerTypeMigratedEvent, TokenMetadataOverrideSetEvent, TokenMetadataRegisteredEvent,
+    TokenMetadataUpdateReceivedEvent, TokenMetadataUpdateStartedEvent, TokenPausedEvent,
+    TokenUnpausedEvent, TrustedChainRemovedEvent, TrustedChainSetEvent,
 };
+use crate::executable::InterchainTokenExecutableClient;
 use crate::flow_limit::FlowDirection;
 use crate::interface::InterchainTokenServiceInterface;
-use crate::storage::{self, TokenIdConfigValue};
+use crate::storage::{self, ExpressExecutionValue, TokenIdConfigValue};
 use crate::token_manager::TokenManagerClientExt;
 use crate::token_metadata::TokenMetadataExt;
 use crate::types::{
-    DeployInterchainToken, HubMessage, InterchainTransfer, LinkToken, Message,
-    RegisterTokenMetadata, TokenManagerType,
+    DeployInterchainToken, GasPayment, HubMessage, InterchainTransfer, LinkToken, LockedBalance,
+    Message, RegisterTokenMetadata, TokenInfo, TokenManagerType, TokenMetadataOverride,
+    TransferRecovery, TrustedChainConfig, UpdateTokenMetadata,
+};
+use crate::{
+    decimals, deployer, express, fee, flow_limit, migrate, registry, token_handler, token_id,
+    token_metadata, trusted_chain,
 };
-use crate::{deployer, flow_limit, token_handler, token_id, token_metadata};
 
 const ITS_HUB_CHAIN_NAME: &str = "axelar";
-const EXECUTE_WITH_INTERCHAIN_TOKEN: &str = "execute_with_interchain_token";
+/// The maximum size in bytes of the data sent with [`InterchainTokenServiceInterface::call_contract_with_token`].
+const MAX_DATA_SIZE: u32 = 8 * 1024;
 
 #[contract]
 #[derive(Operatable, Ownable, Pausable, Upgradable, AxelarExecutable)]
+#[migratable]
 pub struct InterchainTokenService;
 
 #[contractimpl]
@@ -120,6 +139,7 @@ impl InterchainTokenServiceInterface for InterchainTokenService {
             ContractError::TrustedChainNotSet
         );
 
+        trusted_chain::retain_trusted_chain_config(env, chain.clone());
         storage::remove_trusted_chain_status(env, chain.clone());
 
         TrustedChainRemovedEvent { chain }.emit(env);
@@ -127,7 +147,39 @@ impl InterchainTokenServiceInterface for InterchainTokenService {
         Ok(())
     }
 
+    fn trusted_chain_config(env: &Env, chain: String) -> Result<TrustedChainConfig, ContractError> {
+        trusted_chain::trusted_chain_config(env, chain)
+    }
+
+    #[only_operator]
+    fn set_trusted_chain_config(
+        env: &Env,
+        chain: String,
+        config: TrustedChainConfig,
+    ) -> Result<(), ContractError> {
+        trusted_chain::set_trusted_chain_config(env, chain, config)
+    }
+
+    fn max_transfer_amount(env: &Env, token_id: BytesN<32>, chain: String) -> Option<i128> {
+        trusted_chain::max_transfer_amount(env, token_id, chain)
+    }
+
+    #[only_operator]
+    fn set_max_transfer_amount(
+        env: &Env,
+        token_id: BytesN<32>,
+        chain: String,
+        max_transfer_amount: Option<i128>,
+    ) -> Result<(), ContractError> {
+        trusted_chain::set_max_transfer_amount(env, token_id, chain, max_transfer_amount)
+    }
+
     fn interchain_token_id(env: &Env, deployer: Address, salt: BytesN<32>) -> BytesN<32> {
+        // Tokens of the factory are derived like on EVM chains, from the factory-scoped salt alone
+        if storage::try_interchain_token_factory(env).as_ref() == Some(&deployer) {
+            return token_id::factory_interchain_token_id(env, salt);
+        }
+
         token_id::interchain_token_id(env, Self::chain_name_hash(env), deployer, salt)
     }
 
@@ -139,6 +191,24 @@ impl InterchainTokenServiceInterface for InterchainTokenService {
         token_id::linked_token_id(env, Self::chain_name_hash(env), deployer, salt)
     }
 
+    fn interchain_token_factory(env: &Env) -> Option<Address> {
+        storage::try_interchain_token_factory(env)
+    }
+
+    #[only_owner]
+    fn set_interchain_token_factory(env: &Env, factory: Address) -> Result<(), ContractError> {
+        ensure!(
+            storage::try_interchain_token_factory(env).is_none(),
+            ContractError::InterchainTokenFactoryAlreadySet
+        );
+
+        storage::set_interchain_token_factory(env, &factory);
+
+        InterchainTokenFactorySetEvent { factory }.emit(env);
+
+        Ok(())
+    }
+
     fn interchain_token_address(env: &Env, token_id: BytesN<32>) -> Address {
         deployer::interchain_token_address(env, token_id)
     }
@@ -159,6 +229,96 @@ impl InterchainTokenServiceInterface for InterchainTokenService {
         storage::token_id_config(env, token_id).token_manager_type
     }
 
+    fn token_info(env: &Env, token_id: BytesN<32>) -> Result<TokenInfo, ContractError> {
+        let TokenIdConfigValue {
+            token_address,
+            token_manager,
+            token_manager_type,
+        } = Self::token_id_config(env, token_id.clone())?;
+
+        let metadata =
+            token_metadata::token_metadata(env, &token_address, &Self::native_token_address(env))?;
+
+        Ok(TokenInfo {
+            token_address,
+            token_manager,
+            token_manager_type,
+            metadata,
+            flow_limit: flow_limit::flow_limit(env, token_id.clone()),
+            flow_in_amount: flow_limit::flow_in_amount(env, token_id.clone()),
+            flow_out_amount: flow_limit::flow_out_amount(env, token_id),
+        })
+    }
+
+    fn registered_token_info(
+        env: &Env,
+        token_id: BytesN<32>,
+    ) -> Result<Option<TokenInfo>, ContractError> {
+        match Self::token_info(env, token_id) {
+            Ok(token_info) => Ok(Some(token_info)),
+            Err(ContractError::InvalidTokenId) => Ok(None),
+            Err(err) => Err(err),
+        }
+    }
+
+    fn registered_token_count(env: &Env) -> u32 {
+        registry::registered_token_count(env)
+    }
+
+    fn registered_token_ids(env: &Env, start: u32, limit: u32) -> Vec<BytesN<32>> {
+        registry::registered_token_ids(env, start, limit)
+    }
+
+    #[allow_during_migration]
+    #[only_owner]
+    fn backfill_registry(env: &Env, token_ids: Vec<BytesN<32>>) -> Result<(), ContractError> {
+        ensure!(
+            interfaces::is_migrating(env),
+            ContractError::MigrationNotAllowed
+        );
+
+        migrate::backfill_registry(env, token_ids)
+    }
+
+    fn token_id_count_by_address(env: &Env, token_address: Address) -> u32 {
+        registry::token_id_count_by_address(env, token_address)
+    }
+
+    fn token_ids_by_address(
+        env: &Env,
+        token_address: Address,
+        start: u32,
+        limit: u32,
+    ) -> Vec<BytesN<32>> {
+        registry::token_ids_by_address(env, token_address, start, limit)
+    }
+
+    fn locked_amount(env: &Env, token_id: BytesN<32>) -> i128 {
+        token_handler::locked_amount(env, token_id)
+    }
+
+    fn locked_balance(env: &Env, token_id: BytesN<32>) -> Result<LockedBalance, ContractError> {
+        let TokenIdConfigValue {
+            token_address,
+            token_manager,
+            token_manager_type,
+        } = Self::token_id_config(env, token_id.clone())?;
+
+        ensure!(
+            token_manager_type == TokenManagerType::LockUnlock,
+            ContractError::InvalidTokenManagerType
+        );
+
+        let locked_amount = token_handler::locked_amount(env, token_id);
+        let token_manager_balance = TokenClient::new(env, &token_address).balance(&token_manager);
+
+        Ok(LockedBalance {
+            locked_amount,
+            token_manager_balance,
+            surplus: token_manager_balance - locked_amount,
+        })
+    }
+
     fn flow_limit(env: &Env, token_id: BytesN<32>) -> Option<i128> {
         flow_limit::flow_limit(env, token_id)
     }
@@ -180,6 +340,214 @@ impl InterchainTokenServiceInterface for InterchainTokenService {
         flow_limit::set_flow_limit(env, token_id, flow_limit)
     }
 
+    fn set_flow_limits(
+        env: &Env,
+        caller: Address,
+        flow_limits: Vec<(BytesN<32>, Option<i128>)>,
+    ) -> Result<(), ContractError> {
+        caller.require_auth();
+
+        let is_operator = caller == Self::operator(env);
+
+        for (token_id, flow_limit) in flow_limits {
+            ensure!(
+                is_operator || flow_limit::is_flow_limiter(env, token_id.clone(), caller.clone()),
+                ContractError::NotFlowLimiter
+            );
+
+            flow_limit::set_flow_limit(env, token_id, flow_limit)?;
+        }
+
+        Ok(())
+    }
+
+    fn token_deployer(env: &Env, token_id: BytesN<32>) -> Option<Address> {
+        storage::try_token_deployer(env, token_id)
+    }
+
+    fn is_flow_limiter(env: &Env, token_id: BytesN<32>, flow_limiter: Address) -> bool {
+        flow_limit::is_flow_limiter(env, token_id, flow_limiter)
+    }
+
+    fn add_flow_limiter(
+        env: &Env,
+        caller: Address,
+        token_id: BytesN<32>,
+        flow_limiter: Address,
+    ) -> Result<(), ContractError> {
+        Self::ensure_operator_or_token_deployer(env, &caller, token_id.clone())?;
+
+        flow_limit::add_flow_limiter(env, token_id, flow_limiter)
+    }
+
+    fn remove_flow_limiter(
+        env: &Env,
+        caller: Address,
+        token_id: BytesN<32>,
+        flow_limiter: Address,
+    ) -> Result<(), ContractError> {
+        Self::ensure_operator_or_token_deployer(env, &caller, token_id.clone())?;
+
+        flow_limit::remove_flow_limiter(env, token_id, flow_limiter)
+    }
+
+    fn chain_flow_limit(env: &Env, token_id: BytesN<32>, chain: String) -> Option<i128> {
+        flow_limit::chain_flow_limit(env, token_id, chain)
+    }
+
+    fn flow_out_amount_for_chain(env: &Env, token_id: BytesN<32>, chain: String) -> i128 {
+        flow_limit::flow_out_amount_for_chain(env, token_id, chain)
+    }
+
+    fn flow_in_amount_for_chain(env: &Env, token_id: BytesN<32>, chain: String) -> i128 {
+        flow_limit::flow_in_amount_for_chain(env, token_id, chain)
+    }
+
+    #[only_operator]
+    fn set_chain_flow_limit(
+        env: &Env,
+        token_id: BytesN<32>,
+        chain: String,
+        flow_limit: Option<i128>,
+    ) -> Result<(), ContractError> {
+        flow_limit::set_chain_flow_limit(env, token_id, chain, flow_limit)
+    }
+
+    fn remote_token_decimals(env: &Env, token_id: BytesN<32>, chain: String) -> Option<u32> {
+        decimals::remote_token_decimals(env, token_id, chain)
+    }
+
+    fn set_remote_token_decimals(
+        env: &Env,
+        caller: Address,
+        token_id: BytesN<32>,
+        chain: String,
+        decimals: u32,
+    ) -> Result<(), ContractError> {
+        Self::ensure_operator_or_token_deployer(env, &caller, token_id.clone())?;
+
+        decimals::set_remote_token_decimals(env, token_id, chain, decimals)
+    }
+
+    fn is_decimal_scaling_enabled(env: &Env, token_id: BytesN<32>) -> bool {
+        decimals::is_decimal_scaling_enabled(env, token_id)
+    }
+
+    fn set_decimal_scaling(
+        env: &Env,
+        caller: Address,
+        token_id: BytesN<32>,
+        enabled: bool,
+    ) -> Result<(), ContractError> {
+        Self::ensure_operator_or_token_deployer(env, &caller, token_id.clone())?;
+
+        decimals::set_decimal_scaling(env, token_id, enabled);
+
+        Ok(())
+    }
+
+    fn global_compliance_hook(env: &Env) -> Option<Address> {
+        storage::try_global_compliance_hook(env)
+    }
+
+    #[only_operator]
+    fn set_global_compliance_hook(env: &Env, hook: Option<Address>) {
+        match hook {
+            Some(ref hook) => storage::set_global_compliance_hook(env, hook),
+            None => storage::remove_global_compliance_hook(env),
+        }
+
+        GlobalComplianceHookSetEvent { hook }.emit(env);
+    }
+
+    fn compliance_hook(env: &Env, token_id: BytesN<32>) -> Option<Address> {
+        storage::try_compliance_hook(env, token_id)
+    }
+
+    fn set_compliance_hook(
+        env: &Env,
+        caller: Address,
+        token_id: BytesN<32>,
+        hook: Option<Address>,
+    ) -> Result<(), ContractError> {
+        Self::ensure_operator_or_token_deployer(env, &caller, token_id.clone())?;
+
+        Self::store_compliance_hook(env, token_id.clone(), hook.as_ref());
+
+        ComplianceHookSetEvent { token_id, hook }.emit(env);
+
+        Ok(())
+    }
+
+    fn fee_receiver(env: &Env) -> Option<Address> {
+        fee::fee_receiver(env)
+    }
+
+    #[only_owner]
+    fn set_fee_receiver(env: &Env, receiver: Option<Address>) {
+        fee::set_fee_receiver(env, receiver);
+    }
+
+    fn default_fee_rate(env: &Env) -> u32 {
+        fee::default_fee_rate(env)
+    }
+
+    #[only_owner]
+    fn set_default_fee_rate(env: &Env, rate: u32) -> Result<(), ContractError> {
+        fee::set_default_fee_rate(env, rate)
+    }
+
+    fn fee_rate(env: &Env, token_id: BytesN<32>) -> Option<u32> {
+        fee::fee_rate(env, token_id)
+    }
+
+    #[only_owner]
+    fn set_fee_rate(
+        env: &Env,
+        token_id: BytesN<32>,
+        rate: Option<u32>,
+    ) -> Result<(), ContractError> {
+        fee::set_fee_rate(env, token_id, rate)
+    }
+
+    fn is_token_paused(env: &Env, token_id: BytesN<32>) -> bool {
+        storage::is_token_paused(env, token_id)
+    }
+
+    fn is_token_registered(env: &Env, token_id: BytesN<32>) -> bool {
+        storage::try_token_id_config(env, token_id).is_some()
+    }
+
+    fn is_token_deregistered(env: &Env, token_id: BytesN<32>) -> bool {
+        storage::is_token_deregistered(env, token_id)
+    }
+
+    #[only_operator]
+    fn pause_token(env: &Env, token_id: BytesN<32>) -> Result<(), ContractError> {
+        Self::token_id_config(env, token_id.clone())?;
+        Self::ensure_token_not_paused(env, token_id.clone())?;
+
+        storage::set_token_paused_status(env, token_id.clone());
+
+        TokenPausedEvent { token_id }.emit(env);
+
+        Ok(())
+    }
+
+    #[only_operator]
+    fn unpause_token(env: &Env, token_id: BytesN<32>) -> Result<(), ContractError> {
+        ensure!(
+            storage::is_token_paused(env, token_id.clone()),
+            ContractError::TokenNotPaused
+        );
+
+        storage::remove_token_paused_status(env, token_id.clone());
+
+        TokenUnpausedEvent { token_id }.emit(env);
+
+        Ok(())
+    }
+
     #[when_not_paused]
     fn deploy_interchain_token(
         env: &Env,
@@ -205,6 +573,8 @@ impl InterchainTokenServiceInterface for InterchainTokenService {
 
         let token_address = Self::deploy_token(env, unregistered_token_id, token_metadata, minter)?;
 
+        storage::set_token_deployer(env, token_id.clone(), &caller);
+
         if initial_supply > 0 {
             StellarAssetClient::new(env, &token_address).mint(&caller, &initial_supply);
         }
@@ -224,81 +594,325 @@ impl InterchainTokenServiceInterface for InterchainTokenService {
 
         let token_id = Self::interchain_token_id(env, caller.clone(), salt);
 
-        Self::deploy_remote_token(env, caller, token_id.clone(), destination_chain, gas_token)?;
+        Self::deploy_remote_token(
+            env,
+            token_id.clone(),
+            destination_chain,
+            None,
+            gas_token.map(|gas_token| Self::gas_payment(env, caller, gas_token)),
+        )?;
 
         Ok(token_id)
     }
 
     #[when_not_paused]
-    fn register_canonical_token(
+    fn deploy_remote_interchain_tokens(
         env: &Env,
-        token_address: Address,
+        caller: Address,
+        salt: BytesN<32>,
+        destinations: Vec<(String, Option<Token>)>,
     ) -> Result<BytesN<32>, ContractError> {
-        // Validates the token address and its associated token metadata
-        let _ =
-            token_metadata::token_metadata(env, &token_address, &Self::native_token_address(env))?;
-
-        let token_id = Self::canonical_interchain_token_id(env, token_address.clone());
+        caller.require_auth();
 
-        let unregistered_token_id = token_id::ensure_token_not_registered(env, token_id.clone())?;
+        let token_id = Self::interchain_token_id(env, caller.clone(), salt);
 
-        let _: Address = Self::deploy_token_manager(
-            env,
-            unregistered_token_id,
-            token_address,
-            TokenManagerType::LockUnlock,
-        );
+        Self::deploy_remote_tokens(env, caller, token_id.clone(), destinations)?;
 
         Ok(token_id)
     }
 
-    #[when_not_paused]
-    fn deploy_remote_canonical_token(
+    fn approve_remote_minter(
         env: &Env,
-        token_address: Address,
+        minter: Address,
+        deployer: Address,
+        salt: BytesN<32>,
         destination_chain: String,
-        spender: Address,
-        gas_token: Option<Token>,
-    ) -> Result<BytesN<32>, ContractError> {
-        spender.require_auth();
+        destination_minter: Bytes,
+    ) -> Result<(), ContractError> {
+        minter.require_auth();
 
-        let token_id = Self::canonical_interchain_token_id(env, token_address);
+        let token_id = Self::interchain_token_id(env, deployer, salt);
 
-        Self::deploy_remote_token(env, spender, token_id.clone(), destination_chain, gas_token)?;
+        let _ = Self::ensure_minter(env, token_id.clone(), &minter)?;
 
-        Ok(token_id)
+        storage::set_remote_minter_approval(
+            env,
+            minter.clone(),
+            token_id.clone(),
+            destination_chain.clone(),
+            &env.crypto().keccak256(&destination_minter).into(),
+        );
+
+        RemoteMinterApprovedEvent {
+            minter,
+            token_id,
+            destination_chain,
+            destination_minter,
+        }
+        .emit(env);
+
+        Ok(())
     }
 
-    #[when_not_paused]
-    fn register_token_metadata(
+    fn revoke_remote_minter(
         env: &Env,
-        token_address: Address,
-        spender: Address,
-        gas_token: Option<Token>,
+        minter: Address,
+        deployer: Address,
+        salt: BytesN<32>,
+        destination_chain: String,
     ) -> Result<(), ContractError> {
-        spender.require_auth();
+        minter.require_auth();
 
-        let token_metadata =
-            token_metadata::token_metadata(env, &token_address, &Self::native_token_address(env))?;
+        let token_id = Self::interchain_token_id(env, deployer, salt);
 
-        // RegisterTokenMetadata expects u8 decimals, but TokenMetadata uses u32.
-        // The unwrap() is safe because token_metadata validation ensures decimals <= 255.
-        let hub_message = HubMessage::RegisterTokenMetadata(RegisterTokenMetadata {
-            decimals: u8::try_from(token_metadata.decimal).unwrap(),
-            token_address: token_address.to_string_bytes(),
-        });
+        ensure!(
+            storage::has_remote_minter_approval(
+                env,
+                minter.clone(),
+                token_id.clone(),
+                destination_chain.clone()
+            ),
+            ContractError::RemoteMinterNotApproved
+        );
 
-        Self::send_to_hub(env, spender, hub_message, gas_token)?;
+        storage::remove_remote_minter_approval(
+            env,
+            minter.clone(),
+            token_id.clone(),
+            destination_chain.clone(),
+        );
 
-        TokenMetadataRegisteredEvent {
+        RemoteMinterRevokedEvent {
+            minter,
+            token_id,
+            destination_chain,
+        }
+        .emit(env);
+
+        Ok(())
+    }
+
+    #[when_not_paused]
+    fn deploy_remote_token_with_minter(
+        env: &Env,
+        caller: Address,
+        salt: BytesN<32>,
+        minter: Address,
+        destination_chain: String,
+        destination_minter: Bytes,
+        gas_token: Option<Token>,
+    ) -> Result<BytesN<32>, ContractError> {
+        caller.require_auth();
+
+        let token_id = Self::interchain_token_id(env, caller.clone(), salt);
+
+        Self::consume_remote_minter_approval(
+            env,
+            token_id.clone(),
+            minter,
+            destination_chain.clone(),
+            &destination_minter,
+        )?;
+
+        Self::deploy_remote_token(
+            env,
+            token_id.clone(),
+            destination_chain,
+            Some(destination_minter),
+            gas_token.map(|gas_token| Self::gas_payment(env, caller, gas_token)),
+        )?;
+
+        Ok(token_id)
+    }
+
+    #[when_not_paused]
+    fn deploy_remote_token_with_gas(
+        env: &Env,
+        caller: Address,
+        salt: BytesN<32>,
+        destination_chain: String,
+        destination_minter: Option<(Address, Bytes)>,
+        gas_payment: GasPayment,
+    ) -> Result<BytesN<32>, ContractError> {
+        caller.require_auth();
+
+        if gas_payment.spender != caller {
+            gas_payment.spender.require_auth();
+        }
+
+        let token_id = Self::interchain_token_id(env, caller, salt);
+
+        let destination_minter = match destination_minter {
+            Some((minter, destination_minter)) => {
+                Self::consume_remote_minter_approval(
+                    env,
+                    token_id.clone(),
+                    minter,
+                    destination_chain.clone(),
+                    &destination_minter,
+                )?;
+
+                Some(destination_minter)
+            }
+            None => None,
+        };
+
+        Self::deploy_remote_token(
+            env,
+            token_id.clone(),
+            destination_chain,
+            destination_minter,
+            Some(gas_payment),
+        )?;
+
+        Ok(token_id)
+    }
+
+    #[when_not_paused]
+    fn register_canonical_token(
+        env: &Env,
+        token_address: Address,
+    ) -> Result<BytesN<32>, ContractError> {
+        let token_id = Self::canonical_interchain_token_id(env, token_address.clone());
+
+        // Validates the token address and its associated token metadata
+        let _ = token_metadata::token_metadata_with_override(
+            env,
+            &token_address,
+            &Self::native_token_address(env),
+            storage::try_token_metadata_override(env, token_id.clone()),
+        )?;
+
+        let unregistered_token_id = token_id::ensure_token_not_registered(env, token_id.clone())?;
+
+        let _: Address = Self::deploy_token_manager(
+            env,
+            unregistered_token_id,
             token_address,
-            decimals: token_metadata.decimal,
+            TokenManagerType::LockUnlock,
+        );
+
+        Ok(token_id)
+    }
+
+    fn token_metadata_override(env: &Env, token_address: Address) -> Option<TokenMetadataOverride> {
+        storage::try_token_metadata_override(
+            env,
+            Self::canonical_interchain_token_id(env, token_address),
+        )
+    }
+
+    fn set_token_metadata_override(
+        env: &Env,
+        caller: Address,
+        token_address: Address,
+        metadata: Option<TokenMetadataOverride>,
+    ) -> Result<(), ContractError> {
+        caller.require_auth();
+
+        ensure!(
+            caller == Self::owner(env)
+                || StellarAssetClient::new(env, &token_address)
+                    .try_admin()
+                    .is_ok_and(|admin| admin.as_ref() == Ok(&caller)),
+            ContractError::NotOwnerOrTokenAdmin
+        );
+
+        // Validates the token address and the resulting token metadata
+        let _ = token_metadata::token_metadata_with_override(
+            env,
+            &token_address,
+            &Self::native_token_address(env),
+            metadata.clone(),
+        )?;
+
+        let token_id = Self::canonical_interchain_token_id(env, token_address.clone());
+
+        match metadata {
+            Some(ref metadata) => {
+                storage::set_token_metadata_override(env, token_id.clone(), metadata)
+            }
+            None => storage::remove_token_metadata_override(env, token_id.clone()),
+        }
+
+        TokenMetadataOverrideSetEvent {
+            token_id,
+            token_address,
+            metadata,
         }
         .emit(env);
 
         Ok(())
     }
 
+    #[when_not_paused]
+    fn deploy_remote_canonical_token(
+        env: &Env,
+        token_address: Address,
+        destination_chain: String,
+        spender: Address,
+        gas_token: Option<Token>,
+    ) -> Result<BytesN<32>, ContractError> {
+        spender.require_auth();
+
+        let token_id = Self::canonical_interchain_token_id(env, token_address);
+
+        Self::deploy_remote_token(
+            env,
+            token_id.clone(),
+            destination_chain,
+            None,
+            gas_token.map(|gas_token| Self::gas_payment(env, spender, gas_token)),
+        )?;
+
+        Ok(token_id)
+    }
+
+    #[when_not_paused]
+    fn deploy_remote_canonical_tokens(
+        env: &Env,
+        token_address: Address,
+        spender: Address,
+        destinations: Vec<(String, Option<Token>)>,
+    ) -> Result<BytesN<32>, ContractError> {
+        spender.require_auth();
+
+        let token_id = Self::canonical_interchain_token_id(env, token_address);
+
+        Self::deploy_remote_tokens(env, spender, token_id.clone(), destinations)?;
+
+        Ok(token_id)
+    }
+
+    #[when_not_paused]
+    fn register_token_metadata(
+        env: &Env,
+        token_address: Address,
+        spender: Address,
+        gas_token: Option<Token>,
+    ) -> Result<(), ContractError> {
+        spender.require_auth();
+
+        Self::send_token_metadata(env, token_address, spender, gas_token)
+    }
+
+    #[when_not_paused]
+    fn register_tokens_metadata(
+        env: &Env,
+        tokens: Vec<(Address, Option<Token>)>,
+        spender: Address,
+    ) -> Result<(), ContractError> {
+        spender.require_auth();
+
+        ensure!(!tokens.is_empty(), ContractError::EmptyTokenAddresses);
+
+        for (token_address, gas_token) in tokens {
+            Self::send_token_metadata(env, token_address, spender.clone(), gas_token)?;
+        }
+
+        Ok(())
+    }
+
     #[when_not_paused]
     fn register_custom_token(
         env: &Env,
@@ -319,7 +933,7 @@ impl InterchainTokenServiceInterface for InterchainTokenService {
         let _ =
             token_metadata::token_metadata(env, &token_address, &Self::native_token_address(env))?;
 
-        let token_id = Self::linked_token_id(env, deployer, salt);
+        let token_id = Self::linked_token_id(env, deployer.clone(), salt);
 
         let unregistered_token_id = token_id::ensure_token_not_registered(env, token_id.clone())?;
 
@@ -330,6 +944,8 @@ impl InterchainTokenServiceInterface for InterchainTokenService {
             token_manager_type,
         );
 
+        storage::set_token_deployer(env, token_id.clone(), &deployer);
+
         Ok(token_id)
     }
 
@@ -342,6 +958,7 @@ impl InterchainTokenServiceInterface for InterchainTokenService {
         destination_token_address: Bytes,
         token_manager_type: TokenManagerType,
         link_params: Option<Bytes>,
+        destination_token_decimals: Option<u32>,
         gas_token: Option<Token>,
     ) -> Result<BytesN<32>, ContractError> {
         deployer.require_auth();
@@ -360,6 +977,15 @@ impl InterchainTokenServiceInterface for InterchainTokenService {
         let token_id = Self::linked_token_id(env, deployer.clone(), salt);
         let token_address = Self::token_id_config(env, token_id.clone())?.token_address;
 
+        if let Some(decimals) = destination_token_decimals {
+            decimals::set_remote_token_decimals(
+                env,
+                token_id.clone(),
+                destination_chain.clone(),
+                decimals,
+            )?;
+        }
+
         let message = Message::LinkToken(LinkToken {
             token_id: token_id.clone(),
             token_manager_type,
@@ -378,7 +1004,12 @@ impl InterchainTokenServiceInterface for InterchainTokenService {
         }
         .emit(env);
 
-        Self::pay_gas_and_call_contract(env, deployer, destination_chain, message, gas_token)?;
+        Self::pay_gas_and_call_contract(
+            env,
+            destination_chain,
+            message,
+            gas_token.map(|gas_token| Self::gas_payment(env, deployer, gas_token)),
+        )?;
 
         Ok(token_id)
     }
@@ -394,82 +1025,485 @@ impl InterchainTokenServiceInterface for InterchainTokenService {
         data: Option<Bytes>,
         gas_token: Option<Token>,
     ) -> Result<(), ContractError> {
-        ensure!(amount > 0, ContractError::InvalidAmount);
-
-        ensure!(
-            !destination_address.is_empty(),
-            ContractError::InvalidDestinationAddress
-        );
-
-        if let Some(ref data) = data {
-            ensure!(!data.is_empty(), ContractError::InvalidData);
-        }
-
-        caller.require_auth();
+        let gas_payment =
+            gas_token.map(|gas_token| Self::gas_payment(env, caller.clone(), gas_token));
 
-        token_handler::take_token(
+        Self::send_interchain_transfer(
             env,
-            &caller,
-            Self::token_id_config(env, token_id.clone())?,
+            caller,
+            token_id,
+            destination_chain,
+            destination_address,
             amount,
-        )?;
-
-        FlowDirection::Out.add_flow(env, token_id.clone(), amount)?;
+            data,
+            gas_payment,
+        )
+    }
 
-        InterchainTransferSentEvent {
-            token_id: token_id.clone(),
-            source_address: caller.clone(),
-            destination_chain: destination_chain.clone(),
-            destination_address: destination_address.clone(),
-            amount,
-            data_hash: data
-                .as_ref()
-                .map(|data| env.crypto().keccak256(data).into()),
+    #[when_not_paused]
+    fn interchain_transfer_with_gas(
+        env: &Env,
+        caller: Address,
+        token_id: BytesN<32>,
+        destination_chain: String,
+        destination_address: Bytes,
+        amount: i128,
+        data: Option<Bytes>,
+        gas_payment: GasPayment,
+    ) -> Result<(), ContractError> {
+        // The sponsor authorizes the whole transfer, so the gas payment cannot be reused for another message
+        if gas_payment.spender != caller {
+            gas_payment.spender.require_auth();
         }
-        .emit(env);
 
-        let message = Message::InterchainTransfer(InterchainTransfer {
+        Self::send_interchain_transfer(
+            env,
+            caller,
             token_id,
-            source_address: caller.to_string_bytes(),
+            destination_chain,
             destination_address,
             amount,
             data,
-        });
-
-        Self::pay_gas_and_call_contract(env, caller, destination_chain, message, gas_token)?;
-
-        Ok(())
+            Some(gas_payment),
+        )
     }
 
-    #[only_owner]
-    fn transfer_token_admin(
+    #[when_not_paused]
+    fn call_contract_with_token(
         env: &Env,
+        caller: Address,
         token_id: BytesN<32>,
-        new_admin: Address,
+        destination_chain: String,
+        destination_address: Bytes,
+        amount: i128,
+        data: Bytes,
+        gas_token: Option<Token>,
     ) -> Result<(), ContractError> {
-        let TokenIdConfigValue {
-            token_address,
-            token_manager_type,
-            token_manager,
-        } = Self::token_id_config(env, token_id)?;
+        ensure!(!data.is_empty(), ContractError::InvalidData);
+        ensure!(data.len() <= MAX_DATA_SIZE, ContractError::DataTooLarge);
 
-        ensure!(
-            token_manager_type == TokenManagerType::MintBurn,
-            ContractError::InvalidTokenManagerType
-        );
+        let gas_payment =
+            gas_token.map(|gas_token| Self::gas_payment(env, caller.clone(), gas_token));
 
-        TokenManagerClient::new(env, &token_manager).set_admin(env, &token_address, &new_admin);
+        Self::send_interchain_transfer(
+            env,
+            caller,
+            token_id,
+            destination_chain,
+            destination_address,
+            amount,
+            Some(data),
+            gas_payment,
+        )
+    }
 
-        Ok(())
+    fn is_non_reverting_execution(env: &Env, contract: Address) -> bool {
+        storage::is_non_reverting_execution(env, contract)
     }
-}
 
-impl InterchainTokenService {
+    fn set_non_reverting_execution(env: &Env, contract: Address, enabled: bool) {
+        contract.require_auth();
+
+        if enabled {
+            storage::set_non_reverting_execution_status(env, contract.clone());
+        } else {
+            storage::remove_non_reverting_execution_status(env, contract.clone());
+        }
+
+        NonRevertingExecutionSetEvent { contract, enabled }.emit(env);
+    }
+
+    fn recovery_address(env: &Env, contract: Address) -> Option<Address> {
+        storage::try_recovery_address(env, contract)
+    }
+
+    fn set_recovery_address(env: &Env, contract: Address, recovery_address: Option<Address>) {
+        contract.require_auth();
+
+        match recovery_address {
+            Some(ref recovery_address) => {
+                storage::set_recovery_address(env, contract.clone(), recovery_address)
+            }
+            None => storage::remove_recovery_address(env, contract.clone()),
+        }
+
+        RecoveryAddressSetEvent {
+            contract,
+            recovery_address,
+        }
+        .emit(env);
+    }
+
+    #[only_operator]
+    #[when_not_paused]
+    fn recover_interchain_transfer(
+        env: &Env,
+        source_chain: String,
+        message_id: String,
+        source_address: String,
+        payload: Bytes,
+        recovery: TransferRecovery,
+        gas_token: Option<Token>,
+    ) -> Result<(), ContractError> {
+        validate_message::<Self>(env, &source_chain, &message_id, &source_address, &payload)
+            .map_err(|_| ContractError::NotApproved)?;
+
+        let express_execution_hash = express::express_execution_hash(
+            env,
+            source_chain.clone(),
+            message_id.clone(),
+            source_address.clone(),
+            express::payload_hash(env, &payload),
+        );
+        ensure!(
+            express::express_executor(env, express_execution_hash).is_none(),
+            ContractError::ExpressExecutorAlreadySet
+        );
+
+        let (original_source_chain, message) =
+            Self::get_execute_params(env, source_chain, source_address, payload)?;
+
+        let Message::InterchainTransfer(message) = message else {
+            return Err(ContractError::InvalidMessageType);
+        };
+
+        Self::ensure_token_not_paused(env, message.token_id.clone())?;
+
+        match recovery {
+            TransferRecovery::RecoveryAddress => Self::give_token_to_recovery_address(
+                env,
+                original_source_chain,
+                message_id,
+                message,
+            ),
+            TransferRecovery::ReturnToSender => Self::return_token_to_sender(
+                env,
+                original_source_chain,
+                message_id,
+                message,
+                gas_token,
+            ),
+        }
+    }
+
+    fn express_executor(
+        env: &Env,
+        source_chain: String,
+        message_id: String,
+        source_address: String,
+        payload_hash: BytesN<32>,
+    ) -> Option<Address> {
+        express::express_executor(
+            env,
+            express::express_execution_hash(
+                env,
+                source_chain,
+                message_id,
+                source_address,
+                payload_hash,
+            ),
+        )
+    }
+
+    #[when_not_paused]
+    fn express_execute(
+        env: &Env,
+        express_executor: Address,
+        source_chain: String,
+        message_id: String,
+        source_address: String,
+        payload: Bytes,
+    ) -> Result<(), ContractError> {
+        express_executor.require_auth();
+
+        let gateway = AxelarGatewayMessagingClient::new(env, &Self::gateway(env));
+        ensure!(
+            !gateway.is_message_executed(&source_chain, &message_id),
+            ContractError::MessageAlreadyExecuted
+        );
+
+        let payload_hash = express::payload_hash(env, &payload);
+        let express_execution_hash = express::express_execution_hash(
+            env,
+            source_chain.clone(),
+            message_id.clone(),
+            source_address.clone(),
+            payload_hash.clone(),
+        );
+        ensure!(
+            express::express_executor(env, express_execution_hash.clone()).is_none(),
+            ContractError::ExpressExecutorAlreadySet
+        );
+
+        let (original_source_chain, message) =
+            Self::get_execute_params(env, source_chain.clone(), source_address.clone(), payload)?;
+
+        let Message::InterchainTransfer(message) = message else {
+            return Err(ContractError::InvalidMessageType);
+        };
+
+        Self::ensure_token_not_paused(env, message.token_id.clone())?;
+
+        // The fronted amount is recorded, so the express executor is repaid the same amount
+        // even if the decimals of the token change before the message is executed
+        let amount = decimals::to_local_amount(
+            env,
+            message.token_id.clone(),
+            &Self::token_id_config(env, message.token_id.clone())?.token_address,
+            original_source_chain.clone(),
+            message.amount,
+        )?;
+        express::set_express_executor(
+            env,
+            express_execution_hash,
+            express_executor.clone(),
+            amount,
+        );
+
+        ExpressExecutedEvent {
+            source_chain,
+            message_id: message_id.clone(),
+            source_address,
+            payload_hash,
+            express_executor: express_executor.clone(),
+        }
+        .emit(env);
+
+        Self::execute_transfer_message(
+            env,
+            &original_source_chain,
+            message_id,
+            message,
+            Some(express_executor),
+        )
+    }
+
+    #[only_owner]
+    fn transfer_token_admin(
+        env: &Env,
+        token_id: BytesN<32>,
+        new_admin: Address,
+    ) -> Result<(), ContractError> {
+        let TokenIdConfigValue {
+            token_address,
+            token_manager_type,
+            token_manager,
+        } = Self::token_id_config(env, token_id)?;
+
+        ensure!(
+            token_manager_type == TokenManagerType::MintBurn,
+            ContractError::InvalidTokenManagerType
+        );
+
+        TokenManagerClient::new(env, &token_manager).set_admin(env, &token_address, &new_admin);
+
+        Ok(())
+    }
+
+    fn deregister_token(
+        env: &Env,
+        caller: Address,
+        token_id: BytesN<32>,
+    ) -> Result<(), ContractError> {
+        caller.require_auth();
+
+        let token_config = Self::token_id_config(env, token_id.clone())?;
+        let is_owner = caller == Self::owner(env);
+
+        if !is_owner {
+            ensure!(
+                storage::try_token_deployer(env, token_id.clone()).as_ref() == Some(&caller),
+                ContractError::NotOwnerOrTokenDeployer
+            );
+            ensure!(
+                !storage::is_token_transferred(env, token_id.clone()),
+                ContractError::TokenAlreadyTransferred
+            );
+        }
+
+        // The deployer never owned a native interchain token, so it only gets the roles it could have granted itself
+        Self::exit_token_manager(env, token_id, token_config, caller, is_owner)
+    }
+
+    #[only_owner]
+    fn exit_token(
+        env: &Env,
+        token_id: BytesN<32>,
+        recipient: Address,
+    ) -> Result<(), ContractError> {
+        let token_config = Self::token_id_config(env, token_id.clone())?;
+
+        Self::exit_token_manager(env, token_id, token_config, recipient, true)
+    }
+
+    #[only_owner]
+    fn migrate_token_manager_type(
+        env: &Env,
+        token_id: BytesN<32>,
+        token_manager_type: TokenManagerType,
+    ) -> Result<(), ContractError> {
+        let token_config = Self::token_id_config(env, token_id.clone())?;
+        let old_token_manager_type = token_config.token_manager_type;
+
+        // Native interchain tokens are deployed by the service, so their token manager type is fixed
+        ensure!(
+            old_token_manager_type != TokenManagerType::NativeInterchainToken
+                && token_manager_type != TokenManagerType::NativeInterchainToken
+                && token_manager_type != old_token_manager_type,
+            ContractError::InvalidTokenManagerType
+        );
+        ensure!(
+            token_manager_type == TokenManagerType::LockUnlock
+                || Self::is_token_manager_minter(env, &token_config, token_manager_type),
+            ContractError::TokenManagerNotMinter
+        );
+        // Locked tokens are only backed by the token manager balance if it can no longer mint
+        ensure!(
+            token_manager_type != TokenManagerType::LockUnlock
+                || !Self::is_token_manager_minter(env, &token_config, old_token_manager_type),
+            ContractError::TokenManagerStillMinter
+        );
+
+        let token_manager = TokenManagerClient::new(env, &token_config.token_manager);
+        let mut burned_amount = 0;
+
+        // Locked tokens back the supply on other chains, which is minted instead when it returns
+        if old_token_manager_type == TokenManagerType::LockUnlock {
+            burned_amount = TokenClient::new(env, &token_config.token_address)
+                .balance(&token_config.token_manager);
+
+            if burned_amount > 0 {
+                token_manager.burn(env, &token_config.token_address, burned_amount);
+            }
+
+            storage::remove_locked_amount(env, token_id.clone());
+        }
+
+        // The token manager is funded with the amount bridged out before migrating
+        if token_manager_type == TokenManagerType::LockUnlock {
+            let locked_amount = TokenClient::new(env, &token_config.token_address)
+                .balance(&token_config.token_manager);
+
+            storage::set_locked_amount(env, token_id.clone(), &locked_amount);
+        }
+
+        Self::set_token_id_config(
+            env,
+            token_id.clone(),
+            TokenIdConfigValue {
+                token_manager_type,
+                ..token_config
+            },
+        );
+
+        TokenManagerTypeMigratedEvent {
+            token_id,
+            old_token_manager_type,
+            new_token_manager_type: token_manager_type,
+            burned_amount,
+        }
+        .emit(env);
+
+        Ok(())
+    }
+
+    fn transfer_mintership(
+        env: &Env,
+        minter: Address,
+        token_id: BytesN<32>,
+        new_minter: Address,
+    ) -> Result<(), ContractError> {
+        minter.require_auth();
+
+        let token_address = Self::ensure_minter(env, token_id.clone(), &minter)?.token_address;
+        let token = InterchainTokenClient::new(env, &token_address);
+
+        ensure!(!token.is_minter(&new_minter), ContractError::InvalidMinter);
+
+        token.remove_minter(&minter);
+        token.add_minter(&new_minter);
+
+        MintershipTransferredEvent {
+            token_id,
+            minter,
+            new_minter,
+        }
+        .emit(env);
+
+        Ok(())
+    }
+
+    fn renounce_mintership(
+        env: &Env,
+        minter: Address,
+        token_id: BytesN<32>,
+    ) -> Result<(), ContractError> {
+        minter.require_auth();
+
+        let token_address = Self::ensure_minter(env, token_id.clone(), &minter)?.token_address;
+
+        InterchainTokenClient::new(env, &token_address).remove_minter(&minter);
+
+        MintershipRenouncedEvent { token_id, minter }.emit(env);
+
+        Ok(())
+    }
+
+    #[when_not_paused]
+    fn update_token_metadata(
+        env: &Env,
+        minter: Address,
+        token_id: BytesN<32>,
+        name: String,
+        symbol: String,
+        destinations: Vec<(String, Option<Token>)>,
+    ) -> Result<(), ContractError> {
+        minter.require_auth();
+
+        let token_address = Self::ensure_minter(env, token_id.clone(), &minter)?.token_address;
+
+        // Validate all destination chains upfront, so no update is sent if any of them is untrusted
+        for (destination_chain, _) in destinations.iter() {
+            ensure!(
+                Self::is_trusted_chain(env, destination_chain),
+                ContractError::UntrustedChain
+            );
+        }
+
+        Self::update_local_token_metadata(env, &token_address, name.clone(), symbol.clone())?;
+
+        for (destination_chain, gas_token) in destinations {
+            let message = Message::UpdateTokenMetadata(UpdateTokenMetadata {
+                token_id: token_id.clone(),
+                name: name.clone(),
+                symbol: symbol.clone(),
+            });
+
+            TokenMetadataUpdateStartedEvent {
+                token_id: token_id.clone(),
+                destination_chain: destination_chain.clone(),
+                name: name.clone(),
+                symbol: symbol.clone(),
+            }
+            .emit(env);
+
+            Self::pay_gas_and_call_contract(
+                env,
+                destination_chain,
+                message,
+                gas_token.map(|gas_token| Self::gas_payment(env, minter.clone(), gas_token)),
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+impl InterchainTokenService {
     fn send_to_hub(
         env: &Env,
-        spender: Address,
         hub_message: HubMessage,
-        gas_token: Option<Token>,
+        gas_payment: Option<GasPayment>,
     ) -> Result<(), ContractError> {
         let gateway = AxelarGatewayMessagingClient::new(env, &Self::gateway(env));
         let gas_service = AxelarGasServiceClient::new(env, &Self::gas_service(env));
@@ -479,15 +1513,22 @@ impl InterchainTokenService {
 
         let payload = hub_message.abi_encode(env)?;
 
-        if let Some(gas_token) = gas_token {
+        if let Some(GasPayment {
+            spender,
+            token,
+            metadata,
+            refund_address,
+        }) = gas_payment
+        {
             gas_service.pay_gas(
                 &env.current_contract_address(),
                 &hub_chain,
                 &hub_address,
                 &payload,
                 &spender,
-                &gas_token,
-                &Bytes::new(env),
+                &refund_address,
+                &token,
+                &metadata,
             );
         }
 
@@ -501,67 +1542,448 @@ impl InterchainTokenService {
         Ok(())
     }
 
-    fn pay_gas_and_call_contract(
+    fn send_interchain_transfer(
+        env: &Env,
+        caller: Address,
+        token_id: BytesN<32>,
+        destination_chain: String,
+        destination_address: Bytes,
+        amount: i128,
+        data: Option<Bytes>,
+        gas_payment: Option<GasPayment>,
+    ) -> Result<(), ContractError> {
+        ensure!(amount > 0, ContractError::InvalidAmount);
+
+        ensure!(
+            !destination_address.is_empty(),
+            ContractError::InvalidDestinationAddress
+        );
+
+        if let Some(ref data) = data {
+            ensure!(!data.is_empty(), ContractError::InvalidData);
+        }
+
+        caller.require_auth();
+
+        Self::ensure_token_not_paused(env, token_id.clone())?;
+
+        let token_config_value = Self::token_id_config(env, token_id.clone())?;
+
+        Self::ensure_outbound_transfer_compliant(
+            env,
+            &token_id,
+            &caller,
+            &destination_chain,
+            &destination_address,
+            amount,
+        )?;
+
+        // The fee is deducted before the amount is sent, so the remaining amount is encoded in the message
+        let amount_unit = decimals::remote_amount_unit(
+            env,
+            token_id.clone(),
+            &token_config_value.token_address,
+            destination_chain.clone(),
+        )?;
+        let fee = fee::charge_fee(
+            env,
+            token_id.clone(),
+            &token_config_value.token_address,
+            &caller,
+            amount,
+            amount_unit,
+        )?;
+        let amount = amount - fee;
+
+        let remote_amount = decimals::to_remote_amount(
+            env,
+            token_id.clone(),
+            &token_config_value.token_address,
+            destination_chain.clone(),
+            amount,
+        )?;
+
+        token_handler::take_token(env, &caller, token_id.clone(), token_config_value, amount)?;
+
+        Self::record_token_transfer(env, token_id.clone());
+        FlowDirection::Out.add_flow(env, token_id.clone(), destination_chain.clone(), amount)?;
+
+        InterchainTransferSentEvent {
+            token_id: token_id.clone(),
+            source_address: caller.clone(),
+            destination_chain: destination_chain.clone(),
+            destination_address: destination_address.clone(),
+            amount,
+            data_hash: data
+                .as_ref()
+                .map(|data| env.crypto().keccak256(data).into()),
+            fee,
+        }
+        .emit(env);
+
+        let message = Message::InterchainTransfer(InterchainTransfer {
+            token_id,
+            source_address: caller.to_string_bytes(),
+            destination_address,
+            amount: remote_amount,
+            data,
+        });
+
+        Self::pay_gas_and_call_contract(env, destination_chain, message, gas_payment)?;
+
+        Ok(())
+    }
+
+    /// Returns the gas payment of the `spender` with the `gas_token` and no metadata, refunded to the `spender`.
+    fn gas_payment(env: &Env, spender: Address, gas_token: Token) -> GasPayment {
+        GasPayment {
+            spender: spender.clone(),
+            token: gas_token,
+            metadata: Bytes::new(env),
+            refund_address: spender,
+        }
+    }
+
+    /// Consumes the single use approval of the `minter` for the `destination_minter` of the token on the `destination_chain`.
+    fn consume_remote_minter_approval(
+        env: &Env,
+        token_id: BytesN<32>,
+        minter: Address,
+        destination_chain: String,
+        destination_minter: &Bytes,
+    ) -> Result<(), ContractError> {
+        let _ = Self::ensure_minter(env, token_id.clone(), &minter)?;
+
+        let approved_minter_hash = storage::try_remote_minter_approval(
+            env,
+            minter.clone(),
+            token_id.clone(),
+            destination_chain.clone(),
+        )
+        .ok_or(ContractError::RemoteMinterNotApproved)?;
+        let destination_minter_hash: BytesN<32> = env.crypto().keccak256(destination_minter).into();
+        ensure!(
+            approved_minter_hash == destination_minter_hash,
+            ContractError::RemoteMinterNotApproved
+        );
+
+        storage::remove_remote_minter_approval(env, minter, token_id, destination_chain);
+
+        Ok(())
+    }
+
+    fn pay_gas_and_call_contract(
+        env: &Env,
+        destination_chain: String,
+        message: Message,
+        gas_payment: Option<GasPayment>,
+    ) -> Result<(), ContractError> {
+        // Validate the destination chain only for non-interchain transfer messages.
+        // Self-transfers are allowed only in interchain transfers, as they may be useful
+        // when broadcasting to a batch that includes the current chain.
+        if !matches!(message, Message::InterchainTransfer(_)) {
+            ensure!(
+                destination_chain != Self::chain_name(env),
+                ContractError::InvalidDestinationChain
+            );
+        }
+
+        trusted_chain::ensure_outbound_allowed(env, destination_chain.clone(), &message)?;
+
+        let hub_message = HubMessage::SendToHub {
+            destination_chain,
+            message,
+        };
+
+        Self::send_to_hub(env, hub_message, gas_payment)?;
+
+        Ok(())
+    }
+
+    /// Validate that the message is coming from the ITS Hub and decode the message
+    fn get_execute_params(
+        env: &Env,
+        source_chain: String,
+        source_address: String,
+        payload: Bytes,
+    ) -> Result<(String, Message), ContractError> {
+        ensure!(
+            source_chain == Self::its_hub_chain_name(env),
+            ContractError::NotHubChain
+        );
+        ensure!(
+            source_address == Self::its_hub_address(env),
+            ContractError::NotHubAddress
+        );
+
+        let HubMessage::ReceiveFromHub {
+            source_chain: original_source_chain,
+            message,
+        } = HubMessage::abi_decode(env, &payload)?
+        else {
+            return Err(ContractError::InvalidMessageType);
+        };
+        trusted_chain::ensure_inbound_allowed(env, original_source_chain.clone(), &message)?;
+
+        Ok((original_source_chain, message))
+    }
+
+    /// Ensures that the `caller` is either the operator or the deployer of the token, and that it has authorized the call.
+    fn ensure_operator_or_token_deployer(
+        env: &Env,
+        caller: &Address,
+        token_id: BytesN<32>,
+    ) -> Result<(), ContractError> {
+        caller.require_auth();
+
+        ensure!(
+            *caller == Self::operator(env)
+                || storage::try_token_deployer(env, token_id).as_ref() == Some(caller),
+            ContractError::NotOperatorOrTokenDeployer
+        );
+
+        Ok(())
+    }
+
+    /// Ensures the `minter` is a minter of the native interchain token registered under the `token_id`.
+    ///
+    /// The token manager's own minter role is never considered, so it cannot be transferred or renounced.
+    fn ensure_minter(
+        env: &Env,
+        token_id: BytesN<32>,
+        minter: &Address,
+    ) -> Result<TokenIdConfigValue, ContractError> {
+        let token_config = Self::token_id_config(env, token_id)?;
+
+        ensure!(
+            token_config.token_manager_type == TokenManagerType::NativeInterchainToken,
+            ContractError::InvalidTokenManagerType
+        );
+        ensure!(
+            *minter != token_config.token_manager
+                && InterchainTokenClient::new(env, &token_config.token_address).is_minter(minter),
+            ContractError::NotMinter
+        );
+
+        Ok(token_config)
+    }
+
+    /// Hands the funds and roles held by the token manager to the `recipient`, and deregisters the token ID.
+    ///
+    /// The ownership of a native interchain token is only transferred if `transfer_token_ownership` is set,
+    /// otherwise the service keeps it, so that nobody can add minters to the token.
+    fn exit_token_manager(
+        env: &Env,
+        token_id: BytesN<32>,
+        token_config: TokenIdConfigValue,
+        recipient: Address,
+        transfer_token_ownership: bool,
+    ) -> Result<(), ContractError> {
+        let token_manager = TokenManagerClient::new(env, &token_config.token_manager);
+        let mut unlocked_amount = 0;
+
+        match token_config.token_manager_type {
+            TokenManagerType::LockUnlock => {
+                unlocked_amount = TokenClient::new(env, &token_config.token_address)
+                    .balance(&token_config.token_manager);
+
+                if unlocked_amount > 0 {
+                    token_manager.transfer(
+                        env,
+                        &token_config.token_address,
+                        &MuxedAddress::from(&recipient),
+                        unlocked_amount,
+                    );
+                }
+            }
+            TokenManagerType::MintBurn | TokenManagerType::MintBurnFrom => {
+                let is_admin = token_config.token_manager_type == TokenManagerType::MintBurn
+                    && StellarAssetClient::new(env, &token_config.token_address)
+                        .try_admin()
+                        .is_ok_and(|admin| admin.as_ref() == Ok(&token_config.token_manager));
+
+                if is_admin {
+                    token_manager.set_admin(env, &token_config.token_address, &recipient);
+                } else {
+                    // The service isn't the owner of custom tokens, so only the token owner can revoke the minter role
+                    ensure!(
+                        !Self::is_token_manager_minter(
+                            env,
+                            &token_config,
+                            TokenManagerType::MintBurnFrom
+                        ),
+                        ContractError::TokenManagerStillMinter
+                    );
+                }
+            }
+            TokenManagerType::NativeInterchainToken => {
+                InterchainTokenClient::new(env, &token_config.token_address)
+                    .remove_minter(&token_config.token_manager);
+                if transfer_token_ownership {
+                    interfaces::OwnableClient::new(env, &token_config.token_address)
+                        .transfer_ownership(&recipient);
+                }
+            }
+        }
+
+        TokenExitedEvent {
+            token_id: token_id.clone(),
+            token_manager_type: token_config.token_manager_type,
+            recipient,
+            unlocked_amount,
+        }
+        .emit(env);
+
+        Self::remove_token_registration(env, token_id, token_config.token_address);
+
+        Ok(())
+    }
+
+    /// Records that the token has been transferred, after which only the owner can deregister it.
+    fn record_token_transfer(env: &Env, token_id: BytesN<32>) {
+        if !storage::is_token_transferred(env, token_id.clone()) {
+            storage::set_token_transferred_status(env, token_id);
+        }
+    }
+
+    /// Removes the token ID from the service, so that it can no longer be used for interchain transfers.
+    ///
+    /// The token ID is marked as deregistered, so it can't be registered again.
+    /// The per-chain and per-address entries of the token, i.e. chain flow limits, maximum transfer amounts,
+    /// remote decimals, flow limiters and remote minter approvals, can't be enumerated, so they are left to expire.
+    /// They are unreachable since the token ID can't be registered again.
+    fn remove_token_registration(env: &Env, token_id: BytesN<32>, token_address: Address) {
+        storage::remove_token_id_config(env, token_id.clone());
+        storage::remove_token_deployer(env, token_id.clone());
+        storage::remove_locked_amount(env, token_id.clone());
+        Self::store_compliance_hook(env, token_id.clone(), None);
+        storage::remove_flow_limit(env, token_id.clone());
+        storage::remove_fee_rate(env, token_id.clone());
+        storage::remove_token_metadata_override(env, token_id.clone());
+        storage::remove_decimal_scaling_status(env, token_id.clone());
+        storage::remove_token_paused_status(env, token_id.clone());
+        storage::remove_token_transferred_status(env, token_id.clone());
+        storage::set_token_deregistered_status(env, token_id.clone());
+        registry::deregister_token(env, token_id.clone(), token_address.clone());
+
+        TokenDeregisteredEvent {
+            token_id,
+            token_address,
+        }
+        .emit(env);
+    }
+
+    /// Returns whether the token manager can mint the token with the given `token_manager_type`.
+    ///
+    /// Stellar Classic Assets can only be minted by their admin, while custom tokens need the token manager as a minter.
+    fn is_token_manager_minter(
+        env: &Env,
+        token_config: &TokenIdConfigValue,
+        token_manager_type: TokenManagerType,
+    ) -> bool {
+        let is_minter = matches!(
+            InterchainTokenClient::new(env, &token_config.token_address)
+                .try_is_minter(&token_config.token_manager),
+            Ok(Ok(true))
+        );
+
+        match token_manager_type {
+            TokenManagerType::MintBurn => {
+                is_minter
+                    || StellarAssetClient::new(env, &token_config.token_address)
+                        .try_admin()
+                        .is_ok_and(|admin| admin.as_ref() == Ok(&token_config.token_manager))
+            }
+            TokenManagerType::MintBurnFrom => is_minter,
+            TokenManagerType::NativeInterchainToken | TokenManagerType::LockUnlock => false,
+        }
+    }
+
+    /// Sets or removes the compliance hook of the token, keeping count of the tokens with their own compliance hook.
+    fn store_compliance_hook(env: &Env, token_id: BytesN<32>, hook: Option<&Address>) {
+        let had_hook = storage::try_compliance_hook(env, token_id.clone()).is_some();
+        let count = storage::try_compliance_hook_count(env).unwrap_or(0);
+
+        match hook {
+            Some(hook) => {
+                storage::set_compliance_hook(env, token_id, hook);
+                if !had_hook {
+                    storage::set_compliance_hook_count(env, &(count + 1));
+                }
+            }
+            None => {
+                storage::remove_compliance_hook(env, token_id);
+                if had_hook {
+                    storage::set_compliance_hook_count(env, &(count - 1));
+                }
+            }
+        }
+    }
+
+    /// Returns the compliance hook of the token, falling back to the global compliance hook.
+    ///
+    /// The persistent per-token entry is only read if any token has its own compliance hook,
+    /// so transfers don't pay for the extra read when no per-token hooks are set.
+    fn effective_compliance_hook(env: &Env, token_id: BytesN<32>) -> Option<Address> {
+        let has_token_hooks = storage::try_compliance_hook_count(env).unwrap_or(0) > 0;
+
+        has_token_hooks
+            .then(|| storage::try_compliance_hook(env, token_id))
+            .flatten()
+            .or_else(|| storage::try_global_compliance_hook(env))
+    }
+
+    fn ensure_outbound_transfer_compliant(
+        env: &Env,
+        token_id: &BytesN<32>,
+        sender: &Address,
+        destination_chain: &String,
+        destination_address: &Bytes,
+        amount: i128,
+    ) -> Result<(), ContractError> {
+        if let Some(hook) = Self::effective_compliance_hook(env, token_id.clone()) {
+            let result = ComplianceHookClient::new(env, &hook).try_check_outbound_transfer(
+                token_id,
+                sender,
+                destination_chain,
+                destination_address,
+                &amount,
+            );
+            ensure!(matches!(result, Ok(Ok(()))), ContractError::TransferBlocked);
+        }
+
+        Ok(())
+    }
+
+    fn ensure_inbound_transfer_compliant(
         env: &Env,
-        caller: Address,
-        destination_chain: String,
-        message: Message,
-        gas_token: Option<Token>,
+        token_id: &BytesN<32>,
+        source_chain: &String,
+        source_address: &Bytes,
+        recipient: &Address,
+        amount: i128,
     ) -> Result<(), ContractError> {
-        // Validate the destination chain only for non-interchain transfer messages.
-        // Self-transfers are allowed only in interchain transfers, as they may be useful
-        // when broadcasting to a batch that includes the current chain.
-        if !matches!(message, Message::InterchainTransfer(_)) {
-            ensure!(
-                destination_chain != Self::chain_name(env),
-                ContractError::InvalidDestinationChain
+        if let Some(hook) = Self::effective_compliance_hook(env, token_id.clone()) {
+            let result = ComplianceHookClient::new(env, &hook).try_check_inbound_transfer(
+                token_id,
+                source_chain,
+                source_address,
+                recipient,
+                &amount,
             );
+            ensure!(matches!(result, Ok(Ok(()))), ContractError::TransferBlocked);
         }
 
-        ensure!(
-            Self::is_trusted_chain(env, destination_chain.clone()),
-            ContractError::UntrustedChain
-        );
-
-        let hub_message = HubMessage::SendToHub {
-            destination_chain,
-            message,
-        };
-
-        Self::send_to_hub(env, caller, hub_message, gas_token)?;
-
         Ok(())
     }
 
-    /// Validate that the message is coming from the ITS Hub and decode the message
-    fn get_execute_params(
-        env: &Env,
-        source_chain: String,
-        source_address: String,
-        payload: Bytes,
-    ) -> Result<(String, Message), ContractError> {
-        ensure!(
-            source_chain == Self::its_hub_chain_name(env),
-            ContractError::NotHubChain
-        );
-        ensure!(
-            source_address == Self::its_hub_address(env),
-            ContractError::NotHubAddress
-        );
-
-        let HubMessage::ReceiveFromHub {
-            source_chain: original_source_chain,
-            message,
-        } = HubMessage::abi_decode(env, &payload)?
-        else {
-            return Err(ContractError::InvalidMessageType);
-        };
+    fn ensure_token_not_paused(env: &Env, token_id: BytesN<32>) -> Result<(), ContractError> {
         ensure!(
-            storage::is_trusted_chain(env, original_source_chain.clone()),
-            ContractError::UntrustedChain
+            !storage::is_token_paused(env, token_id),
+            ContractError::TokenPaused
         );
 
-        Ok((original_source_chain, message))
+        Ok(())
     }
 
     fn set_token_id_config(env: &Env, token_id: BytesN<32>, token_data: TokenIdConfigValue) {
@@ -597,41 +2019,50 @@ impl InterchainTokenService {
     /// It also constructs and sends the deployment message to the remote chain.
     ///
     /// # Arguments
-    /// * `caller` - Address of the caller initiating the deployment.
     /// * `token_id` - The token ID for the remote token being deployed.
     /// * `destination_chain` - The name of the destination chain where the token will be deployed.
-    /// * `gas_token` - An optional gas token used to pay for gas during the deployment.
+    /// * `minter` - An optional minter address on the destination chain.
+    /// * `gas_payment` - An optional gas payment for the deployment.
     ///
     /// # Errors
     /// - `ContractError::InvalidDestinationChain`: If the `destination_chain` is the current chain.
     /// - `ContractError::InvalidTokenId`: If the token ID is invalid.
     /// - Errors propagated from `token_metadata`.
     /// - Any error propagated from `pay_gas_and_call_contract`.
-    ///
-    /// # Authorization
-    /// - The `caller` must authenticate.
     fn deploy_remote_token(
         env: &Env,
-        caller: Address,
         token_id: BytesN<32>,
         destination_chain: String,
-        gas_token: Option<Token>,
+        minter: Option<Bytes>,
+        gas_payment: Option<GasPayment>,
     ) -> Result<(), ContractError> {
         let token_address = Self::token_id_config(env, token_id.clone())?.token_address;
         let TokenMetadata {
             name,
             symbol,
             decimal,
-        } = token_metadata::token_metadata(env, &token_address, &Self::native_token_address(env))?;
+        } = token_metadata::token_metadata_with_override(
+            env,
+            &token_address,
+            &Self::native_token_address(env),
+            storage::try_token_metadata_override(env, token_id.clone()),
+        )?;
 
         let message = Message::DeployInterchainToken(DeployInterchainToken {
             token_id: token_id.clone(),
             name: name.clone(),
             symbol: symbol.clone(),
             decimals: decimal as u8,
-            minter: None,
+            minter: minter.clone(),
         });
 
+        decimals::set_remote_token_decimals(
+            env,
+            token_id.clone(),
+            destination_chain.clone(),
+            decimal,
+        )?;
+
         InterchainTokenDeploymentStartedEvent {
             token_id,
             token_address,
@@ -639,15 +2070,83 @@ impl InterchainTokenService {
             name,
             symbol,
             decimals: decimal,
-            minter: None,
+            minter,
+        }
+        .emit(env);
+
+        Self::pay_gas_and_call_contract(env, destination_chain, message, gas_payment)?;
+
+        Ok(())
+    }
+
+    /// Validates the metadata of the token and registers it with the ITS Hub.
+    fn send_token_metadata(
+        env: &Env,
+        token_address: Address,
+        spender: Address,
+        gas_token: Option<Token>,
+    ) -> Result<(), ContractError> {
+        let token_metadata =
+            token_metadata::token_metadata(env, &token_address, &Self::native_token_address(env))?;
+
+        // RegisterTokenMetadata expects u8 decimals, but TokenMetadata uses u32.
+        // The unwrap() is safe because token_metadata validation ensures decimals <= 255.
+        let hub_message = HubMessage::RegisterTokenMetadata(RegisterTokenMetadata {
+            decimals: u8::try_from(token_metadata.decimal).unwrap(),
+            token_address: token_address.to_string_bytes(),
+        });
+
+        Self::send_to_hub(
+            env,
+            hub_message,
+            gas_token.map(|gas_token| Self::gas_payment(env, spender, gas_token)),
+        )?;
+
+        TokenMetadataRegisteredEvent {
+            token_address,
+            decimals: token_metadata.decimal,
         }
         .emit(env);
 
-        Self::pay_gas_and_call_contract(env, caller, destination_chain, message, gas_token)?;
+        Ok(())
+    }
+
+    fn deploy_remote_tokens(
+        env: &Env,
+        caller: Address,
+        token_id: BytesN<32>,
+        destinations: Vec<(String, Option<Token>)>,
+    ) -> Result<(), ContractError> {
+        ensure!(
+            !destinations.is_empty(),
+            ContractError::EmptyDestinationChains
+        );
+
+        // Validate all destination chains upfront, so no deployment is started if any of them is untrusted
+        for (destination_chain, _) in destinations.iter() {
+            ensure!(
+                Self::is_trusted_chain(env, destination_chain),
+                ContractError::UntrustedChain
+            );
+        }
+
+        for (destination_chain, gas_token) in destinations {
+            Self::deploy_remote_token(
+                env,
+                token_id.clone(),
+                destination_chain,
+                None,
+                gas_token.map(|gas_token| Self::gas_payment(env, caller.clone(), gas_token)),
+            )?;
+        }
 
         Ok(())
     }
 
+    /// Executes an inbound interchain transfer.
+    ///
+    /// If an `express_executor` is provided, the tokens are transferred from the express executor
+    /// to the recipient instead of being given by the token manager.
     fn execute_transfer_message(
         env: &Env,
         source_chain: &String,
@@ -659,17 +2158,50 @@ impl InterchainTokenService {
             amount,
             data,
         }: InterchainTransfer,
+        express_executor: Option<Address>,
     ) -> Result<(), ContractError> {
         ensure!(amount > 0, ContractError::InvalidAmount);
 
-        let destination_address = Address::from_string_bytes(&destination_address);
+        // Muxed (M...) destinations are credited to their underlying account, with the mux ID kept for attribution
+        let destination = MuxedAddress::from_string_bytes(&destination_address);
+        let destination_address = destination.address();
 
         let token_config_value = Self::token_id_config(env, token_id.clone())?;
         let token_address = token_config_value.token_address.clone();
+        let amount = decimals::to_local_amount(
+            env,
+            token_id.clone(),
+            &token_address,
+            source_chain.clone(),
+            amount,
+        )?;
 
-        FlowDirection::In.add_flow(env, token_id.clone(), amount)?;
+        Self::ensure_inbound_transfer_compliant(
+            env,
+            &token_id,
+            source_chain,
+            &source_address,
+            &destination_address,
+            amount,
+        )?;
+
+        Self::record_token_transfer(env, token_id.clone());
+        FlowDirection::In.add_flow(env, token_id.clone(), source_chain.clone(), amount)?;
 
-        token_handler::give_token(env, &destination_address, token_config_value, amount)?;
+        match express_executor {
+            Some(express_executor) => TokenClient::new(env, &token_address).transfer(
+                &express_executor,
+                &destination,
+                &amount,
+            ),
+            None => token_handler::give_token(
+                env,
+                &destination,
+                token_id.clone(),
+                token_config_value,
+                amount,
+            )?,
+        }
 
         InterchainTransferReceivedEvent {
             source_chain: source_chain.clone(),
@@ -680,6 +2212,7 @@ impl InterchainTokenService {
             data_hash: data
                 .as_ref()
                 .map(|data| env.crypto().keccak256(data).into()),
+            destination_mux_id: destination.id(),
         }
         .emit(env);
 
@@ -711,22 +2244,158 @@ impl InterchainTokenService {
         token_address: Address,
         amount: i128,
     ) {
-        // Due to limitations of the soroban-sdk, there is no type-safe client for contract execution.
-        // The invocation might return a value, so we use Val as the return type to avoid panics
-        env.invoke_contract::<Val>(
-            &destination_address,
-            &Symbol::new(env, EXECUTE_WITH_INTERCHAIN_TOKEN),
-            vec![
-                env,
-                source_chain.to_val(),
-                message_id.to_val(),
-                source_address.to_val(),
-                payload.to_val(),
-                token_id.to_val(),
-                token_address.to_val(),
-                amount.into_val(env),
-            ],
-        );
+        let executable = InterchainTokenExecutableClient::new(env, &destination_address);
+
+        if !storage::is_non_reverting_execution(env, destination_address.clone()) {
+            executable.execute_with_interchain_token(
+                source_chain,
+                &message_id,
+                &source_address,
+                &payload,
+                &token_id,
+                &token_address,
+                &amount,
+            );
+            return;
+        }
+
+        // The tokens have already been given to the destination contract, so they are kept there if the execution fails
+        let error = match executable.try_execute_with_interchain_token(
+            source_chain,
+            &message_id,
+            &source_address,
+            &payload,
+            &token_id,
+            &token_address,
+            &amount,
+        ) {
+            Ok(_) => return,
+            Err(Ok(error)) => error,
+            Err(Err(InvokeError::Contract(code))) => Error::from_contract_error(code),
+            Err(Err(InvokeError::Abort)) => {
+                Error::from_type_and_code(ScErrorType::Context, ScErrorCode::InvalidAction)
+            }
+        };
+
+        ContractExecutionFailedEvent {
+            source_chain: source_chain.clone(),
+            message_id,
+            destination_address,
+            token_id,
+            amount,
+            error,
+        }
+        .emit(env);
+    }
+
+    fn give_token_to_recovery_address(
+        env: &Env,
+        source_chain: String,
+        message_id: String,
+        InterchainTransfer {
+            token_id,
+            source_address,
+            destination_address,
+            amount,
+            ..
+        }: InterchainTransfer,
+    ) -> Result<(), ContractError> {
+        ensure!(amount > 0, ContractError::InvalidAmount);
+
+        let recovery_address = storage::try_recovery_address(
+            env,
+            MuxedAddress::from_string_bytes(&destination_address).address(),
+        )
+        .ok_or(ContractError::RecoveryAddressNotSet)?;
+
+        let token_config_value = Self::token_id_config(env, token_id.clone())?;
+        let amount = decimals::to_local_amount(
+            env,
+            token_id.clone(),
+            &token_config_value.token_address,
+            source_chain.clone(),
+            amount,
+        )?;
+
+        Self::ensure_inbound_transfer_compliant(
+            env,
+            &token_id,
+            &source_chain,
+            &source_address,
+            &recovery_address,
+            amount,
+        )?;
+
+        Self::record_token_transfer(env, token_id.clone());
+        FlowDirection::In.add_flow(env, token_id.clone(), source_chain.clone(), amount)?;
+
+        token_handler::give_token(
+            env,
+            &MuxedAddress::from(&recovery_address),
+            token_id.clone(),
+            token_config_value,
+            amount,
+        )?;
+
+        InterchainTransferRecoveredEvent {
+            source_chain,
+            message_id,
+            token_id,
+            destination_address,
+            recovery_address,
+            amount,
+        }
+        .emit(env);
+
+        Ok(())
+    }
+
+    /// Sends the tokens of the inbound transfer back to its source address.
+    ///
+    /// The tokens never arrived on this chain, so the amount encoded with the decimals of the source chain
+    /// is sent back as is, without taking tokens or recording flow on this chain.
+    fn return_token_to_sender(
+        env: &Env,
+        source_chain: String,
+        message_id: String,
+        InterchainTransfer {
+            token_id,
+            source_address,
+            destination_address,
+            amount,
+            ..
+        }: InterchainTransfer,
+        gas_token: Option<Token>,
+    ) -> Result<(), ContractError> {
+        ensure!(amount > 0, ContractError::InvalidAmount);
+
+        Self::token_id_config(env, token_id.clone())?;
+
+        Self::record_token_transfer(env, token_id.clone());
+
+        InterchainTransferReturnedEvent {
+            source_chain: source_chain.clone(),
+            message_id,
+            token_id: token_id.clone(),
+            source_address: source_address.clone(),
+            amount,
+        }
+        .emit(env);
+
+        let message = Message::InterchainTransfer(InterchainTransfer {
+            token_id,
+            source_address: destination_address,
+            destination_address: source_address,
+            amount,
+            data: None,
+        });
+
+        Self::pay_gas_and_call_contract(
+            env,
+            source_chain,
+            message,
+            gas_token.map(|gas_token| Self::gas_payment(env, Self::operator(env), gas_token)),
+        )
     }
 
     fn execute_deploy_message(
@@ -750,6 +2419,66 @@ impl InterchainTokenService {
         Ok(())
     }
 
+    fn execute_update_token_metadata_message(
+        env: &Env,
+        source_chain: String,
+        UpdateTokenMetadata {
+            token_id,
+            name,
+            symbol,
+        }: UpdateTokenMetadata,
+    ) -> Result<(), ContractError> {
+        let token_config = Self::token_id_config(env, token_id.clone())?;
+
+        // Only interchain tokens deployed by the service can be updated, since it is their owner
+        ensure!(
+            token_config.token_manager_type == TokenManagerType::NativeInterchainToken,
+            ContractError::InvalidTokenManagerType
+        );
+
+        // Tokens deployed on this chain can only be updated by their minters, not by remote chains
+        ensure!(
+            storage::try_token_deployer(env, token_id.clone()).is_none(),
+            ContractError::TokenOriginatesLocally
+        );
+
+        Self::update_local_token_metadata(
+            env,
+            &token_config.token_address,
+            name.clone(),
+            symbol.clone(),
+        )?;
+
+        TokenMetadataUpdateReceivedEvent {
+            source_chain,
+            token_id,
+            name,
+            symbol,
+        }
+        .emit(env);
+
+        Ok(())
+    }
+
+    /// Validates the new name and symbol of the interchain token and updates them as the owner of the token.
+    fn update_local_token_metadata(
+        env: &Env,
+        token_address: &Address,
+        name: String,
+        symbol: String,
+    ) -> Result<(), ContractError> {
+        let decimals = TokenClient::new(env, token_address).decimals();
+        let TokenMetadata { name, symbol, .. } = TokenMetadata::new(name, symbol, decimals)?;
+
+        InterchainTokenClient::new(env, token_address).update_metadata(
+            &env.current_contract_address(),
+            &name,
+            &symbol,
+        );
+
+        Ok(())
+    }
+
     fn execute_link_token_message(
         env: &Env,
         source_chain: String,
@@ -804,6 +2533,8 @@ impl InterchainTokenService {
             token_manager_type,
         );
 
+        registry::register_token(env, token_id.clone(), token_address.clone());
+
         Self::set_token_id_config(
             env,
             token_id,
@@ -870,16 +2601,65 @@ impl CustomAxelarExecutable for InterchainTokenService {
         source_address: String,
         payload: Bytes,
     ) -> Result<(), Self::Error> {
-        let (source_chain, message) =
-            Self::get_execute_params(env, source_chain, source_address, payload)?;
+        let payload_hash = express::payload_hash(env, &payload);
+        let express_execution_hash = express::express_execution_hash(
+            env,
+            source_chain.clone(),
+            message_id.clone(),
+            source_address.clone(),
+            payload_hash.clone(),
+        );
+
+        let (original_source_chain, message) =
+            Self::get_execute_params(env, source_chain.clone(), source_address.clone(), payload)?;
 
         match message {
             Message::InterchainTransfer(message) => {
-                Self::execute_transfer_message(env, &source_chain, message_id, message)
+                Self::ensure_token_not_paused(env, message.token_id.clone())?;
+
+                match express::take_express_execution(env, express_execution_hash) {
+                    Some(ExpressExecutionValue {
+                        express_executor,
+                        amount,
+                    }) => {
+                        let token_config_value =
+                            Self::token_id_config(env, message.token_id.clone())?;
+
+                        // The tokens were already fronted to the recipient, so the express executor is repaid instead
+                        token_handler::give_token(
+                            env,
+                            &MuxedAddress::from(&express_executor),
+                            message.token_id,
+                            token_config_value,
+                            amount,
+                        )?;
+
+                        ExpressExecutionFulfilledEvent {
+                            source_chain,
+                            message_id,
+                            source_address,
+                            payload_hash,
+                            express_executor,
+                        }
+                        .emit(env);
+
+                        Ok(())
+                    }
+                    None => Self::execute_transfer_message(
+                        env,
+                        &original_source_chain,
+                        message_id,
+                        message,
+                        None,
+                    ),
+                }
             }
             Message::DeployInterchainToken(message) => Self::execute_deploy_message(env, message),
             Message::LinkToken(message) => {
-                Self::execute_link_token_message(env, source_chain, message)
+                Self::execute_link_token_message(env, original_source_chain, message)
+            }
+            Message::UpdateTokenMetadata(message) => {
+                Self::execute_update_token_metadata_message(env, original_source_chain, message)
             }
         }?;
 
@@ -0,0 +1,139 @@
+use stellar_axelar_std::events::Event;
+use stellar_axelar_std::token::TokenClient;
+use stellar_axelar_std::{ensure, Address, BytesN, Env, String};
+
+use crate::error::ContractError;
+use crate::event::{DecimalScalingSetEvent, RemoteTokenDecimalsSetEvent};
+use crate::storage;
+
+pub fn remote_token_decimals(env: &Env, token_id: BytesN<32>, chain: String) -> Option<u32> {
+    storage::try_remote_token_decimals(env, token_id, chain)
+}
+
+pub fn set_remote_token_decimals(
+    env: &Env,
+    token_id: BytesN<32>,
+    chain: String,
+    decimals: u32,
+) -> Result<(), ContractError> {
+    // Decimals are encoded as u8 in hub messages
+    ensure!(
+        decimals <= u8::MAX as u32,
+        ContractError::InvalidTokenDecimals
+    );
+
+    storage::set_remote_token_decimals(env, token_id.clone(), chain.clone(), &decimals);
+
+    RemoteTokenDecimalsSetEvent {
+        token_id,
+        chain,
+        decimals,
+    }
+    .emit(env);
+
+    Ok(())
+}
+
+pub fn is_decimal_scaling_enabled(env: &Env, token_id: BytesN<32>) -> bool {
+    storage::is_decimal_scaling(env, token_id)
+}
+
+pub fn set_decimal_scaling(env: &Env, token_id: BytesN<32>, enabled: bool) {
+    if enabled {
+        storage::set_decimal_scaling_status(env, token_id.clone());
+    } else {
+        storage::remove_decimal_scaling_status(env, token_id.clone());
+    }
+
+    DecimalScalingSetEvent { token_id, enabled }.emit(env);
+}
+
+/// Validates an outbound `amount` against the decimals of the token on the destination `chain`,
+/// and returns the amount to encode in the interchain transfer.
+///
+/// If the remote decimals are unknown, the `amount` is passed through unchanged.
+/// Otherwise, an `amount` that cannot be represented with the remote decimals is rejected,
+/// and the amount is scaled to the remote decimals if decimal scaling is enabled for the token.
+pub fn to_remote_amount(
+    env: &Env,
+    token_id: BytesN<32>,
+    token_address: &Address,
+    chain: String,
+    amount: i128,
+) -> Result<i128, ContractError> {
+    let Some(remote_decimals) = remote_token_decimals(env, token_id.clone(), chain) else {
+        return Ok(amount);
+    };
+
+    let local_decimals = TokenClient::new(env, token_address).decimals();
+    let remote_amount = scale_amount(amount, local_decimals, remote_decimals)?;
+
+    if is_decimal_scaling_enabled(env, token_id) {
+        Ok(remote_amount)
+    } else {
+        Ok(amount)
+    }
+}
+
+/// Returns the smallest amount of the token that can be represented with its decimals on the destination `chain`.
+///
+/// Any amount can be represented if the remote decimals are unknown or not lower than the local decimals.
+pub fn remote_amount_unit(
+    env: &Env,
+    token_id: BytesN<32>,
+    token_address: &Address,
+    chain: String,
+) -> Result<i128, ContractError> {
+    let Some(remote_decimals) = remote_token_decimals(env, token_id, chain) else {
+        return Ok(1);
+    };
+
+    let local_decimals = TokenClient::new(env, token_address).decimals();
+
+    if remote_decimals >= local_decimals {
+        return Ok(1);
+    }
+
+    10i128
+        .checked_pow(local_decimals - remote_decimals)
+        .ok_or(ContractError::AmountPrecisionLoss)
+}
+
+/// Converts an inbound `amount` encoded with the decimals of the token on the source `chain`
+/// to the local decimals, if decimal scaling is enabled for the token and the remote decimals are known.
+pub fn to_local_amount(
+    env: &Env,
+    token_id: BytesN<32>,
+    token_address: &Address,
+    chain: String,
+    amount: i128,
+) -> Result<i128, ContractError> {
+    if !is_decimal_scaling_enabled(env, token_id.clone()) {
+        return Ok(amount);
+    }
+
+    let Some(remote_decimals) = remote_token_decimals(env, token_id, chain) else {
+        return Ok(amount);
+    };
+
+    let local_decimals = TokenClient::new(env, token_address).decimals();
+
+    scale_amount(amount, remote_decimals, local_decimals)
+}
+
+fn scale_amount(amount: i128, from_decimals: u32, to_decimals: u32) -> Result<i128, ContractError> {
+    if to_decimals >= from_decimals {
+        10i128
+            .checked_pow(to_decimals - from_decimals)
+            .and_then(|factor| amount.checked_mul(factor))
+            .ok_or(ContractError::InvalidAmount)
+    } else {
+        let factor = 10i128
+            .checked_pow(from_decimals - to_decimals)
+            .ok_or(ContractError::AmountPrecisionLoss)?;
+
+        ensure!(amount % factor == 0, ContractError::AmountPrecisionLoss);
+
+        Ok(amount / factor)
+    }
+}
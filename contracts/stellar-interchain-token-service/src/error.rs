@@ -40,4 +40,31 @@ pub enum ContractError {
     FlowAmountExceededLimit = 34,
     InvalidTokenManagerType = 35,
     InvalidDestinationTokenAddress = 36,
+    NotOperatorOrTokenDeployer = 37,
+    FlowLimiterAlreadyAdded = 38,
+    NotFlowLimiter = 39,
+    MessageAlreadyExecuted = 40,
+    ExpressExecutorAlreadySet = 41,
+    NotMinter = 42,
+    RemoteMinterNotApproved = 43,
+    AmountPrecisionLoss = 44,
+    EmptyDestinationChains = 45,
+    TokenPaused = 46,
+    TokenNotPaused = 47,
+    TrustedChainInboundDisabled = 48,
+    TrustedChainOutboundDisabled = 49,
+    TransferAmountExceedsChainLimit = 50,
+    DataTooLarge = 51,
+    RecoveryAddressNotSet = 52,
+    TransferBlocked = 53,
+    TokenManagerNotMinter = 54,
+    InvalidFeeRate = 55,
+    NotOwnerOrTokenAdmin = 56,
+    EmptyTokenAddresses = 57,
+    NotOwnerOrTokenDeployer = 58,
+    TokenAlreadyTransferred = 59,
+    TokenDeregistered = 60,
+    TokenManagerStillMinter = 61,
+    TokenOriginatesLocally = 62,
+    InterchainTokenFactoryAlreadySet = 63,
 }
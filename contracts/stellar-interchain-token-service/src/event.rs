@@ -1,8 +1,8 @@
 use core::fmt::Debug;
 
-use stellar_axelar_std::{Address, Bytes, BytesN, IntoEvent, String};
+use stellar_axelar_std::{Address, Bytes, BytesN, Error, IntoEvent, String};
 
-use crate::types::TokenManagerType;
+use crate::types::{TokenManagerType, TokenMetadataOverride, TrustedChainStatus};
 
 #[derive(Debug, PartialEq, Eq, IntoEvent)]
 pub struct TrustedChainSetEvent {
@@ -14,6 +14,21 @@ pub struct TrustedChainRemovedEvent {
     pub chain: String,
 }
 
+#[derive(Debug, PartialEq, Eq, IntoEvent)]
+pub struct TrustedChainConfigSetEvent {
+    pub chain: String,
+    #[data]
+    pub status: TrustedChainStatus,
+}
+
+#[derive(Debug, PartialEq, Eq, IntoEvent)]
+pub struct MaxTransferAmountSetEvent {
+    pub token_id: BytesN<32>,
+    pub chain: String,
+    /// A `None` value implies that the transfers of this `token_id` to and from this `chain` are not capped
+    pub max_transfer_amount: Option<i128>,
+}
+
 #[derive(Debug, PartialEq, Eq, IntoEvent)]
 pub struct FlowLimitSetEvent {
     pub token_id: BytesN<32>,
@@ -21,6 +36,81 @@ pub struct FlowLimitSetEvent {
     pub flow_limit: Option<i128>,
 }
 
+#[derive(Debug, PartialEq, Eq, IntoEvent)]
+pub struct ChainFlowLimitSetEvent {
+    pub token_id: BytesN<32>,
+    pub chain: String,
+    /// A `None` value implies that flow limit checks for this `chain` have been disabled for this `token_id`
+    pub flow_limit: Option<i128>,
+}
+
+#[derive(Debug, PartialEq, Eq, IntoEvent)]
+pub struct RemoteTokenDecimalsSetEvent {
+    pub token_id: BytesN<32>,
+    pub chain: String,
+    pub decimals: u32,
+}
+
+#[derive(Debug, PartialEq, Eq, IntoEvent)]
+pub struct DecimalScalingSetEvent {
+    pub token_id: BytesN<32>,
+    pub enabled: bool,
+}
+
+#[derive(Debug, PartialEq, Eq, IntoEvent)]
+pub struct GlobalComplianceHookSetEvent {
+    pub hook: Option<Address>,
+}
+
+#[derive(Debug, PartialEq, Eq, IntoEvent)]
+pub struct ComplianceHookSetEvent {
+    pub token_id: BytesN<32>,
+    pub hook: Option<Address>,
+}
+
+#[derive(Debug, PartialEq, Eq, IntoEvent)]
+pub struct InterchainTokenFactorySetEvent {
+    pub factory: Address,
+}
+
+#[derive(Debug, PartialEq, Eq, IntoEvent)]
+pub struct FeeReceiverSetEvent {
+    pub receiver: Option<Address>,
+}
+
+#[derive(Debug, PartialEq, Eq, IntoEvent)]
+pub struct DefaultFeeRateSetEvent {
+    pub rate: u32,
+}
+
+#[derive(Debug, PartialEq, Eq, IntoEvent)]
+pub struct FeeRateSetEvent {
+    pub token_id: BytesN<32>,
+    pub rate: Option<u32>,
+}
+
+#[derive(Debug, PartialEq, Eq, IntoEvent)]
+pub struct TokenPausedEvent {
+    pub token_id: BytesN<32>,
+}
+
+#[derive(Debug, PartialEq, Eq, IntoEvent)]
+pub struct TokenUnpausedEvent {
+    pub token_id: BytesN<32>,
+}
+
+#[derive(Debug, PartialEq, Eq, IntoEvent)]
+pub struct FlowLimiterAddedEvent {
+    pub token_id: BytesN<32>,
+    pub flow_limiter: Address,
+}
+
+#[derive(Debug, PartialEq, Eq, IntoEvent)]
+pub struct FlowLimiterRemovedEvent {
+    pub token_id: BytesN<32>,
+    pub flow_limiter: Address,
+}
+
 #[derive(Debug, PartialEq, Eq, IntoEvent)]
 pub struct InterchainTokenDeployedEvent {
     pub token_id: BytesN<32>,
@@ -39,6 +129,28 @@ pub struct TokenManagerDeployedEvent {
     pub token_manager_type: TokenManagerType,
 }
 
+#[derive(Debug, PartialEq, Eq, IntoEvent)]
+pub struct TokenManagerTypeMigratedEvent {
+    pub token_id: BytesN<32>,
+    pub old_token_manager_type: TokenManagerType,
+    pub new_token_manager_type: TokenManagerType,
+    pub burned_amount: i128,
+}
+
+#[derive(Debug, PartialEq, Eq, IntoEvent)]
+pub struct TokenExitedEvent {
+    pub token_id: BytesN<32>,
+    pub token_manager_type: TokenManagerType,
+    pub recipient: Address,
+    pub unlocked_amount: i128,
+}
+
+#[derive(Debug, PartialEq, Eq, IntoEvent)]
+pub struct TokenDeregisteredEvent {
+    pub token_id: BytesN<32>,
+    pub token_address: Address,
+}
+
 #[derive(Debug, PartialEq, Eq, IntoEvent)]
 #[event_name("token_deployment_started")]
 pub struct InterchainTokenDeploymentStartedEvent {
@@ -48,7 +160,51 @@ pub struct InterchainTokenDeploymentStartedEvent {
     pub name: String,
     pub symbol: String,
     pub decimals: u32,
-    pub minter: Option<Address>,
+    pub minter: Option<Bytes>,
+}
+
+#[derive(Debug, PartialEq, Eq, IntoEvent)]
+pub struct TokenMetadataUpdateStartedEvent {
+    pub token_id: BytesN<32>,
+    pub destination_chain: String,
+    pub name: String,
+    pub symbol: String,
+}
+
+#[derive(Debug, PartialEq, Eq, IntoEvent)]
+pub struct TokenMetadataUpdateReceivedEvent {
+    pub source_chain: String,
+    pub token_id: BytesN<32>,
+    pub name: String,
+    pub symbol: String,
+}
+
+#[derive(Debug, PartialEq, Eq, IntoEvent)]
+pub struct RemoteMinterApprovedEvent {
+    pub minter: Address,
+    pub token_id: BytesN<32>,
+    pub destination_chain: String,
+    pub destination_minter: Bytes,
+}
+
+#[derive(Debug, PartialEq, Eq, IntoEvent)]
+pub struct RemoteMinterRevokedEvent {
+    pub minter: Address,
+    pub token_id: BytesN<32>,
+    pub destination_chain: String,
+}
+
+#[derive(Debug, PartialEq, Eq, IntoEvent)]
+pub struct MintershipTransferredEvent {
+    pub token_id: BytesN<32>,
+    pub minter: Address,
+    pub new_minter: Address,
+}
+
+#[derive(Debug, PartialEq, Eq, IntoEvent)]
+pub struct MintershipRenouncedEvent {
+    pub token_id: BytesN<32>,
+    pub minter: Address,
 }
 
 #[derive(Debug, PartialEq, Eq, IntoEvent)]
@@ -57,6 +213,14 @@ pub struct TokenMetadataRegisteredEvent {
     pub decimals: u32,
 }
 
+#[derive(Debug, PartialEq, Eq, IntoEvent)]
+pub struct TokenMetadataOverrideSetEvent {
+    pub token_id: BytesN<32>,
+    pub token_address: Address,
+    #[data]
+    pub metadata: Option<TokenMetadataOverride>,
+}
+
 #[derive(Debug, PartialEq, Eq, IntoEvent)]
 pub struct LinkTokenStartedEvent {
     pub token_id: BytesN<32>,
@@ -86,6 +250,8 @@ pub struct InterchainTransferSentEvent {
     pub amount: i128,
     #[data]
     pub data_hash: Option<BytesN<32>>,
+    #[data]
+    pub fee: i128,
 }
 
 #[derive(Debug, PartialEq, Eq, IntoEvent)]
@@ -97,4 +263,66 @@ pub struct InterchainTransferReceivedEvent {
     pub amount: i128,
     #[data]
     pub data_hash: Option<BytesN<32>>,
+    #[data]
+    pub destination_mux_id: Option<u64>,
+}
+
+#[derive(Debug, PartialEq, Eq, IntoEvent)]
+pub struct NonRevertingExecutionSetEvent {
+    pub contract: Address,
+    pub enabled: bool,
+}
+
+#[derive(Debug, PartialEq, Eq, IntoEvent)]
+pub struct ContractExecutionFailedEvent {
+    pub source_chain: String,
+    pub message_id: String,
+    pub destination_address: Address,
+    pub token_id: BytesN<32>,
+    pub amount: i128,
+    #[data]
+    pub error: Error,
+}
+
+#[derive(Debug, PartialEq, Eq, IntoEvent)]
+pub struct RecoveryAddressSetEvent {
+    pub contract: Address,
+    pub recovery_address: Option<Address>,
+}
+
+#[derive(Debug, PartialEq, Eq, IntoEvent)]
+pub struct InterchainTransferRecoveredEvent {
+    pub source_chain: String,
+    pub message_id: String,
+    pub token_id: BytesN<32>,
+    pub destination_address: Bytes,
+    pub recovery_address: Address,
+    pub amount: i128,
+}
+
+#[derive(Debug, PartialEq, Eq, IntoEvent)]
+pub struct InterchainTransferReturnedEvent {
+    pub source_chain: String,
+    pub message_id: String,
+    pub token_id: BytesN<32>,
+    pub source_address: Bytes,
+    pub amount: i128,
+}
+
+#[derive(Debug, PartialEq, Eq, IntoEvent)]
+pub struct ExpressExecutedEvent {
+    pub source_chain: String,
+    pub message_id: String,
+    pub source_address: String,
+    pub payload_hash: BytesN<32>,
+    pub express_executor: Address,
+}
+
+#[derive(Debug, PartialEq, Eq, IntoEvent)]
+pub struct ExpressExecutionFulfilledEvent {
+    pub source_chain: String,
+    pub message_id: String,
+    pub source_address: String,
+    pub payload_hash: BytesN<32>,
+    pub express_executor: Address,
 }
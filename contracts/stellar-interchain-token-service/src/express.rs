@@ -0,0 +1,59 @@
+use stellar_axelar_std::xdr::ToXdr;
+use stellar_axelar_std::{Address, Bytes, BytesN, Env, String};
+
+use crate::storage::{self, ExpressExecutionValue};
+
+/// Computes the hash identifying a gateway message for express execution.
+///
+/// The hash commits to the full message, so an express executor is only repaid
+/// for the exact message it fronted the tokens for.
+pub fn express_execution_hash(
+    env: &Env,
+    source_chain: String,
+    message_id: String,
+    source_address: String,
+    payload_hash: BytesN<32>,
+) -> BytesN<32> {
+    env.crypto()
+        .keccak256(&(source_chain, message_id, source_address, payload_hash).to_xdr(env))
+        .into()
+}
+
+pub fn payload_hash(env: &Env, payload: &Bytes) -> BytesN<32> {
+    env.crypto().keccak256(payload).into()
+}
+
+pub fn express_executor(env: &Env, express_execution_hash: BytesN<32>) -> Option<Address> {
+    storage::try_express_executor(env, express_execution_hash)
+        .map(|express_execution| express_execution.express_executor)
+}
+
+pub fn set_express_executor(
+    env: &Env,
+    express_execution_hash: BytesN<32>,
+    express_executor: Address,
+    amount: i128,
+) {
+    storage::set_express_executor(
+        env,
+        express_execution_hash,
+        &ExpressExecutionValue {
+            express_executor,
+            amount,
+        },
+    );
+}
+
+/// Removes and returns the express execution of a message, if the message was express executed.
+pub fn take_express_execution(
+    env: &Env,
+    express_execution_hash: BytesN<32>,
+) -> Option<ExpressExecutionValue> {
+    let express_execution = storage::try_express_executor(env, express_execution_hash.clone());
+
+    if express_execution.is_some() {
+        storage::remove_express_executor(env, express_execution_hash);
+    }
+
+    express_execution
+}
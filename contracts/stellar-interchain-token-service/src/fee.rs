@@ -0,0 +1,97 @@
+use stellar_axelar_std::events::Event;
+use stellar_axelar_std::token::TokenClient;
+use stellar_axelar_std::{ensure, Address, BytesN, Env};
+
+use crate::error::ContractError;
+use crate::event::{DefaultFeeRateSetEvent, FeeRateSetEvent, FeeReceiverSetEvent};
+use crate::storage;
+
+/// Fee rates are expressed in basis points of the transferred amount.
+const MAX_FEE_RATE: u32 = 10_000;
+
+pub fn fee_receiver(env: &Env) -> Option<Address> {
+    storage::try_fee_receiver(env)
+}
+
+pub fn set_fee_receiver(env: &Env, receiver: Option<Address>) {
+    match receiver {
+        Some(ref receiver) => storage::set_fee_receiver(env, receiver),
+        None => storage::remove_fee_receiver(env),
+    }
+
+    FeeReceiverSetEvent { receiver }.emit(env);
+}
+
+pub fn default_fee_rate(env: &Env) -> u32 {
+    storage::try_default_fee_rate(env).unwrap_or(0)
+}
+
+pub fn set_default_fee_rate(env: &Env, rate: u32) -> Result<(), ContractError> {
+    ensure!(rate <= MAX_FEE_RATE, ContractError::InvalidFeeRate);
+
+    storage::set_default_fee_rate(env, &rate);
+
+    DefaultFeeRateSetEvent { rate }.emit(env);
+
+    Ok(())
+}
+
+pub fn fee_rate(env: &Env, token_id: BytesN<32>) -> Option<u32> {
+    storage::try_fee_rate(env, token_id)
+}
+
+pub fn set_fee_rate(
+    env: &Env,
+    token_id: BytesN<32>,
+    rate: Option<u32>,
+) -> Result<(), ContractError> {
+    match rate {
+        Some(rate) => {
+            ensure!(rate <= MAX_FEE_RATE, ContractError::InvalidFeeRate);
+
+            storage::set_fee_rate(env, token_id.clone(), &rate);
+        }
+        None => storage::remove_fee_rate(env, token_id.clone()),
+    }
+
+    FeeRateSetEvent { token_id, rate }.emit(env);
+
+    Ok(())
+}
+
+/// Charges the protocol fee on an outbound transfer of `amount` tokens from the `sender`,
+/// and returns the fee amount, which is transferred to the fee receiver.
+///
+/// No fee is charged while the fee receiver is not set. The fee is rounded down, but if a fee is charged,
+/// it is rounded up so that the remaining amount is a multiple of the `amount_unit`,
+/// i.e. the remaining amount can be represented on the destination chain.
+pub fn charge_fee(
+    env: &Env,
+    token_id: BytesN<32>,
+    token_address: &Address,
+    sender: &Address,
+    amount: i128,
+    amount_unit: i128,
+) -> Result<i128, ContractError> {
+    let Some(receiver) = fee_receiver(env) else {
+        return Ok(0);
+    };
+
+    let rate = fee_rate(env, token_id).unwrap_or_else(|| default_fee_rate(env));
+    let mut fee = amount
+        .checked_mul(rate as i128)
+        .ok_or(ContractError::InvalidAmount)?
+        / MAX_FEE_RATE as i128;
+
+    if fee > 0 {
+        fee += (amount - fee) % amount_unit;
+    }
+
+    ensure!(fee < amount, ContractError::InvalidAmount);
+
+    if fee > 0 {
+        TokenClient::new(env, token_address).transfer(sender, &receiver, &fee);
+    }
+
+    Ok(fee)
+}
@@ -1,8 +1,10 @@
 use stellar_axelar_std::events::Event;
-use stellar_axelar_std::{ensure, BytesN, Env};
+use stellar_axelar_std::{ensure, Address, BytesN, Env, String};
 
 use crate::error::ContractError;
-use crate::event::FlowLimitSetEvent;
+use crate::event::{
+    ChainFlowLimitSetEvent, FlowLimitSetEvent, FlowLimiterAddedEvent, FlowLimiterRemovedEvent,
+};
 use crate::storage;
 
 const EPOCH_TIME: u64 = 6 * 60 * 60; // 6 hours in seconds = 21600
@@ -36,10 +38,38 @@ impl FlowDirection {
         };
     }
 
+    fn chain_flow(&self, env: &Env, token_id: BytesN<32>, chain: String) -> i128 {
+        match self {
+            Self::In => flow_in_amount_for_chain(env, token_id, chain),
+            Self::Out => flow_out_amount_for_chain(env, token_id, chain),
+        }
+    }
+
+    fn reverse_chain_flow(&self, env: &Env, token_id: BytesN<32>, chain: String) -> i128 {
+        match self {
+            Self::In => flow_out_amount_for_chain(env, token_id, chain),
+            Self::Out => flow_in_amount_for_chain(env, token_id, chain),
+        }
+    }
+
+    fn update_chain_flow(&self, env: &Env, token_id: BytesN<32>, chain: String, new_flow: i128) {
+        match self {
+            Self::In => {
+                storage::set_chain_flow_in(env, token_id, chain, current_epoch(env), &new_flow)
+            }
+            Self::Out => {
+                storage::set_chain_flow_out(env, token_id, chain, current_epoch(env), &new_flow)
+            }
+        };
+    }
+
     /// Adds flow amount in the specified direction (in/out) for a token.
     /// Flow amounts are stored in temporary storage since they only need to persist for
     /// the 6-hour epoch duration.
     ///
+    /// The flow is checked against the global flow limit of the token, and additionally against the
+    /// flow limit set for the `chain` the transfer is coming from or going to, if any.
+    ///
     /// Checks that:
     /// - Flow amount doesn't exceed the flow limit
     /// - The flow in each direction doesn't exceed `i128::MAX`
@@ -48,39 +78,63 @@ impl FlowDirection {
         &self,
         env: &Env,
         token_id: BytesN<32>,
+        chain: String,
         flow_amount: i128,
     ) -> Result<(), ContractError> {
-        let Some(flow_limit) = flow_limit(env, token_id.clone()) else {
-            return Ok(());
-        };
+        if let Some(flow_limit) = flow_limit(env, token_id.clone()) {
+            let new_flow = new_flow(
+                flow_limit,
+                self.flow(env, token_id.clone()),
+                self.reverse_flow(env, token_id.clone()),
+                flow_amount,
+            )?;
 
-        ensure!(
-            flow_amount <= flow_limit,
-            ContractError::FlowAmountExceededLimit
-        );
+            self.update_flow(env, token_id.clone(), new_flow);
+        }
 
-        let flow = self.flow(env, token_id.clone());
-        let reverse_flow = self.reverse_flow(env, token_id.clone());
+        if let Some(flow_limit) = chain_flow_limit(env, token_id.clone(), chain.clone()) {
+            let new_flow = new_flow(
+                flow_limit,
+                self.chain_flow(env, token_id.clone(), chain.clone()),
+                self.reverse_chain_flow(env, token_id.clone(), chain.clone()),
+                flow_amount,
+            )?;
 
-        let new_flow = flow
-            .checked_add(flow_amount)
-            .ok_or(ContractError::FlowAmountOverflow)?;
+            self.update_chain_flow(env, token_id, chain, new_flow);
+        }
 
-        // Since `new_flow` and `reverse_flow` are both positive, there won't be any overflow
-        let net_flow = new_flow
-            .checked_sub(reverse_flow)
-            .expect("unexpected overflow");
+        Ok(())
+    }
+}
 
-        // `net_flow.abs()` can't overflow since `net_flow` can never be `i128::MIN` through subtracting two non-negative values
-        ensure!(
-            net_flow.abs() <= flow_limit,
-            ContractError::FlowLimitExceeded
-        );
+/// Returns the updated flow after adding `flow_amount` to `flow`, if it is within the `flow_limit`.
+fn new_flow(
+    flow_limit: i128,
+    flow: i128,
+    reverse_flow: i128,
+    flow_amount: i128,
+) -> Result<i128, ContractError> {
+    ensure!(
+        flow_amount <= flow_limit,
+        ContractError::FlowAmountExceededLimit
+    );
 
-        self.update_flow(env, token_id, new_flow);
+    let new_flow = flow
+        .checked_add(flow_amount)
+        .ok_or(ContractError::FlowAmountOverflow)?;
 
-        Ok(())
-    }
+    // Since `new_flow` and `reverse_flow` are both positive, there won't be any overflow
+    let net_flow = new_flow
+        .checked_sub(reverse_flow)
+        .expect("unexpected overflow");
+
+    // `net_flow.abs()` can't overflow since `net_flow` can never be `i128::MIN` through subtracting two non-negative values
+    ensure!(
+        net_flow.abs() <= flow_limit,
+        ContractError::FlowLimitExceeded
+    );
+
+    Ok(new_flow)
 }
 
 pub fn current_epoch(env: &Env) -> u64 {
@@ -113,6 +167,80 @@ pub fn set_flow_limit(
     Ok(())
 }
 
+pub fn is_flow_limiter(env: &Env, token_id: BytesN<32>, flow_limiter: Address) -> bool {
+    storage::is_flow_limiter(env, token_id, flow_limiter)
+}
+
+pub fn add_flow_limiter(
+    env: &Env,
+    token_id: BytesN<32>,
+    flow_limiter: Address,
+) -> Result<(), ContractError> {
+    ensure!(
+        !is_flow_limiter(env, token_id.clone(), flow_limiter.clone()),
+        ContractError::FlowLimiterAlreadyAdded
+    );
+
+    storage::set_flow_limiter_status(env, token_id.clone(), flow_limiter.clone());
+
+    FlowLimiterAddedEvent {
+        token_id,
+        flow_limiter,
+    }
+    .emit(env);
+
+    Ok(())
+}
+
+pub fn remove_flow_limiter(
+    env: &Env,
+    token_id: BytesN<32>,
+    flow_limiter: Address,
+) -> Result<(), ContractError> {
+    ensure!(
+        is_flow_limiter(env, token_id.clone(), flow_limiter.clone()),
+        ContractError::NotFlowLimiter
+    );
+
+    storage::remove_flow_limiter_status(env, token_id.clone(), flow_limiter.clone());
+
+    FlowLimiterRemovedEvent {
+        token_id,
+        flow_limiter,
+    }
+    .emit(env);
+
+    Ok(())
+}
+
+pub fn chain_flow_limit(env: &Env, token_id: BytesN<32>, chain: String) -> Option<i128> {
+    storage::try_chain_flow_limit(env, token_id, chain)
+}
+
+pub fn set_chain_flow_limit(
+    env: &Env,
+    token_id: BytesN<32>,
+    chain: String,
+    flow_limit: Option<i128>,
+) -> Result<(), ContractError> {
+    if let Some(flow_limit) = flow_limit {
+        ensure!(flow_limit >= 0, ContractError::InvalidFlowLimit);
+
+        storage::set_chain_flow_limit(env, token_id.clone(), chain.clone(), &flow_limit);
+    } else {
+        storage::remove_chain_flow_limit(env, token_id.clone(), chain.clone());
+    }
+
+    ChainFlowLimitSetEvent {
+        token_id,
+        chain,
+        flow_limit,
+    }
+    .emit(env);
+
+    Ok(())
+}
+
 pub fn flow_out_amount(env: &Env, token_id: BytesN<32>) -> i128 {
     storage::try_flow_out(env, token_id, current_epoch(env)).unwrap_or(0)
 }
@@ -120,3 +248,11 @@ pub fn flow_out_amount(env: &Env, token_id: BytesN<32>) -> i128 {
 pub fn flow_in_amount(env: &Env, token_id: BytesN<32>) -> i128 {
     storage::try_flow_in(env, token_id, current_epoch(env)).unwrap_or(0)
 }
+
+pub fn flow_out_amount_for_chain(env: &Env, token_id: BytesN<32>, chain: String) -> i128 {
+    storage::try_chain_flow_out(env, token_id, chain, current_epoch(env)).unwrap_or(0)
+}
+
+pub fn flow_in_amount_for_chain(env: &Env, token_id: BytesN<32>, chain: String) -> i128 {
+    storage::try_chain_flow_in(env, token_id, chain, current_epoch(env)).unwrap_or(0)
+}
@@ -4,10 +4,13 @@ use stellar_axelar_std::interfaces::{
     OperatableInterface, OwnableInterface, PausableInterface, UpgradableInterface,
 };
 use stellar_axelar_std::types::Token;
-use stellar_axelar_std::{contractclient, soroban_sdk, Address, Bytes, BytesN, Env, String};
+use stellar_axelar_std::{contractclient, soroban_sdk, Address, Bytes, BytesN, Env, String, Vec};
 
 use crate::error::ContractError;
-use crate::types::TokenManagerType;
+use crate::types::{
+    GasPayment, LockedBalance, TokenInfo, TokenManagerType, TokenMetadataOverride,
+    TransferRecovery, TrustedChainConfig,
+};
 
 #[contractclient(name = "InterchainTokenServiceClient")]
 pub trait InterchainTokenServiceInterface:
@@ -47,15 +50,78 @@ pub trait InterchainTokenServiceInterface:
     fn set_trusted_chain(env: &Env, chain: String) -> Result<(), ContractError>;
 
     /// Removes the specified chain from trusted chains.
+    ///
+    /// The config of the chain is retained, so it remains known as a previously trusted chain,
+    /// and its settings apply again if it is trusted again.
+    ///
     /// # Authorization
     /// - [`OperatableInterface::operator`] must authorize.
     fn remove_trusted_chain(env: &Env, chain: String) -> Result<(), ContractError>;
 
+    /// Returns the settings applied to the interchain traffic with the specified trusted chain,
+    /// or the retained settings of a previously trusted chain.
+    ///
+    /// # Errors
+    /// - [`ContractError::TrustedChainNotSet`]: If the chain has never been trusted.
+    fn trusted_chain_config(env: &Env, chain: String) -> Result<TrustedChainConfig, ContractError>;
+
+    /// Sets the settings applied to the interchain traffic with the specified trusted chain.
+    ///
+    /// The status restricts the direction of the messages sent to and received from the chain,
+    /// without removing the chain from the trusted chains.
+    ///
+    /// # Arguments
+    /// - `chain`: The trusted chain.
+    /// - `config`: The status of the chain.
+    ///
+    /// # Errors
+    /// - [`ContractError::TrustedChainNotSet`]: If the chain is not trusted.
+    ///
+    /// # Authorization
+    /// - [`OperatableInterface::operator`] must authorize.
+    fn set_trusted_chain_config(
+        env: &Env,
+        chain: String,
+        config: TrustedChainConfig,
+    ) -> Result<(), ContractError>;
+
+    /// Returns the maximum amount of a single interchain transfer of the token associated with the specified token ID
+    /// to or from the specified chain.
+    /// Returns `None` if transfers are not capped.
+    fn max_transfer_amount(env: &Env, token_id: BytesN<32>, chain: String) -> Option<i128>;
+
+    /// Sets or removes the maximum amount of a single interchain transfer of a token to or from a specific chain.
+    ///
+    /// The maximum applies to the amount encoded in the interchain transfer messages exchanged with the chain,
+    /// i.e. in the remote decimals of the token if decimal scaling is enabled.
+    ///
+    /// # Arguments
+    /// - `token_id`: Unique identifier of the token.
+    /// - `chain`: The name of the remote chain the maximum applies to.
+    /// - `max_transfer_amount`: The maximum transfer amount. Must be positive if Some.
+    ///
+    /// # Errors
+    /// - [`ContractError::InvalidAmount`]: If the maximum transfer amount is not positive.
+    ///
+    /// # Authorization
+    /// - [`OperatableInterface::operator`] must authorize.
+    fn set_max_transfer_amount(
+        env: &Env,
+        token_id: BytesN<32>,
+        chain: String,
+        max_transfer_amount: Option<i128>,
+    ) -> Result<(), ContractError>;
+
     /// Computes the unique identifier for an interchain token.
     ///
     /// The token ID is derived uniquely from the deployer's address and the provided salt.
     ///
     /// # Parameters
+    /// If the deployer is the [`InterchainTokenServiceInterface::interchain_token_factory`], the salt is the
+    /// factory-scoped deploy salt, and the token ID is derived as by the EVM `InterchainTokenService`
+    /// for the `TOKEN_FACTORY_DEPLOYER`, so that it is compatible with the EVM `InterchainTokenFactory`.
+    ///
+    /// # Parameters
     /// - `deployer`: The address of the token deployer. In the case of tokens deployed by this contract, it will be Stellar's "dead" address.
     /// - `salt`: A unique value used to generate the token ID.
     ///
@@ -83,6 +149,21 @@ pub trait InterchainTokenServiceInterface:
     /// - `salt`: A unique value used to generate the token ID.
     fn linked_token_id(env: &Env, deployer: Address, salt: BytesN<32>) -> BytesN<32>;
 
+    /// Returns the address of the interchain token factory, if set.
+    fn interchain_token_factory(env: &Env) -> Option<Address>;
+
+    /// Sets the address of the interchain token factory, whose tokens get EVM-compatible token IDs.
+    /// See [`InterchainTokenServiceInterface::interchain_token_id`].
+    ///
+    /// The factory can only be set once, since changing it would change the token IDs of its tokens.
+    ///
+    /// # Errors
+    /// - [`ContractError::InterchainTokenFactoryAlreadySet`]: If the factory is already set.
+    ///
+    /// # Authorization
+    /// - [`OwnableInterface::owner`] must authorize.
+    fn set_interchain_token_factory(env: &Env, factory: Address) -> Result<(), ContractError>;
+
     /// Returns the predicted address of the native interchain token associated with the specified token ID.
     ///
     /// # Arguments
@@ -110,6 +191,100 @@ pub trait InterchainTokenServiceInterface:
     /// Returns the type of the token manager associated with the specified token ID.
     fn token_manager_type(env: &Env, token_id: BytesN<32>) -> TokenManagerType;
 
+    /// Returns the aggregated state of the token associated with the specified token ID.
+    ///
+    /// # Arguments
+    /// - `token_id`: The token ID of the registered token.
+    ///
+    /// # Returns
+    /// - `Ok(TokenInfo)`: The token address, token manager, token manager type, token metadata,
+    ///   flow limit, and the flow amounts of the current epoch.
+    ///
+    /// # Errors
+    /// - [`ContractError::InvalidTokenId`]: If the token ID does not exist in the persistent storage.
+    /// - Any error propagated from retrieving the token metadata.
+    fn token_info(env: &Env, token_id: BytesN<32>) -> Result<TokenInfo, ContractError>;
+
+    /// Returns the aggregated state of the token associated with the specified token ID,
+    /// or `None` if the token ID is not registered.
+    ///
+    /// See [`InterchainTokenServiceInterface::token_info`].
+    ///
+    /// # Errors
+    /// - Any error propagated from retrieving the token metadata.
+    fn registered_token_info(
+        env: &Env,
+        token_id: BytesN<32>,
+    ) -> Result<Option<TokenInfo>, ContractError>;
+
+    /// Returns the number of tokens registered with the interchain token service.
+    fn registered_token_count(env: &Env) -> u32;
+
+    /// Returns the registered token IDs in registration order.
+    ///
+    /// Deregistering a token moves the last registered token ID to the index of the deregistered one.
+    ///
+    /// # Arguments
+    /// - `start`: The index of the first token ID to return.
+    /// - `limit`: The maximum number of token IDs to return, capped at 50.
+    fn registered_token_ids(env: &Env, start: u32, limit: u32) -> Vec<BytesN<32>>;
+
+    /// Adds the tokens registered before the upgrade that introduced the token registry to the registry,
+    /// and sets the locked amount of `LockUnlock` tokens to the balance of their token manager.
+    ///
+    /// The backfill is only allowed during the migration after the upgrade, and can be split across multiple calls
+    /// to stay within the resource limits of a single invocation. The last batch is passed to `migrate`,
+    /// which completes the migration. Tokens that are already in the registry are skipped.
+    ///
+    /// # Arguments
+    /// - `token_ids`: The IDs of the tokens to add to the registry.
+    ///
+    /// # Errors
+    /// - [`ContractError::MigrationNotAllowed`]: If the contract is not being migrated.
+    /// - [`ContractError::InvalidTokenId`]: If any of the token IDs is not registered.
+    ///
+    /// # Authorization
+    /// - [`OwnableInterface::owner`] must authorize.
+    fn backfill_registry(env: &Env, token_ids: Vec<BytesN<32>>) -> Result<(), ContractError>;
+
+    /// Returns the number of token IDs registered for the specified token address.
+    fn token_id_count_by_address(env: &Env, token_address: Address) -> u32;
+
+    /// Returns the token IDs registered for the specified token address.
+    ///
+    /// A token address can be registered under multiple token IDs, e.g. as a canonical token and as a custom token.
+    ///
+    /// # Arguments
+    /// - `token_address`: The address of the token.
+    /// - `start`: The index of the first token ID to return.
+    /// - `limit`: The maximum number of token IDs to return, capped at 50.
+    fn token_ids_by_address(
+        env: &Env,
+        token_address: Address,
+        start: u32,
+        limit: u32,
+    ) -> Vec<BytesN<32>>;
+
+    /// Returns the net amount of tokens locked in the token manager by interchain transfers
+    /// for the `LockUnlock` token associated with the specified token ID.
+    ///
+    /// Tokens sent to the token manager directly are not included.
+    fn locked_amount(env: &Env, token_id: BytesN<32>) -> i128;
+
+    /// Compares the locked amount of the `LockUnlock` token associated with the specified token ID
+    /// with the actual token balance of its token manager.
+    ///
+    /// # Arguments
+    /// - `token_id`: The token ID of the registered token.
+    ///
+    /// # Returns
+    /// - `Ok(LockedBalance)`: The locked amount, the token manager balance, and the surplus of the balance over the locked amount.
+    ///
+    /// # Errors
+    /// - [`ContractError::InvalidTokenId`]: If the token ID does not exist in the persistent storage.
+    /// - [`ContractError::InvalidTokenManagerType`]: If the token manager type is not `LockUnlock`.
+    fn locked_balance(env: &Env, token_id: BytesN<32>) -> Result<LockedBalance, ContractError>;
+
     /// Returns the flow limit for the token associated with the specified token ID.
     /// Returns `None` if no limit is set.
     fn flow_limit(env: &Env, token_id: BytesN<32>) -> Option<i128>;
@@ -124,76 +299,505 @@ pub trait InterchainTokenServiceInterface:
 
     /// Sets or updates the flow limit for a token.
     ///
-    /// Flow limit controls how many tokens can flow in/out during a single epoch.
-    /// Setting the limit to `None` disables flow limit checks for the token.
-    /// Setting the limit to 0 effectively freezes the token by preventing any flow.
+    /// Flow limit controls how many tokens can flow in/out during a single epoch.
+    /// Setting the limit to `None` disables flow limit checks for the token.
+    /// Setting the limit to 0 effectively freezes the token by preventing any flow.
+    ///
+    /// # Arguments
+    /// - `token_id`: Unique identifier of the token.
+    /// - `flow_limit`: The new flow limit value. Must be positive if Some.
+    ///
+    /// # Errors
+    /// - [`ContractError::InvalidFlowLimit`]: If the provided flow limit is not positive.
+    ///
+    /// # Authorization
+    /// - [`OperatableInterface::operator`] must authorize.
+    fn set_flow_limit(
+        env: &Env,
+        token_id: BytesN<32>,
+        flow_limit: Option<i128>,
+    ) -> Result<(), ContractError>;
+
+    /// Sets or updates the flow limits for multiple tokens.
+    ///
+    /// This allows flow limiters of a token to manage its flow limits without holding the
+    /// [`OperatableInterface::operator`] key. See [`InterchainTokenServiceInterface::set_flow_limit`]
+    /// for the semantics of each flow limit value.
+    ///
+    /// # Arguments
+    /// - `caller`: The address setting the flow limits.
+    /// - `flow_limits`: A list of token IDs and their new flow limit values.
+    ///
+    /// # Errors
+    /// - [`ContractError::NotFlowLimiter`]: If the caller is neither the operator nor a flow limiter of one of the tokens.
+    /// - [`ContractError::InvalidFlowLimit`]: If one of the provided flow limits is not positive.
+    ///
+    /// # Authorization
+    /// - The `caller` must authorize.
+    fn set_flow_limits(
+        env: &Env,
+        caller: Address,
+        flow_limits: Vec<(BytesN<32>, Option<i128>)>,
+    ) -> Result<(), ContractError>;
+
+    /// Returns the address that deployed or registered the token associated with the specified token ID on this chain.
+    /// Returns `None` for tokens without a local deployer, e.g. canonical tokens or tokens deployed from a remote chain.
+    fn token_deployer(env: &Env, token_id: BytesN<32>) -> Option<Address>;
+
+    /// Returns whether the specified address is a flow limiter for the token associated with the specified token ID.
+    fn is_flow_limiter(env: &Env, token_id: BytesN<32>, flow_limiter: Address) -> bool;
+
+    /// Grants the flow limiter role of a token to the specified address.
+    ///
+    /// # Arguments
+    /// - `caller`: The address granting the role. Must be the operator or the deployer of the token.
+    /// - `token_id`: Unique identifier of the token.
+    /// - `flow_limiter`: The address receiving the flow limiter role.
+    ///
+    /// # Errors
+    /// - [`ContractError::NotOperatorOrTokenDeployer`]: If the caller is neither the operator nor the token deployer.
+    /// - [`ContractError::FlowLimiterAlreadyAdded`]: If the address is already a flow limiter for the token.
+    ///
+    /// # Authorization
+    /// - The `caller` must authorize.
+    fn add_flow_limiter(
+        env: &Env,
+        caller: Address,
+        token_id: BytesN<32>,
+        flow_limiter: Address,
+    ) -> Result<(), ContractError>;
+
+    /// Revokes the flow limiter role of a token from the specified address.
+    ///
+    /// # Arguments
+    /// - `caller`: The address revoking the role. Must be the operator or the deployer of the token.
+    /// - `token_id`: Unique identifier of the token.
+    /// - `flow_limiter`: The address losing the flow limiter role.
+    ///
+    /// # Errors
+    /// - [`ContractError::NotOperatorOrTokenDeployer`]: If the caller is neither the operator nor the token deployer.
+    /// - [`ContractError::NotFlowLimiter`]: If the address is not a flow limiter for the token.
+    ///
+    /// # Authorization
+    /// - The `caller` must authorize.
+    fn remove_flow_limiter(
+        env: &Env,
+        caller: Address,
+        token_id: BytesN<32>,
+        flow_limiter: Address,
+    ) -> Result<(), ContractError>;
+
+    /// Returns the flow limit for the token associated with the specified token ID on transfers
+    /// to and from the specified chain.
+    /// Returns `None` if no limit is set.
+    fn chain_flow_limit(env: &Env, token_id: BytesN<32>, chain: String) -> Option<i128>;
+
+    /// Returns the amount that has flowed out of the chain to the specified chain during the current epoch
+    /// for the token associated with the specified token ID.
+    fn flow_out_amount_for_chain(env: &Env, token_id: BytesN<32>, chain: String) -> i128;
+
+    /// Retrieves the amount that has flowed into the chain from the specified chain during the current epoch
+    /// for the token associated with the specified token ID.
+    fn flow_in_amount_for_chain(env: &Env, token_id: BytesN<32>, chain: String) -> i128;
+
+    /// Sets or updates the flow limit for a token on transfers to and from a specific chain.
+    ///
+    /// The chain flow limit is enforced in addition to the global flow limit of the token,
+    /// so a single chain cannot use up the whole global limit.
+    /// Setting the limit to `None` disables the chain flow limit checks for the token.
+    /// Setting the limit to 0 effectively freezes the token on that chain.
+    ///
+    /// # Arguments
+    /// - `token_id`: Unique identifier of the token.
+    /// - `chain`: The name of the remote chain the limit applies to.
+    /// - `flow_limit`: The new flow limit value. Must be positive if Some.
+    ///
+    /// # Errors
+    /// - [`ContractError::InvalidFlowLimit`]: If the provided flow limit is not positive.
+    ///
+    /// # Authorization
+    /// - [`OperatableInterface::operator`] must authorize.
+    fn set_chain_flow_limit(
+        env: &Env,
+        token_id: BytesN<32>,
+        chain: String,
+        flow_limit: Option<i128>,
+    ) -> Result<(), ContractError>;
+
+    /// Returns the decimals of the token associated with the specified token ID on the specified remote chain, if known.
+    ///
+    /// Remote decimals are recorded when the token is deployed to a remote chain, or linked to a remote token
+    /// via [`InterchainTokenServiceInterface::link_token`] with known destination token decimals.
+    /// Links received from a remote chain don't carry the decimals of the source token,
+    /// so they must be set via [`InterchainTokenServiceInterface::set_remote_token_decimals`].
+    fn remote_token_decimals(env: &Env, token_id: BytesN<32>, chain: String) -> Option<u32>;
+
+    /// Sets the decimals of the token associated with the specified token ID on the specified remote chain.
+    ///
+    /// Once the remote decimals are known, outbound transfers of amounts that cannot be represented
+    /// with the remote decimals are rejected.
+    ///
+    /// # Arguments
+    /// - `caller`: The operator or the deployer of the token.
+    /// - `token_id`: Unique identifier of the token.
+    /// - `chain`: The name of the remote chain.
+    /// - `decimals`: The decimals of the token on the remote chain.
+    ///
+    /// # Errors
+    /// - [`ContractError::NotOperatorOrTokenDeployer`]: If the caller is neither the operator nor the token deployer.
+    /// - [`ContractError::InvalidTokenDecimals`]: If the decimals exceed the maximum supported by hub messages.
+    ///
+    /// # Authorization
+    /// - The `caller` must authorize.
+    fn set_remote_token_decimals(
+        env: &Env,
+        caller: Address,
+        token_id: BytesN<32>,
+        chain: String,
+        decimals: u32,
+    ) -> Result<(), ContractError>;
+
+    /// Returns whether amounts of the token associated with the specified token ID are scaled
+    /// between the local and remote decimals on interchain transfers.
+    fn is_decimal_scaling_enabled(env: &Env, token_id: BytesN<32>) -> bool;
+
+    /// Enables or disables decimal scaling for the token associated with the specified token ID.
+    ///
+    /// When enabled, outbound amounts are encoded with the remote decimals of the destination chain,
+    /// and inbound amounts are converted from the remote decimals of the source chain to the local decimals.
+    /// Chains without known remote decimals are not scaled.
+    ///
+    /// # Arguments
+    /// - `caller`: The operator or the deployer of the token.
+    /// - `token_id`: Unique identifier of the token.
+    /// - `enabled`: Whether decimal scaling is enabled.
+    ///
+    /// # Errors
+    /// - [`ContractError::NotOperatorOrTokenDeployer`]: If the caller is neither the operator nor the token deployer.
+    ///
+    /// # Authorization
+    /// - The `caller` must authorize.
+    fn set_decimal_scaling(
+        env: &Env,
+        caller: Address,
+        token_id: BytesN<32>,
+        enabled: bool,
+    ) -> Result<(), ContractError>;
+
+    /// Returns the compliance hook consulted for the interchain transfers of all tokens without their own hook.
+    fn global_compliance_hook(env: &Env) -> Option<Address>;
+
+    /// Sets or removes the compliance hook consulted for the interchain transfers of all tokens without their own hook.
+    ///
+    /// See [`InterchainTokenServiceInterface::set_compliance_hook`].
+    ///
+    /// # Authorization
+    /// - [`OperatableInterface::operator`] must authorize.
+    fn set_global_compliance_hook(env: &Env, hook: Option<Address>);
+
+    /// Returns the compliance hook consulted for the interchain transfers of the token associated with the specified token ID.
+    fn compliance_hook(env: &Env, token_id: BytesN<32>) -> Option<Address>;
+
+    /// Sets or removes the compliance hook consulted for the interchain transfers of the token associated with the specified token ID.
+    ///
+    /// The hook implements [`crate::compliance::ComplianceHookInterface`] and can veto outbound interchain transfers
+    /// as well as the inbound execution of interchain transfers. A token hook takes precedence over the global hook.
+    ///
+    /// # Arguments
+    /// - `caller`: The operator or the deployer of the token.
+    /// - `token_id`: Unique identifier of the token.
+    /// - `hook`: The address of the compliance hook contract, or `None` to remove it.
+    ///
+    /// # Errors
+    /// - [`ContractError::NotOperatorOrTokenDeployer`]: If the caller is neither the operator nor the token deployer.
+    ///
+    /// # Authorization
+    /// - The `caller` must authorize.
+    fn set_compliance_hook(
+        env: &Env,
+        caller: Address,
+        token_id: BytesN<32>,
+        hook: Option<Address>,
+    ) -> Result<(), ContractError>;
+
+    /// Returns the receiver of the protocol fee charged on outbound interchain transfers.
+    fn fee_receiver(env: &Env) -> Option<Address>;
+
+    /// Sets or removes the receiver of the protocol fee charged on outbound interchain transfers.
+    ///
+    /// No fee is charged while the fee receiver is not set.
+    ///
+    /// # Authorization
+    /// - [`OwnableInterface::owner`] must authorize.
+    fn set_fee_receiver(env: &Env, receiver: Option<Address>);
+
+    /// Returns the protocol fee rate in basis points charged on outbound interchain transfers of tokens without their own rate.
+    fn default_fee_rate(env: &Env) -> u32;
+
+    /// Sets the protocol fee rate in basis points charged on outbound interchain transfers of tokens without their own rate.
+    ///
+    /// # Errors
+    /// - [`ContractError::InvalidFeeRate`]: If the rate exceeds 10,000 basis points.
+    ///
+    /// # Authorization
+    /// - [`OwnableInterface::owner`] must authorize.
+    fn set_default_fee_rate(env: &Env, rate: u32) -> Result<(), ContractError>;
+
+    /// Returns the protocol fee rate in basis points of the token associated with the specified token ID, if set.
+    fn fee_rate(env: &Env, token_id: BytesN<32>) -> Option<u32>;
+
+    /// Sets or removes the protocol fee rate in basis points of the token associated with the specified token ID.
+    ///
+    /// The fee is deducted from the amount of outbound interchain transfers before it is sent to the destination chain,
+    /// and transferred from the sender to the [`InterchainTokenServiceInterface::fee_receiver`].
+    /// A token rate takes precedence over the [`InterchainTokenServiceInterface::default_fee_rate`].
+    ///
+    /// # Arguments
+    /// - `token_id`: Unique identifier of the token.
+    /// - `rate`: The fee rate in basis points, or `None` to fall back to the default rate.
+    ///
+    /// # Errors
+    /// - [`ContractError::InvalidFeeRate`]: If the rate exceeds 10,000 basis points.
+    ///
+    /// # Authorization
+    /// - [`OwnableInterface::owner`] must authorize.
+    fn set_fee_rate(
+        env: &Env,
+        token_id: BytesN<32>,
+        rate: Option<u32>,
+    ) -> Result<(), ContractError>;
+
+    /// Returns whether interchain transfers of the token associated with the specified token ID are paused.
+    fn is_token_paused(env: &Env, token_id: BytesN<32>) -> bool;
+
+    /// Returns whether a token is registered under the specified token ID.
+    fn is_token_registered(env: &Env, token_id: BytesN<32>) -> bool;
+
+    /// Returns whether the specified token ID has been deregistered, and can no longer be registered.
+    fn is_token_deregistered(env: &Env, token_id: BytesN<32>) -> bool;
+
+    /// Pauses interchain transfers of the token associated with the specified token ID.
+    ///
+    /// While paused, outbound interchain transfers as well as the inbound execution and express execution
+    /// of interchain transfers of the token are rejected. Other tokens are not affected.
+    ///
+    /// # Arguments
+    /// - `token_id`: Unique identifier of the token.
+    ///
+    /// # Errors
+    /// - [`ContractError::InvalidTokenId`]: If the token ID does not exist in the persistent storage.
+    /// - [`ContractError::TokenPaused`]: If the token is already paused.
+    ///
+    /// # Authorization
+    /// - [`OperatableInterface::operator`] must authorize.
+    fn pause_token(env: &Env, token_id: BytesN<32>) -> Result<(), ContractError>;
+
+    /// Unpauses interchain transfers of the token associated with the specified token ID.
+    ///
+    /// # Arguments
+    /// - `token_id`: Unique identifier of the token.
+    ///
+    /// # Errors
+    /// - [`ContractError::TokenNotPaused`]: If the token is not paused.
+    ///
+    /// # Authorization
+    /// - [`OperatableInterface::operator`] must authorize.
+    fn unpause_token(env: &Env, token_id: BytesN<32>) -> Result<(), ContractError>;
+
+    /// Deploys a new interchain token on the current chain with specified metadata and optional
+    /// initial supply. If initial supply is provided, it is minted to the caller. The
+    /// caller can also specify an optional minter address for the interchain token.
+    ///
+    /// # Arguments
+    /// - `caller`: Address of the caller initiating the deployment.
+    /// - `salt`: A 32-byte unique salt used for token deployment.
+    /// - `token_metadata`: Metadata for the new token (name, symbol, decimals).
+    /// - `initial_supply`: Initial amount to mint to caller, if greater than 0.
+    /// - `minter`: Optional address that will have a minter role for the deployed interchain token.
+    ///
+    /// # Returns
+    /// - `Ok(BytesN<32>)`: Returns the token ID.
+    ///
+    /// # Errors
+    /// - [`ContractError::InvalidMinter`]: If the minter address is invalid.
+    ///
+    /// # Authorization
+    /// - The `deployer` must authorize.
+    fn deploy_interchain_token(
+        env: &Env,
+        deployer: Address,
+        salt: BytesN<32>,
+        token_metadata: TokenMetadata,
+        initial_supply: i128,
+        minter: Option<Address>,
+    ) -> Result<BytesN<32>, ContractError>;
+
+    /// Initiates the deployment of an interchain token to a specified destination chain.
+    ///
+    /// # Arguments
+    /// - `caller`: Address of the caller initiating the deployment.
+    /// - `salt`: A 32-byte unique salt used for token deployment.
+    /// - `destination_chain`: The name of the destination chain where the token will be deployed.
+    /// - `gas_token`: An optional gas token used to pay for the gas cost of the cross-chain call.
+    ///
+    /// # Returns
+    /// - `Ok(BytesN<32>)`: Returns the token ID.
+    ///
+    /// # Errors
+    /// - [`ContractError::InvalidTokenId`]: If the token ID does not exist in the persistent storage.
+    /// - Any error propagated from `pay_gas_and_call_contract`.
+    ///
+    /// # Authorization
+    /// - The `caller` must authorize.
+    fn deploy_remote_interchain_token(
+        env: &Env,
+        caller: Address,
+        salt: BytesN<32>,
+        destination_chain: String,
+        gas_token: Option<Token>,
+    ) -> Result<BytesN<32>, ContractError>;
+
+    /// Initiates the deployment of an interchain token to multiple destination chains.
+    ///
+    /// A [`crate::event::InterchainTokenDeploymentStartedEvent`] is emitted for each destination chain.
+    /// The deployment fails as a whole if any of the destination chains is not trusted.
+    ///
+    /// # Arguments
+    /// - `caller`: Address of the caller initiating the deployments.
+    /// - `salt`: A 32-byte unique salt used for token deployment.
+    /// - `destinations`: The destination chains, each with an optional gas token used to pay for the cross-chain call.
+    ///
+    /// # Returns
+    /// - `Ok(BytesN<32>)`: Returns the token ID.
+    ///
+    /// # Errors
+    /// - [`ContractError::EmptyDestinationChains`]: If no destination chains are provided.
+    /// - [`ContractError::UntrustedChain`]: If any of the destination chains is not trusted.
+    /// - [`ContractError::InvalidTokenId`]: If the token ID does not exist in the persistent storage.
+    /// - Any error propagated from `pay_gas_and_call_contract`.
+    ///
+    /// # Authorization
+    /// - The `caller` must authorize.
+    fn deploy_remote_interchain_tokens(
+        env: &Env,
+        caller: Address,
+        salt: BytesN<32>,
+        destinations: Vec<(String, Option<Token>)>,
+    ) -> Result<BytesN<32>, ContractError>;
+
+    /// Approves a destination minter for a remote deployment of an interchain token with a minter.
+    ///
+    /// The approval is consumed by [`InterchainTokenServiceInterface::deploy_remote_token_with_minter`],
+    /// ensuring the `minter` has consented to granting minter rights to `destination_minter` on the `destination_chain`.
     ///
     /// # Arguments
-    /// - `token_id`: Unique identifier of the token.
-    /// - `flow_limit`: The new flow limit value. Must be positive if Some.
+    /// - `minter`: Address of a minter of the interchain token.
+    /// - `deployer`: Address of the deployer of the interchain token.
+    /// - `salt`: The salt used to deploy the interchain token.
+    /// - `destination_chain`: The name of the destination chain.
+    /// - `destination_minter`: The minter address on the destination chain, encoded as bytes.
     ///
     /// # Errors
-    /// - [`ContractError::InvalidFlowLimit`]: If the provided flow limit is not positive.
+    /// - [`ContractError::InvalidTokenId`]: If the token ID does not exist in the persistent storage.
+    /// - [`ContractError::NotMinter`]: If `minter` is not a minter of the interchain token.
     ///
     /// # Authorization
-    /// - [`OperatableInterface::operator`] must authorize.
-    fn set_flow_limit(
+    /// - The `minter` must authorize.
+    fn approve_remote_minter(
         env: &Env,
-        token_id: BytesN<32>,
-        flow_limit: Option<i128>,
+        minter: Address,
+        deployer: Address,
+        salt: BytesN<32>,
+        destination_chain: String,
+        destination_minter: Bytes,
     ) -> Result<(), ContractError>;
 
-    /// Deploys a new interchain token on the current chain with specified metadata and optional
-    /// initial supply. If initial supply is provided, it is minted to the caller. The
-    /// caller can also specify an optional minter address for the interchain token.
+    /// Revokes a previously approved destination minter for a remote deployment of an interchain token.
+    ///
+    /// # Arguments
+    /// - `minter`: Address of the minter that approved the destination minter.
+    /// - `deployer`: Address of the deployer of the interchain token.
+    /// - `salt`: The salt used to deploy the interchain token.
+    /// - `destination_chain`: The name of the destination chain.
+    ///
+    /// # Errors
+    /// - [`ContractError::RemoteMinterNotApproved`]: If no destination minter is approved.
+    ///
+    /// # Authorization
+    /// - The `minter` must authorize.
+    fn revoke_remote_minter(
+        env: &Env,
+        minter: Address,
+        deployer: Address,
+        salt: BytesN<32>,
+        destination_chain: String,
+    ) -> Result<(), ContractError>;
+
+    /// Initiates the deployment of an interchain token to a specified destination chain, with a minter on the destination chain.
     ///
     /// # Arguments
     /// - `caller`: Address of the caller initiating the deployment.
     /// - `salt`: A 32-byte unique salt used for token deployment.
-    /// - `token_metadata`: Metadata for the new token (name, symbol, decimals).
-    /// - `initial_supply`: Initial amount to mint to caller, if greater than 0.
-    /// - `minter`: Optional address that will have a minter role for the deployed interchain token.
+    /// - `minter`: Address of a minter of the interchain token, which approved the `destination_minter`.
+    /// - `destination_chain`: The name of the destination chain where the token will be deployed.
+    /// - `destination_minter`: The minter address on the destination chain, encoded as bytes.
+    /// - `gas_token`: An optional gas token used to pay for the gas cost of the cross-chain call.
     ///
     /// # Returns
     /// - `Ok(BytesN<32>)`: Returns the token ID.
     ///
     /// # Errors
-    /// - [`ContractError::InvalidMinter`]: If the minter address is invalid.
+    /// - [`ContractError::InvalidTokenId`]: If the token ID does not exist in the persistent storage.
+    /// - [`ContractError::NotMinter`]: If `minter` is not a minter of the interchain token.
+    /// - [`ContractError::RemoteMinterNotApproved`]: If `minter` has not approved `destination_minter` for the `destination_chain`.
+    /// - Any error propagated from `pay_gas_and_call_contract`.
     ///
     /// # Authorization
-    /// - The `deployer` must authorize.
-    fn deploy_interchain_token(
+    /// - The `caller` must authorize.
+    /// - The `minter` must have authorized the `destination_minter` via [`InterchainTokenServiceInterface::approve_remote_minter`].
+    fn deploy_remote_token_with_minter(
         env: &Env,
-        deployer: Address,
+        caller: Address,
         salt: BytesN<32>,
-        token_metadata: TokenMetadata,
-        initial_supply: i128,
-        minter: Option<Address>,
+        minter: Address,
+        destination_chain: String,
+        destination_minter: Bytes,
+        gas_token: Option<Token>,
     ) -> Result<BytesN<32>, ContractError>;
 
-    /// Initiates the deployment of an interchain token to a specified destination chain.
+    /// Initiates the deployment of an interchain token to a specified destination chain with an explicit gas payment.
+    ///
+    /// Behaves like [`InterchainTokenServiceInterface::deploy_remote_interchain_token`], or like
+    /// [`InterchainTokenServiceInterface::deploy_remote_token_with_minter`] if a `destination_minter` is provided,
+    /// except that the gas is paid by `gas_payment.spender`, which can differ from the `caller`.
+    /// This allows a contract deploying tokens on behalf of users, e.g. a token factory, to let the user pay the gas.
     ///
     /// # Arguments
     /// - `caller`: Address of the caller initiating the deployment.
     /// - `salt`: A 32-byte unique salt used for token deployment.
     /// - `destination_chain`: The name of the destination chain where the token will be deployed.
-    /// - `gas_token`: An optional gas token used to pay for the gas cost of the cross-chain call.
+    /// - `destination_minter`: An optional minter of the interchain token, together with the minter address on the destination chain it approved.
+    /// - `gas_payment`: The spender, gas token, metadata, and refund address of the gas payment.
     ///
     /// # Returns
     /// - `Ok(BytesN<32>)`: Returns the token ID.
     ///
     /// # Errors
     /// - [`ContractError::InvalidTokenId`]: If the token ID does not exist in the persistent storage.
+    /// - [`ContractError::NotMinter`]: If the minter is not a minter of the interchain token.
+    /// - [`ContractError::RemoteMinterNotApproved`]: If the minter has not approved the destination minter for the `destination_chain`.
     /// - Any error propagated from `pay_gas_and_call_contract`.
     ///
     /// # Authorization
     /// - The `caller` must authorize.
-    fn deploy_remote_interchain_token(
+    /// - The `gas_payment.spender` must authorize, including the gas payment to the gas service.
+    fn deploy_remote_token_with_gas(
         env: &Env,
         caller: Address,
         salt: BytesN<32>,
         destination_chain: String,
-        gas_token: Option<Token>,
+        destination_minter: Option<(Address, Bytes)>,
+        gas_payment: GasPayment,
     ) -> Result<BytesN<32>, ContractError>;
 
     /// Registers a canonical token as an interchain token.
@@ -206,16 +810,48 @@ pub trait InterchainTokenServiceInterface:
     ///
     /// # Errors
     /// - [`ContractError::TokenAlreadyRegistered`]: If the token ID is already registered.
+    /// - [`ContractError::TokenDeregistered`]: If the token ID has been deregistered.
     fn register_canonical_token(
         env: &Env,
         token_address: Address,
     ) -> Result<BytesN<32>, ContractError>;
 
+    /// Returns the metadata override of the canonical token, if set.
+    fn token_metadata_override(env: &Env, token_address: Address) -> Option<TokenMetadataOverride>;
+
+    /// Sets or removes the name and symbol used instead of the token's own metadata
+    /// when the canonical token is registered and deployed to other chains.
+    ///
+    /// This allows remote representations of e.g. Stellar Classic Assets to carry a readable name,
+    /// such as "USD Coin (Stellar)". The decimals are always read from the token.
+    /// Remote tokens that have already been deployed are not updated.
+    ///
+    /// # Arguments
+    /// - `caller`: The owner of the interchain token service or the admin of the token.
+    /// - `token_address`: The address of the canonical token.
+    /// - `metadata`: The name and symbol to use, or `None` to use the token's own metadata.
+    ///
+    /// # Errors
+    /// - [`ContractError::NotOwnerOrTokenAdmin`]: If the caller is neither the owner nor the admin of the token.
+    /// - [`ContractError::InvalidTokenName`]: If the name is empty, longer than 32 characters, or not ASCII.
+    /// - [`ContractError::InvalidTokenSymbol`]: If the symbol is empty, longer than 32 characters, or not ASCII.
+    /// - [`ContractError::InvalidTokenAddress`]: If the token address is not a valid token.
+    ///
+    /// # Authorization
+    /// - The `caller` must authorize.
+    fn set_token_metadata_override(
+        env: &Env,
+        caller: Address,
+        token_address: Address,
+        metadata: Option<TokenMetadataOverride>,
+    ) -> Result<(), ContractError>;
+
     /// Deploys a remote canonical token on a specified destination chain.
     ///
     /// Anyone can call this to deploy a trustless canonical representation of the token to any trusted destination chain.
     /// If the token name is longer than 32 characters, the symbol will be used as the name.
     /// Specifically, natively issued Stellar assets will be deployed with the symbol as the name.
+    /// The name and symbol can be replaced via [`InterchainTokenServiceInterface::set_token_metadata_override`].
     ///
     /// # Arguments
     /// * `token_address` - The address of the token to be deployed.
@@ -240,6 +876,34 @@ pub trait InterchainTokenServiceInterface:
         gas_token: Option<Token>,
     ) -> Result<BytesN<32>, ContractError>;
 
+    /// Deploys a remote canonical token on multiple destination chains.
+    ///
+    /// A [`crate::event::InterchainTokenDeploymentStartedEvent`] is emitted for each destination chain.
+    /// The deployment fails as a whole if any of the destination chains is not trusted.
+    ///
+    /// # Arguments
+    /// * `token_address` - The address of the token to be deployed.
+    /// * `spender` - The spender of the cross-chain gas.
+    /// * `destinations` - The destination chains, each with an optional gas token used to pay for the deployment.
+    ///
+    /// # Returns
+    /// - `Ok(BytesN<32>)`: Returns the token ID.
+    ///
+    /// # Errors
+    /// - [`ContractError::EmptyDestinationChains`]: If no destination chains are provided.
+    /// - [`ContractError::UntrustedChain`]: If any of the destination chains is not trusted.
+    /// - [`ContractError::InvalidTokenId`]: If the token ID does not exist in the persistent storage.
+    /// - Any error propagated from `pay_gas_and_call_contract`.
+    ///
+    /// # Authorization
+    /// - `spender` must authorize.
+    fn deploy_remote_canonical_tokens(
+        env: &Env,
+        token_address: Address,
+        spender: Address,
+        destinations: Vec<(String, Option<Token>)>,
+    ) -> Result<BytesN<32>, ContractError>;
+
     /// Registers token metadata with the ITS Hub.
     ///
     /// This function validates the token's metadata and sends a message to the ITS Hub
@@ -268,6 +932,30 @@ pub trait InterchainTokenServiceInterface:
         gas_token: Option<Token>,
     ) -> Result<(), ContractError>;
 
+    /// Registers the metadata of multiple tokens with the ITS Hub.
+    ///
+    /// A separate message is sent to the ITS Hub for each token, all paid by the same `spender`,
+    /// and a [`crate::event::TokenMetadataRegisteredEvent`] is emitted for each token.
+    /// The registration fails as a whole if the metadata of any of the tokens is invalid.
+    ///
+    /// # Arguments
+    /// - `tokens`: The addresses of the tokens whose metadata is being registered, each with an optional gas token.
+    /// - `spender`: The address that will pay for the cross-chain gas.
+    ///
+    /// # Errors
+    /// - [`ContractError::EmptyTokenAddresses`]: If no token addresses are provided.
+    /// - [`ContractError::InvalidTokenAddress`]: If any of the token addresses is invalid or the token contract doesn't implement the required interface.
+    /// - [`ContractError::TokenInvocationError`]: If a token contract call fails.
+    /// - Any error propagated from `pay_gas_and_call_contract`.
+    ///
+    /// # Authorization
+    /// - `spender` must authorize.
+    fn register_tokens_metadata(
+        env: &Env,
+        tokens: Vec<(Address, Option<Token>)>,
+        spender: Address,
+    ) -> Result<(), ContractError>;
+
     /// Registers a custom token as an interchain token.
     ///
     /// This function is used to register custom tokens on this chain.
@@ -284,6 +972,7 @@ pub trait InterchainTokenServiceInterface:
     ///
     /// # Errors
     /// - [`ContractError::TokenAlreadyRegistered`]: If the token ID is already registered.
+    /// - [`ContractError::TokenDeregistered`]: If the token ID has been deregistered.
     /// - [`ContractError::InvalidTokenManagerType`]: If the provided token manager type is not allowed for custom tokens.
     ///
     /// # Authorization
@@ -307,6 +996,8 @@ pub trait InterchainTokenServiceInterface:
     /// - `destination_token_address`: The token address of the token being linked on the destination chain.
     /// - `token_manager_type`: The token manager type used for the token link. Cannot be `[TokenManagerType::NativeInterchainToken]`.
     /// - `link_params`: Optional Additional parameters for the token link depending on the destination chain.
+    /// - `destination_token_decimals`: The decimals of the token on the destination chain, if known.
+    ///   They are recorded as the remote decimals of the token, see [`InterchainTokenServiceInterface::remote_token_decimals`].
     /// - `gas_token`: An optional gas token used to pay for cross-chain message execution.
     ///
     /// # Returns
@@ -315,6 +1006,7 @@ pub trait InterchainTokenServiceInterface:
     /// # Errors
     /// - [`ContractError::InvalidTokenManagerType`]: If the provided token manager type is NativeInterchainToken.
     /// - [`ContractError::InvalidDestinationChain`]: If the destination chain is the same as the current chain.
+    /// - [`ContractError::InvalidTokenDecimals`]: If the destination token decimals exceed the maximum supported by hub messages.
     /// - Any error propagated from token metadata validation or `pay_gas_and_call_contract`.
     ///
     /// # Authorization
@@ -327,6 +1019,7 @@ pub trait InterchainTokenServiceInterface:
         destination_token_address: Bytes,
         token_manager_type: TokenManagerType,
         link_params: Option<Bytes>,
+        destination_token_decimals: Option<u32>,
         gas_token: Option<Token>,
     ) -> Result<BytesN<32>, ContractError>;
 
@@ -351,6 +1044,7 @@ pub trait InterchainTokenServiceInterface:
     /// # Errors
     /// - [`ContractError::InvalidAmount`]: If amount is not greater than 0.
     /// - [`ContractError::FlowLimitExceeded`]: If transfer would exceed flow limits.
+    /// - [`ContractError::AmountPrecisionLoss`]: If the amount cannot be represented with the token decimals on the destination chain.
     /// - Any error propagated from `pay_gas_and_call_contract`.
     ///
     /// # Authorization
@@ -366,6 +1060,178 @@ pub trait InterchainTokenServiceInterface:
         gas_token: Option<Token>,
     ) -> Result<(), ContractError>;
 
+    /// Initiates a cross-chain token transfer with an explicit gas payment.
+    ///
+    /// Behaves like [`InterchainTokenServiceInterface::interchain_transfer`], except that the gas for the
+    /// cross-chain message execution is paid by `gas_payment.spender`, which can differ from the `caller`
+    /// to allow a sponsor to pay the gas on behalf of the token sender. The `gas_payment.metadata` is forwarded
+    /// to the gas service, and any gas refund is issued by the gas service to `gas_payment.refund_address`.
+    ///
+    /// # Arguments
+    /// - `caller`: The address initiating the transfer.
+    /// - `token_id`: The unique identifier of the token being transferred.
+    /// - `destination_chain`: The chain to which tokens will be transferred.
+    /// - `destination_address`: The recipient address on the destination chain.
+    /// - `amount`: The amount of tokens to transfer. Must be greater than 0.
+    /// - `data`: Optional data to be handled by the destination address if it's a contract.
+    /// - `gas_payment`: The spender, gas token, metadata, and refund address of the gas payment.
+    ///
+    /// # Errors
+    /// - Same as [`InterchainTokenServiceInterface::interchain_transfer`].
+    ///
+    /// # Authorization
+    /// - The `caller` must authorize.
+    /// - The `gas_payment.spender` must authorize, including the gas payment to the gas service.
+    fn interchain_transfer_with_gas(
+        env: &Env,
+        caller: Address,
+        token_id: BytesN<32>,
+        destination_chain: String,
+        destination_address: Bytes,
+        amount: i128,
+        data: Option<Bytes>,
+        gas_payment: GasPayment,
+    ) -> Result<(), ContractError>;
+
+    /// Initiates a cross-chain token transfer that executes the destination contract with the specified data.
+    ///
+    /// Takes tokens from the caller and sends them to the `destination_address` on the destination chain,
+    /// which is then executed as a contract with the `data` to handle the transferred tokens.
+    ///
+    /// # Arguments
+    /// - `caller`: The address initiating the transfer.
+    /// - `token_id`: The unique identifier of the token being transferred.
+    /// - `destination_chain`: The chain to which tokens will be transferred.
+    /// - `destination_address`: The contract address on the destination chain.
+    /// - `amount`: The amount of tokens to transfer. Must be greater than 0.
+    /// - `data`: The data to be handled by the destination contract. Must not be empty, and must be at most 8 KiB.
+    /// - `gas_token`: An optional gas token used to pay for cross-chain message execution.
+    ///
+    /// # Errors
+    /// - [`ContractError::InvalidData`]: If the data is empty.
+    /// - [`ContractError::DataTooLarge`]: If the data exceeds the maximum size.
+    /// - Any error propagated from [`InterchainTokenServiceInterface::interchain_transfer`].
+    ///
+    /// # Authorization
+    /// - The `caller` must authorize.
+    fn call_contract_with_token(
+        env: &Env,
+        caller: Address,
+        token_id: BytesN<32>,
+        destination_chain: String,
+        destination_address: Bytes,
+        amount: i128,
+        data: Bytes,
+        gas_token: Option<Token>,
+    ) -> Result<(), ContractError>;
+
+    /// Returns whether a failing execution of the specified contract with received interchain tokens
+    /// is recorded instead of reverting the token delivery.
+    fn is_non_reverting_execution(env: &Env, contract: Address) -> bool;
+
+    /// Enables or disables the non-reverting execution of the specified contract with received interchain tokens.
+    ///
+    /// By default, a failing execution reverts the whole interchain transfer, so it can be retried.
+    /// When enabled, the transferred tokens are kept by the contract if its execution fails,
+    /// and the failure is recorded with a [`crate::event::ContractExecutionFailedEvent`].
+    ///
+    /// # Arguments
+    /// - `contract`: The contract receiving interchain tokens with data.
+    /// - `enabled`: Whether failing executions of the contract are non-reverting.
+    ///
+    /// # Authorization
+    /// - The `contract` must authorize.
+    fn set_non_reverting_execution(env: &Env, contract: Address, enabled: bool);
+
+    /// Returns the address receiving the recovered interchain transfers to the specified contract.
+    fn recovery_address(env: &Env, contract: Address) -> Option<Address>;
+
+    /// Sets or removes the address receiving the recovered interchain transfers to the specified contract.
+    ///
+    /// The senders of interchain transfers live on other chains and cannot authorize on this chain,
+    /// so the recovery address is registered by the destination contract instead.
+    ///
+    /// # Arguments
+    /// - `contract`: The contract receiving interchain tokens with data.
+    /// - `recovery_address`: The address receiving the recovered transfers, or `None` to remove it.
+    ///
+    /// # Authorization
+    /// - The `contract` must authorize.
+    fn set_recovery_address(env: &Env, contract: Address, recovery_address: Option<Address>);
+
+    /// Recovers an approved inbound interchain transfer whose execution cannot succeed,
+    /// e.g. because the destination contract is permanently failing.
+    ///
+    /// The gateway approval of the message is consumed, so the message can no longer be executed.
+    /// Failed executions revert all of their state changes, so they cannot be counted on-chain,
+    /// and the recovery is triggered by the operator instead.
+    ///
+    /// # Arguments
+    /// - `source_chain`, `message_id`, `source_address`, `payload`: The message as approved by the gateway.
+    /// - `recovery`: Whether the tokens are given to the recovery address registered by the destination address,
+    ///   or sent back to the source address on the original source chain.
+    /// - `gas_token`: An optional gas token paid by the operator for sending the tokens back to the source chain.
+    ///
+    /// # Errors
+    /// - [`ContractError::NotApproved`]: If the message is not approved by the gateway.
+    /// - [`ContractError::InvalidMessageType`]: If the message is not an interchain transfer.
+    /// - [`ContractError::ExpressExecutorAlreadySet`]: If the message has been express executed.
+    /// - [`ContractError::TokenPaused`]: If the token is paused.
+    /// - [`ContractError::RecoveryAddressNotSet`]: If the destination address has no registered recovery address.
+    /// - Any error propagated from decoding the message or sending the tokens back.
+    ///
+    /// # Authorization
+    /// - [`OperatableInterface::operator`] must authorize.
+    fn recover_interchain_transfer(
+        env: &Env,
+        source_chain: String,
+        message_id: String,
+        source_address: String,
+        payload: Bytes,
+        recovery: TransferRecovery,
+        gas_token: Option<Token>,
+    ) -> Result<(), ContractError>;
+
+    /// Returns the express executor of the specified message, if it was express executed and
+    /// has not been fulfilled by the gateway yet.
+    fn express_executor(
+        env: &Env,
+        source_chain: String,
+        message_id: String,
+        source_address: String,
+        payload_hash: BytesN<32>,
+    ) -> Option<Address>;
+
+    /// Express executes an inbound interchain transfer before it is approved by the gateway.
+    ///
+    /// The `express_executor` fronts the tokens to the recipient of the transfer, and the recipient
+    /// is executed with the transfer data if provided. When the message is later executed via
+    /// [`AxelarExecutableInterface::execute`], the tokens are given to the `express_executor` instead of the recipient.
+    ///
+    /// The express executor takes on the risk of the message never being approved by the gateway,
+    /// so it should only express execute messages that it has verified on the source chain.
+    ///
+    /// # Arguments
+    /// - `express_executor`: The address fronting the tokens for the transfer.
+    /// - `source_chain`, `message_id`, `source_address`, `payload`: The message as it will be approved by the gateway.
+    ///
+    /// # Errors
+    /// - [`ContractError::MessageAlreadyExecuted`]: If the message has already been executed by the gateway.
+    /// - [`ContractError::ExpressExecutorAlreadySet`]: If the message has already been express executed.
+    /// - [`ContractError::InvalidMessageType`]: If the message is not an interchain transfer.
+    /// - Any error propagated from decoding or executing the interchain transfer.
+    ///
+    /// # Authorization
+    /// - The `express_executor` must authorize.
+    fn express_execute(
+        env: &Env,
+        express_executor: Address,
+        source_chain: String,
+        message_id: String,
+        source_address: String,
+        payload: Bytes,
+    ) -> Result<(), ContractError>;
+
     /// Transfers the admin role of a Stellar Classic Asset registered under the `token_id`
     /// from the corresponding token manager to the `new_admin`.
     ///
@@ -380,4 +1246,170 @@ pub trait InterchainTokenServiceInterface:
         token_id: BytesN<32>,
         new_admin: Address,
     ) -> Result<(), ContractError>;
+
+    /// Deregisters the token registered under the `token_id`, e.g. if it was registered with the wrong token manager type.
+    ///
+    /// The token config is removed, and the token ID can't be registered again.
+    /// The funds and roles held by the token manager are handed to the `caller`,
+    /// as described in [`InterchainTokenServiceInterface::exit_token`].
+    /// If the `caller` is the token deployer, the ownership of a native interchain token stays with the service,
+    /// so only the minter role of the token manager is removed, and the existing minters keep their role.
+    /// A [`crate::event::TokenExitedEvent`] and a [`crate::event::TokenDeregisteredEvent`] are emitted.
+    ///
+    /// # Arguments
+    /// - `caller`: The owner, or the deployer of a token that has never been transferred.
+    /// - `token_id`: The unique identifier of the registered token.
+    ///
+    /// # Errors
+    /// - [`ContractError::InvalidTokenId`]: If the token ID is not registered.
+    /// - [`ContractError::NotOwnerOrTokenDeployer`]: If the `caller` is neither the owner nor the token deployer.
+    /// - [`ContractError::TokenAlreadyTransferred`]: If the `caller` is the token deployer, but the token has been transferred.
+    /// - [`ContractError::TokenManagerStillMinter`]: If the token manager is still a minter of a custom token.
+    ///
+    /// # Authorization
+    /// - The `caller` must authorize.
+    fn deregister_token(
+        env: &Env,
+        caller: Address,
+        token_id: BytesN<32>,
+    ) -> Result<(), ContractError>;
+
+    /// Exits the token registered under the `token_id` from the interchain token service,
+    /// handing the funds and roles held by its token manager to the `recipient`, and deregisters the token ID.
+    /// See [`InterchainTokenServiceInterface::deregister_token`].
+    ///
+    /// Depending on the token manager type:
+    /// - [`TokenManagerType::LockUnlock`]: The whole token manager balance is unlocked to the `recipient`.
+    /// - [`TokenManagerType::MintBurn`]: The admin role of a Stellar Classic Asset is transferred to the `recipient`,
+    ///   if held by the token manager.
+    /// - [`TokenManagerType::MintBurn`] and [`TokenManagerType::MintBurnFrom`]: The minter role of the token manager
+    ///   of a custom token can only be revoked by the token owner, which has to happen before exiting the token.
+    /// - [`TokenManagerType::NativeInterchainToken`]: The token manager is removed as a minter,
+    ///   and the ownership of the token is transferred to the `recipient`.
+    ///
+    /// A [`crate::event::TokenExitedEvent`] and a [`crate::event::TokenDeregisteredEvent`] are emitted.
+    ///
+    /// # Arguments
+    /// - `token_id`: The unique identifier of the registered token.
+    /// - `recipient`: The address receiving the unlocked funds and the roles of the token manager.
+    ///
+    /// # Errors
+    /// - [`ContractError::InvalidTokenId`]: If the token ID is not registered.
+    /// - [`ContractError::TokenManagerStillMinter`]: If the token manager is still a minter of a custom token.
+    ///
+    /// # Authorization
+    /// - [`OwnableInterface::owner`] must authorize.
+    fn exit_token(env: &Env, token_id: BytesN<32>, recipient: Address)
+        -> Result<(), ContractError>;
+
+    /// Migrates the token registered under the `token_id` to a different token manager type.
+    ///
+    /// The token manager itself is kept, only the way it handles tokens changes:
+    /// - Migrating away from [`TokenManagerType::LockUnlock`] burns the locked balance of the token manager,
+    ///   since tokens returning from other chains are minted from then on.
+    /// - Migrating to [`TokenManagerType::LockUnlock`] requires the token manager to be funded with the
+    ///   amount bridged out beforehand, which becomes its locked amount. The token manager must no longer be
+    ///   able to mint the token, so the admin role of a Stellar Classic Asset has to be moved via
+    ///   [`InterchainTokenServiceInterface::transfer_token_admin`], and the minter role of a custom token
+    ///   revoked by its owner, before migrating.
+    ///
+    /// # Arguments
+    /// - `token_id`: The unique identifier of the registered token.
+    /// - `token_manager_type`: The new token manager type.
+    ///
+    /// # Errors
+    /// - [`ContractError::InvalidTokenId`]: If the token ID is not registered.
+    /// - [`ContractError::InvalidTokenManagerType`]: If the token is a native interchain token,
+    ///   the new type is [`TokenManagerType::NativeInterchainToken`], or the type is unchanged.
+    /// - [`ContractError::TokenManagerNotMinter`]: If the new type mints tokens, but the token manager
+    ///   is neither the admin nor a minter of the token.
+    /// - [`ContractError::TokenManagerStillMinter`]: If the new type is [`TokenManagerType::LockUnlock`],
+    ///   but the token manager can still mint the token.
+    ///
+    /// # Authorization
+    /// - [`OwnableInterface::owner`] must authorize.
+    fn migrate_token_manager_type(
+        env: &Env,
+        token_id: BytesN<32>,
+        token_manager_type: TokenManagerType,
+    ) -> Result<(), ContractError>;
+
+    /// Transfers the minter role of the interchain token registered under the `token_id`
+    /// from the `minter` to the `new_minter`.
+    ///
+    /// # Arguments
+    /// - `minter`: The current minter of the interchain token.
+    /// - `token_id`: The unique identifier of the interchain token.
+    /// - `new_minter`: The address that will become a minter of the interchain token.
+    ///
+    /// # Errors
+    /// - [`ContractError::InvalidTokenId`]: If the token ID does not exist in the persistent storage.
+    /// - [`ContractError::InvalidTokenManagerType`]: If the token is not a native interchain token.
+    /// - [`ContractError::NotMinter`]: If `minter` is not a minter of the interchain token.
+    /// - [`ContractError::InvalidMinter`]: If `new_minter` is already a minter of the interchain token.
+    ///
+    /// # Authorization
+    /// - The `minter` must authorize.
+    fn transfer_mintership(
+        env: &Env,
+        minter: Address,
+        token_id: BytesN<32>,
+        new_minter: Address,
+    ) -> Result<(), ContractError>;
+
+    /// Renounces the minter role of the `minter` for the interchain token registered under the `token_id`.
+    ///
+    /// # Arguments
+    /// - `minter`: The current minter of the interchain token.
+    /// - `token_id`: The unique identifier of the interchain token.
+    ///
+    /// # Errors
+    /// - [`ContractError::InvalidTokenId`]: If the token ID does not exist in the persistent storage.
+    /// - [`ContractError::InvalidTokenManagerType`]: If the token is not a native interchain token.
+    /// - [`ContractError::NotMinter`]: If `minter` is not a minter of the interchain token.
+    ///
+    /// # Authorization
+    /// - The `minter` must authorize.
+    fn renounce_mintership(
+        env: &Env,
+        minter: Address,
+        token_id: BytesN<32>,
+    ) -> Result<(), ContractError>;
+
+    /// Updates the name and symbol of the interchain token registered under the `token_id`,
+    /// and propagates the new metadata to the remote tokens on the `destinations` via the ITS Hub.
+    ///
+    /// A [`crate::event::TokenMetadataUpdateStartedEvent`] is emitted for each destination chain.
+    /// Remote chains apply the update to the interchain tokens deployed from this chain by their interchain token service.
+    ///
+    /// Note: The ITS Hub does not support the metadata update message yet, so messages sent to the `destinations`
+    /// are currently rejected by the hub, and any gas paid for them is not refunded.
+    /// Pass empty `destinations` to only update the local token until the hub supports it.
+    ///
+    /// # Arguments
+    /// - `minter`: A minter of the interchain token.
+    /// - `token_id`: The unique identifier of the interchain token.
+    /// - `name`: The new name of the token.
+    /// - `symbol`: The new symbol of the token.
+    /// - `destinations`: The chains the token was deployed to, each with an optional gas token used to pay for the update.
+    ///
+    /// # Errors
+    /// - [`ContractError::InvalidTokenId`]: If the token ID does not exist in the persistent storage.
+    /// - [`ContractError::InvalidTokenManagerType`]: If the token is not a native interchain token.
+    /// - [`ContractError::NotMinter`]: If `minter` is not a minter of the interchain token.
+    /// - [`ContractError::InvalidTokenName`]: If the name is empty, longer than 32 characters, or not ASCII.
+    /// - [`ContractError::InvalidTokenSymbol`]: If the symbol is empty, longer than 32 characters, or not ASCII.
+    /// - [`ContractError::UntrustedChain`]: If any of the destination chains is not trusted.
+    /// - Any error propagated from `pay_gas_and_call_contract`.
+    ///
+    /// # Authorization
+    /// - The `minter` must authorize, including the payment of the gas tokens.
+    fn update_token_metadata(
+        env: &Env,
+        minter: Address,
+        token_id: BytesN<32>,
+        name: String,
+        symbol: String,
+        destinations: Vec<(String, Option<Token>)>,
+    ) -> Result<(), ContractError>;
 }
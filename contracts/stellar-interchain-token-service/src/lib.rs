@@ -3,6 +3,7 @@
 #[cfg(any(test, feature = "testutils"))]
 extern crate std;
 
+pub mod compliance;
 pub mod error;
 pub mod executable;
 mod interface;
@@ -28,6 +29,12 @@ cfg_if::cfg_if! {
         mod token_handler;
         mod contract;
         mod flow_limit;
+        mod express;
+        mod decimals;
+        mod fee;
+        mod registry;
+        mod migrate;
+        mod trusted_chain;
 
         pub use contract::{InterchainTokenService, InterchainTokenServiceClient};
         pub use interface::InterchainTokenServiceInterface;
@@ -0,0 +1,49 @@
+use stellar_axelar_std::interfaces::CustomMigratableInterface;
+use stellar_axelar_std::token::TokenClient;
+use stellar_axelar_std::{BytesN, Env, Vec};
+
+use crate::error::ContractError;
+use crate::types::TokenManagerType;
+use crate::{registry, storage, InterchainTokenService};
+
+/// This upgrade only adds new storage keys, so no legacy keys need to be migrated.
+/// The tokens registered before the upgrade are backfilled into the token registry instead.
+pub mod legacy_storage {}
+
+impl CustomMigratableInterface for InterchainTokenService {
+    /// The IDs of the last batch of tokens registered before the upgrade, to be added to the token registry.
+    ///
+    /// Earlier batches are added via [`crate::interface::InterchainTokenServiceInterface::backfill_registry`]
+    /// before the migration is completed.
+    type MigrationData = Vec<BytesN<32>>;
+    type Error = ContractError;
+
+    fn __migrate(env: &Env, token_ids: Self::MigrationData) -> Result<(), Self::Error> {
+        backfill_registry(env, token_ids)
+    }
+}
+
+/// Adds the tokens registered before the upgrade to the token registry.
+///
+/// Tokens that are already in the registry are skipped.
+pub fn backfill_registry(env: &Env, token_ids: Vec<BytesN<32>>) -> Result<(), ContractError> {
+    for token_id in token_ids {
+        let token_config = storage::try_token_id_config(env, token_id.clone())
+            .ok_or(ContractError::InvalidTokenId)?;
+
+        // Tokens registered since the upgrade are already indexed, and their locked amount is tracked
+        if !registry::register_token(env, token_id.clone(), token_config.token_address.clone()) {
+            continue;
+        }
+
+        // Locked tokens weren't tracked before the upgrade, so the token manager balance is taken as the locked amount
+        if token_config.token_manager_type == TokenManagerType::LockUnlock {
+            let locked_amount = TokenClient::new(env, &token_config.token_address)
+                .balance(&token_config.token_manager);
+
+            storage::set_locked_amount(env, token_id, &locked_amount);
+        }
+    }
+
+    Ok(())
+}
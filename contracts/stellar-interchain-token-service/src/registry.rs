@@ -0,0 +1,116 @@
+use stellar_axelar_std::{Address, BytesN, Env, Vec};
+
+use crate::storage;
+
+/// The maximum number of token IDs returned by a single [`registered_token_ids`] or [`token_ids_by_address`] query.
+pub const MAX_PAGE_SIZE: u32 = 50;
+
+/// Adds the token ID to the enumerable registry and to the index of the token address.
+///
+/// Token IDs that are already registered are ignored, in which case `false` is returned.
+pub fn register_token(env: &Env, token_id: BytesN<32>, token_address: Address) -> bool {
+    if storage::try_registered_token_index(env, token_id.clone()).is_some() {
+        return false;
+    }
+
+    let index = registered_token_count(env);
+    storage::set_registered_token_id(env, index, &token_id);
+    storage::set_registered_token_index(env, token_id.clone(), &index);
+    storage::set_registered_token_count(env, &(index + 1));
+
+    let address_index = token_id_count_by_address(env, token_address.clone());
+    storage::set_token_id_by_address(env, token_address.clone(), address_index, &token_id);
+    storage::set_token_id_index_by_address(env, token_id, &address_index);
+    storage::set_token_id_count_by_address(env, token_address, &(address_index + 1));
+
+    true
+}
+
+/// Removes the token ID from the enumerable registry and from the index of the token address.
+///
+/// The last token ID of each index is moved to the position of the removed one, so the indices stay contiguous.
+pub fn deregister_token(env: &Env, token_id: BytesN<32>, token_address: Address) {
+    if let Some(index) = storage::try_registered_token_index(env, token_id.clone()) {
+        let last_index = registered_token_count(env) - 1;
+
+        if index != last_index {
+            let last_token_id = storage::registered_token_id(env, last_index);
+            storage::set_registered_token_id(env, index, &last_token_id);
+            storage::set_registered_token_index(env, last_token_id, &index);
+        }
+
+        storage::remove_registered_token_id(env, last_index);
+        storage::remove_registered_token_index(env, token_id.clone());
+        storage::set_registered_token_count(env, &last_index);
+    }
+
+    if let Some(index) = storage::try_token_id_index_by_address(env, token_id.clone()) {
+        let last_index = token_id_count_by_address(env, token_address.clone()) - 1;
+
+        if index != last_index {
+            let last_token_id =
+                storage::token_id_by_address(env, token_address.clone(), last_index);
+            storage::set_token_id_by_address(env, token_address.clone(), index, &last_token_id);
+            storage::set_token_id_index_by_address(env, last_token_id, &index);
+        }
+
+        storage::remove_token_id_by_address(env, token_address.clone(), last_index);
+        storage::remove_token_id_index_by_address(env, token_id);
+
+        if last_index == 0 {
+            storage::remove_token_id_count_by_address(env, token_address);
+        } else {
+            storage::set_token_id_count_by_address(env, token_address, &last_index);
+        }
+    }
+}
+
+pub fn registered_token_count(env: &Env) -> u32 {
+    storage::try_registered_token_count(env).unwrap_or(0)
+}
+
+/// Returns up to `limit` registered token IDs in registration order, starting at the index `start`.
+///
+/// The order only changes when a token is deregistered, see [`deregister_token`].
+/// The `limit` is capped at [`MAX_PAGE_SIZE`].
+pub fn registered_token_ids(env: &Env, start: u32, limit: u32) -> Vec<BytesN<32>> {
+    let end = start
+        .saturating_add(limit.min(MAX_PAGE_SIZE))
+        .min(registered_token_count(env));
+
+    let mut token_ids = Vec::new(env);
+    for index in start..end {
+        token_ids.push_back(storage::registered_token_id(env, index));
+    }
+
+    token_ids
+}
+
+pub fn token_id_count_by_address(env: &Env, token_address: Address) -> u32 {
+    storage::try_token_id_count_by_address(env, token_address).unwrap_or(0)
+}
+
+/// Returns up to `limit` token IDs registered for the token address, starting at the index `start`.
+///
+/// The `limit` is capped at [`MAX_PAGE_SIZE`].
+pub fn token_ids_by_address(
+    env: &Env,
+    token_address: Address,
+    start: u32,
+    limit: u32,
+) -> Vec<BytesN<32>> {
+    let end = start
+        .saturating_add(limit.min(MAX_PAGE_SIZE))
+        .min(token_id_count_by_address(env, token_address.clone()));
+
+    let mut token_ids = Vec::new(env);
+    for index in start..end {
+        token_ids.push_back(storage::token_id_by_address(
+            env,
+            token_address.clone(),
+            index,
+        ));
+    }
+
+    token_ids
+}
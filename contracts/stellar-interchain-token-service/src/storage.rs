@@ -1,6 +1,6 @@
 use stellar_axelar_std::{contractstorage, contracttype, soroban_sdk, Address, BytesN, String};
 
-use crate::types::TokenManagerType;
+use crate::types::{TokenManagerType, TokenMetadataOverride, TrustedChainConfig};
 
 #[contractstorage]
 enum DataKey {
@@ -36,6 +36,55 @@ enum DataKey {
     #[status]
     TrustedChain { chain: String },
 
+    #[persistent]
+    #[value(TrustedChainConfig)]
+    TrustedChainConfig { chain: String },
+
+    #[instance]
+    #[value(u32)]
+    RegisteredTokenCount,
+
+    #[instance]
+    #[value(Address)]
+    InterchainTokenFactory,
+
+    #[instance]
+    #[value(Address)]
+    GlobalComplianceHook,
+
+    /// The number of tokens with their own compliance hook.
+    #[instance]
+    #[value(u32)]
+    ComplianceHookCount,
+
+    #[instance]
+    #[value(Address)]
+    FeeReceiver,
+
+    #[instance]
+    #[value(u32)]
+    DefaultFeeRate,
+
+    #[persistent]
+    #[value(BytesN<32>)]
+    RegisteredTokenId { index: u32 },
+
+    #[persistent]
+    #[value(u32)]
+    RegisteredTokenIndex { token_id: BytesN<32> },
+
+    #[persistent]
+    #[value(BytesN<32>)]
+    TokenIdByAddress { token_address: Address, index: u32 },
+
+    #[persistent]
+    #[value(u32)]
+    TokenIdCountByAddress { token_address: Address },
+
+    #[persistent]
+    #[value(u32)]
+    TokenIdIndexByAddress { token_id: BytesN<32> },
+
     #[persistent]
     #[value(TokenIdConfigValue)]
     TokenIdConfig { token_id: BytesN<32> },
@@ -44,6 +93,18 @@ enum DataKey {
     #[value(i128)]
     FlowLimit { token_id: BytesN<32> },
 
+    #[persistent]
+    #[value(u32)]
+    FeeRate { token_id: BytesN<32> },
+
+    #[persistent]
+    #[value(Address)]
+    ComplianceHook { token_id: BytesN<32> },
+
+    #[persistent]
+    #[value(i128)]
+    LockedAmount { token_id: BytesN<32> },
+
     #[temporary]
     #[value(i128)]
     FlowOut { token_id: BytesN<32>, epoch: u64 },
@@ -51,6 +112,85 @@ enum DataKey {
     #[temporary]
     #[value(i128)]
     FlowIn { token_id: BytesN<32>, epoch: u64 },
+
+    #[persistent]
+    #[value(i128)]
+    ChainFlowLimit { token_id: BytesN<32>, chain: String },
+
+    #[persistent]
+    #[value(i128)]
+    MaxTransferAmount { token_id: BytesN<32>, chain: String },
+
+    #[temporary]
+    #[value(i128)]
+    ChainFlowOut {
+        token_id: BytesN<32>,
+        chain: String,
+        epoch: u64,
+    },
+
+    #[temporary]
+    #[value(i128)]
+    ChainFlowIn {
+        token_id: BytesN<32>,
+        chain: String,
+        epoch: u64,
+    },
+
+    #[persistent]
+    #[value(Address)]
+    TokenDeployer { token_id: BytesN<32> },
+
+    #[persistent]
+    #[status]
+    FlowLimiter {
+        token_id: BytesN<32>,
+        flow_limiter: Address,
+    },
+
+    #[persistent]
+    #[value(ExpressExecutionValue)]
+    ExpressExecutor { express_execution_hash: BytesN<32> },
+
+    #[persistent]
+    #[value(BytesN<32>)]
+    RemoteMinterApproval {
+        minter: Address,
+        token_id: BytesN<32>,
+        destination_chain: String,
+    },
+
+    #[persistent]
+    #[value(TokenMetadataOverride)]
+    TokenMetadataOverride { token_id: BytesN<32> },
+
+    #[persistent]
+    #[value(u32)]
+    RemoteTokenDecimals { token_id: BytesN<32>, chain: String },
+
+    #[persistent]
+    #[status]
+    DecimalScaling { token_id: BytesN<32> },
+
+    #[persistent]
+    #[status]
+    TokenPaused { token_id: BytesN<32> },
+
+    #[persistent]
+    #[status]
+    TokenTransferred { token_id: BytesN<32> },
+
+    #[persistent]
+    #[status]
+    TokenDeregistered { token_id: BytesN<32> },
+
+    #[persistent]
+    #[status]
+    NonRevertingExecution { contract: Address },
+
+    #[persistent]
+    #[value(Address)]
+    RecoveryAddress { contract: Address },
 }
 
 #[contracttype]
@@ -60,3 +200,11 @@ pub struct TokenIdConfigValue {
     pub token_manager: Address,
     pub token_manager_type: TokenManagerType,
 }
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ExpressExecutionValue {
+    pub express_executor: Address,
+    /// The local amount fronted by the express executor, which is repaid when the message is executed.
+    pub amount: i128,
+}
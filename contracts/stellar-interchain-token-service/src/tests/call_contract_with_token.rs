@@ -0,0 +1,134 @@
+use stellar_axelar_gas_service::testutils::setup_gas_token;
+use stellar_axelar_std::testutils::Address as _;
+use stellar_axelar_std::traits::BytesExt;
+use stellar_axelar_std::{assert_contract_err, events, Address, Bytes, String};
+
+use super::utils::setup_env;
+use crate::error::ContractError;
+use crate::event::InterchainTransferSentEvent;
+use crate::testutils::setup_its_token;
+
+const MAX_DATA_SIZE: usize = 8 * 1024;
+
+#[test]
+fn call_contract_with_token_succeeds() {
+    let (env, client, _, _, _) = setup_env();
+
+    let amount = 1000;
+    let sender = Address::generate(&env);
+    let gas_token = setup_gas_token(&env, &sender);
+    let (token_id, _) = setup_its_token(&env, &client, &sender, amount);
+    let destination_chain = String::from_str(&env, "ethereum");
+    let destination_address = Bytes::from_hex(&env, "4F4495243837681061C4743b74B3eEdf548D56A5");
+    let data = Bytes::from_hex(&env, "abcd");
+
+    client
+        .mock_all_auths()
+        .set_trusted_chain(&destination_chain);
+
+    client.mock_all_auths().call_contract_with_token(
+        &sender,
+        &token_id,
+        &destination_chain,
+        &destination_address,
+        &amount,
+        &data,
+        &Some(gas_token),
+    );
+
+    goldie::assert!(events::fmt_emitted_event_at_idx::<
+        InterchainTransferSentEvent,
+    >(&env, -4));
+}
+
+#[test]
+fn call_contract_with_token_succeeds_with_max_data_size() {
+    let (env, client, _, _, _) = setup_env();
+
+    let amount = 1000;
+    let sender = Address::generate(&env);
+    let (token_id, _) = setup_its_token(&env, &client, &sender, amount);
+    let destination_chain = String::from_str(&env, "ethereum");
+
+    client
+        .mock_all_auths()
+        .set_trusted_chain(&destination_chain);
+
+    client.mock_all_auths().call_contract_with_token(
+        &sender,
+        &token_id,
+        &destination_chain,
+        &Bytes::from_hex(&env, "4F4495243837681061C4743b74B3eEdf548D56A5"),
+        &amount,
+        &Bytes::from_slice(&env, &[1; MAX_DATA_SIZE]),
+        &None,
+    );
+}
+
+#[test]
+fn call_contract_with_token_fails_with_empty_data() {
+    let (env, client, _, _, _) = setup_env();
+
+    let amount = 1000;
+    let sender = Address::generate(&env);
+    let (token_id, _) = setup_its_token(&env, &client, &sender, amount);
+
+    assert_contract_err!(
+        client.mock_all_auths().try_call_contract_with_token(
+            &sender,
+            &token_id,
+            &String::from_str(&env, "ethereum"),
+            &Bytes::from_hex(&env, "4F4495243837681061C4743b74B3eEdf548D56A5"),
+            &amount,
+            &Bytes::new(&env),
+            &None,
+        ),
+        ContractError::InvalidData
+    );
+}
+
+#[test]
+fn call_contract_with_token_fails_with_data_too_large() {
+    let (env, client, _, _, _) = setup_env();
+
+    let amount = 1000;
+    let sender = Address::generate(&env);
+    let (token_id, _) = setup_its_token(&env, &client, &sender, amount);
+
+    assert_contract_err!(
+        client.mock_all_auths().try_call_contract_with_token(
+            &sender,
+            &token_id,
+            &String::from_str(&env, "ethereum"),
+            &Bytes::from_hex(&env, "4F4495243837681061C4743b74B3eEdf548D56A5"),
+            &amount,
+            &Bytes::from_slice(&env, &[1; MAX_DATA_SIZE + 1]),
+            &None,
+        ),
+        ContractError::DataTooLarge
+    );
+}
+
+#[test]
+fn call_contract_with_token_fails_when_paused() {
+    let (env, client, _, _, _) = setup_env();
+
+    let amount = 1000;
+    let sender = Address::generate(&env);
+    let (token_id, _) = setup_its_token(&env, &client, &sender, amount);
+
+    client.mock_all_auths().pause();
+
+    assert_contract_err!(
+        client.mock_all_auths().try_call_contract_with_token(
+            &sender,
+            &token_id,
+            &String::from_str(&env, "ethereum"),
+            &Bytes::from_hex(&env, "4F4495243837681061C4743b74B3eEdf548D56A5"),
+            &amount,
+            &Bytes::from_hex(&env, "abcd"),
+            &None,
+        ),
+        ContractError::ContractPaused
+    );
+}
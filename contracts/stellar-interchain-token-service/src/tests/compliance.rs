@@ -0,0 +1,301 @@
+use stellar_axelar_gateway::testutils::approve_gateway_messages;
+use stellar_axelar_gateway::types::Message as GatewayMessage;
+use stellar_axelar_std::address::AddressExt;
+use stellar_axelar_std::testutils::Address as _;
+use stellar_axelar_std::token::TokenClient;
+use stellar_axelar_std::traits::BytesExt;
+use stellar_axelar_std::{
+    assert_auth, assert_contract_err, events, vec, Address, Bytes, BytesN, Env, String,
+};
+
+use super::utils::setup_env;
+use crate::error::ContractError;
+use crate::event::{ComplianceHookSetEvent, GlobalComplianceHookSetEvent};
+use crate::testutils::setup_its_token;
+use crate::types::{HubMessage, InterchainTransfer, Message};
+use crate::InterchainTokenServiceClient;
+
+mod test {
+    use stellar_axelar_std::{
+        contract, contractimpl, contracttype, soroban_sdk, Address, Bytes, BytesN, Env, Error,
+        String,
+    };
+
+    use crate::compliance::ComplianceHookInterface;
+
+    #[contract]
+    pub struct BlocklistHook;
+
+    #[contracttype]
+    #[derive(Clone, Debug)]
+    pub enum DataKey {
+        Blocked(Address),
+    }
+
+    #[contractimpl]
+    impl BlocklistHook {
+        pub fn block(env: &Env, address: Address) {
+            env.storage()
+                .instance()
+                .set(&DataKey::Blocked(address), &());
+        }
+
+        fn ensure_not_blocked(env: &Env, address: Address) -> Result<(), Error> {
+            if env.storage().instance().has(&DataKey::Blocked(address)) {
+                return Err(Error::from_contract_error(1));
+            }
+
+            Ok(())
+        }
+    }
+
+    #[contractimpl]
+    impl ComplianceHookInterface for BlocklistHook {
+        fn check_outbound_transfer(
+            env: &Env,
+            _token_id: BytesN<32>,
+            sender: Address,
+            _destination_chain: String,
+            _destination_address: Bytes,
+            _amount: i128,
+        ) -> Result<(), Error> {
+            Self::ensure_not_blocked(env, sender)
+        }
+
+        fn check_inbound_transfer(
+            env: &Env,
+            _token_id: BytesN<32>,
+            _source_chain: String,
+            _source_address: Bytes,
+            recipient: Address,
+            _amount: i128,
+        ) -> Result<(), Error> {
+            Self::ensure_not_blocked(env, recipient)
+        }
+    }
+}
+
+fn register_hook(env: &Env, blocked: &Address) -> Address {
+    let hook = env.register(test::BlocklistHook, ());
+    test::BlocklistHookClient::new(env, &hook).block(blocked);
+
+    hook
+}
+
+fn try_interchain_transfer(
+    env: &Env,
+    client: &InterchainTokenServiceClient,
+    sender: &Address,
+    token_id: &BytesN<32>,
+    amount: i128,
+) -> Result<(), ContractError> {
+    let destination_chain = String::from_str(env, "ethereum");
+    client
+        .mock_all_auths()
+        .set_trusted_chain(&destination_chain);
+
+    match client.mock_all_auths().try_interchain_transfer(
+        sender,
+        token_id,
+        &destination_chain,
+        &Bytes::from_hex(env, "4F4495243837681061C4743b74B3eEdf548D56A5"),
+        &amount,
+        &None,
+        &None,
+    ) {
+        Ok(_) => Ok(()),
+        Err(err) => Err(err.expect("expected a contract error")),
+    }
+}
+
+#[test]
+fn set_global_compliance_hook_succeeds() {
+    let (env, client, _, _, _) = setup_env();
+    let hook = Some(register_hook(&env, &Address::generate(&env)));
+
+    assert_eq!(client.global_compliance_hook(), None);
+
+    assert_auth!(client.operator(), client.set_global_compliance_hook(&hook));
+
+    goldie::assert!(events::fmt_last_emitted_event::<GlobalComplianceHookSetEvent>(&env));
+
+    assert_eq!(client.global_compliance_hook(), hook);
+}
+
+#[test]
+fn set_compliance_hook_succeeds() {
+    let (env, client, _, _, _) = setup_env();
+    let deployer = Address::generate(&env);
+    let (token_id, _) = setup_its_token(&env, &client, &deployer, 100);
+    let hook = Some(register_hook(&env, &Address::generate(&env)));
+
+    assert_auth!(
+        deployer,
+        client.set_compliance_hook(&deployer, &token_id, &hook)
+    );
+
+    goldie::assert!(events::fmt_last_emitted_event::<ComplianceHookSetEvent>(
+        &env
+    ));
+
+    assert_eq!(client.compliance_hook(&token_id), hook);
+
+    client
+        .mock_all_auths()
+        .set_compliance_hook(&deployer, &token_id, &None);
+
+    assert_eq!(client.compliance_hook(&token_id), None);
+}
+
+#[test]
+fn set_compliance_hook_fails_if_not_operator_or_deployer() {
+    let (env, client, _, _, _) = setup_env();
+    let (token_id, _) = setup_its_token(&env, &client, &Address::generate(&env), 100);
+
+    assert_contract_err!(
+        client.mock_all_auths().try_set_compliance_hook(
+            &Address::generate(&env),
+            &token_id,
+            &Some(Address::generate(&env))
+        ),
+        ContractError::NotOperatorOrTokenDeployer
+    );
+}
+
+#[test]
+fn interchain_transfer_fails_if_blocked_by_global_hook() {
+    let (env, client, _, _, _) = setup_env();
+    let sender = Address::generate(&env);
+    let amount = 100;
+    let (token_id, _) = setup_its_token(&env, &client, &sender, amount);
+
+    client
+        .mock_all_auths()
+        .set_global_compliance_hook(&Some(register_hook(&env, &sender)));
+
+    assert_eq!(
+        try_interchain_transfer(&env, &client, &sender, &token_id, amount),
+        Err(ContractError::TransferBlocked)
+    );
+}
+
+#[test]
+fn interchain_transfer_succeeds_if_allowed_by_hook() {
+    let (env, client, _, _, _) = setup_env();
+    let sender = Address::generate(&env);
+    let amount = 100;
+    let (token_id, _) = setup_its_token(&env, &client, &sender, amount);
+
+    client
+        .mock_all_auths()
+        .set_global_compliance_hook(&Some(register_hook(&env, &Address::generate(&env))));
+
+    assert_eq!(
+        try_interchain_transfer(&env, &client, &sender, &token_id, amount),
+        Ok(())
+    );
+}
+
+#[test]
+fn compliance_hook_takes_precedence_over_global_hook() {
+    let (env, client, _, _, _) = setup_env();
+    let sender = Address::generate(&env);
+    let amount = 100;
+    let (token_id, _) = setup_its_token(&env, &client, &sender, amount);
+
+    client
+        .mock_all_auths()
+        .set_global_compliance_hook(&Some(register_hook(&env, &sender)));
+    client.mock_all_auths().set_compliance_hook(
+        &sender,
+        &token_id,
+        &Some(register_hook(&env, &Address::generate(&env))),
+    );
+
+    assert_eq!(
+        try_interchain_transfer(&env, &client, &sender, &token_id, amount),
+        Ok(())
+    );
+}
+
+#[test]
+fn compliance_hook_applies_after_other_token_hook_is_removed() {
+    let (env, client, _, _, _) = setup_env();
+    let amount = 100;
+    let sender = Address::generate(&env);
+    let (token_id, _) = setup_its_token(&env, &client, &sender, amount);
+    let other_deployer = Address::generate(&env);
+    let (other_token_id, _) = setup_its_token(&env, &client, &other_deployer, 0);
+
+    client.mock_all_auths().set_compliance_hook(
+        &sender,
+        &token_id,
+        &Some(register_hook(&env, &sender)),
+    );
+    for hook in [Some(register_hook(&env, &sender)), None, None] {
+        client
+            .mock_all_auths()
+            .set_compliance_hook(&other_deployer, &other_token_id, &hook);
+    }
+
+    assert_eq!(
+        try_interchain_transfer(&env, &client, &sender, &token_id, amount),
+        Err(ContractError::TransferBlocked)
+    );
+}
+
+#[test]
+fn execute_interchain_transfer_fails_if_recipient_blocked() {
+    let (env, client, gateway_client, _, signers) = setup_env();
+    let deployer = Address::generate(&env);
+    let (token_id, _) = setup_its_token(&env, &client, &deployer, 0);
+    let original_source_chain = String::from_str(&env, "ethereum");
+    let recipient = Address::generate(&env);
+    let amount = 1000;
+
+    client
+        .mock_all_auths()
+        .set_trusted_chain(&original_source_chain);
+    client.mock_all_auths().set_compliance_hook(
+        &deployer,
+        &token_id,
+        &Some(register_hook(&env, &recipient)),
+    );
+
+    let payload = HubMessage::ReceiveFromHub {
+        source_chain: original_source_chain,
+        message: Message::InterchainTransfer(InterchainTransfer {
+            token_id: token_id.clone(),
+            source_address: Address::generate(&env).to_string_bytes(),
+            destination_address: recipient.to_string_bytes(),
+            amount,
+            data: None,
+        }),
+    }
+    .abi_encode(&env)
+    .unwrap();
+    let source_chain = client.its_hub_chain_name();
+    let source_address = client.its_hub_address();
+    let message_id = String::from_str(&env, "test");
+
+    let messages = vec![
+        &env,
+        GatewayMessage {
+            source_chain: source_chain.clone(),
+            message_id: message_id.clone(),
+            source_address: source_address.clone(),
+            contract_address: client.address.clone(),
+            payload_hash: env.crypto().keccak256(&payload).into(),
+        },
+    ];
+
+    approve_gateway_messages(&env, &gateway_client, signers, messages);
+
+    assert_contract_err!(
+        client.try_execute(&source_chain, &message_id, &source_address, &payload),
+        ContractError::TransferBlocked
+    );
+
+    let token = TokenClient::new(&env, &client.registered_token_address(&token_id));
+    assert_eq!(token.balance(&recipient), 0);
+}
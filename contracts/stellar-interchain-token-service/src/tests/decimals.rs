@@ -0,0 +1,297 @@
+use stellar_axelar_gateway::event::ContractCalledEvent;
+use stellar_axelar_gateway::testutils::approve_gateway_messages;
+use stellar_axelar_gateway::types::Message as GatewayMessage;
+use stellar_axelar_std::address::AddressExt;
+use stellar_axelar_std::testutils::Address as _;
+use stellar_axelar_std::token::TokenClient;
+use stellar_axelar_std::traits::BytesExt;
+use stellar_axelar_std::types::Token;
+use stellar_axelar_std::{
+    assert_auth, assert_contract_err, events, vec, Address, Bytes, BytesN, String,
+};
+
+use super::utils::setup_env;
+use crate::error::ContractError;
+use crate::event::{DecimalScalingSetEvent, RemoteTokenDecimalsSetEvent};
+use crate::testutils::setup_its_token;
+use crate::types::{HubMessage, InterchainTransfer, Message};
+
+const REMOTE_DECIMALS: u32 = 6;
+// Tokens deployed by `setup_its_token` have 18 decimals
+const SCALING_FACTOR: i128 = 10i128.pow(12);
+
+#[test]
+fn deploy_remote_interchain_token_records_remote_decimals() {
+    let (env, client, _, _, _) = setup_env();
+    let deployer = Address::generate(&env);
+    let salt = BytesN::<32>::from_array(&env, &[1; 32]);
+    let (token_id, token_metadata) = setup_its_token(&env, &client, &deployer, 100);
+    let destination_chain = String::from_str(&env, "ethereum");
+
+    client
+        .mock_all_auths()
+        .set_trusted_chain(&destination_chain);
+
+    client.mock_all_auths().deploy_remote_interchain_token(
+        &deployer,
+        &salt,
+        &destination_chain,
+        &None::<Token>,
+    );
+
+    assert_eq!(
+        client.remote_token_decimals(&token_id, &destination_chain),
+        Some(token_metadata.decimal)
+    );
+}
+
+#[test]
+fn set_remote_token_decimals_succeeds() {
+    let (env, client, _, _, _) = setup_env();
+    let deployer = Address::generate(&env);
+    let (token_id, _) = setup_its_token(&env, &client, &deployer, 100);
+    let chain = String::from_str(&env, "ethereum");
+
+    assert_eq!(client.remote_token_decimals(&token_id, &chain), None);
+
+    assert_auth!(
+        deployer,
+        client.set_remote_token_decimals(&deployer, &token_id, &chain, &REMOTE_DECIMALS)
+    );
+
+    goldie::assert!(events::fmt_last_emitted_event::<RemoteTokenDecimalsSetEvent>(&env));
+
+    assert_eq!(
+        client.remote_token_decimals(&token_id, &chain),
+        Some(REMOTE_DECIMALS)
+    );
+}
+
+#[test]
+fn set_remote_token_decimals_fails_if_not_operator_or_deployer() {
+    let (env, client, _, _, _) = setup_env();
+    let deployer = Address::generate(&env);
+    let (token_id, _) = setup_its_token(&env, &client, &deployer, 100);
+
+    assert_contract_err!(
+        client.mock_all_auths().try_set_remote_token_decimals(
+            &Address::generate(&env),
+            &token_id,
+            &String::from_str(&env, "ethereum"),
+            &REMOTE_DECIMALS
+        ),
+        ContractError::NotOperatorOrTokenDeployer
+    );
+}
+
+#[test]
+fn set_remote_token_decimals_fails_with_invalid_decimals() {
+    let (env, client, _, _, _) = setup_env();
+    let deployer = Address::generate(&env);
+    let (token_id, _) = setup_its_token(&env, &client, &deployer, 100);
+
+    assert_contract_err!(
+        client.mock_all_auths().try_set_remote_token_decimals(
+            &deployer,
+            &token_id,
+            &String::from_str(&env, "ethereum"),
+            &(u8::MAX as u32 + 1)
+        ),
+        ContractError::InvalidTokenDecimals
+    );
+}
+
+#[test]
+fn set_decimal_scaling_succeeds() {
+    let (env, client, _, _, _) = setup_env();
+    let deployer = Address::generate(&env);
+    let (token_id, _) = setup_its_token(&env, &client, &deployer, 100);
+    let operator = client.operator();
+
+    assert!(!client.is_decimal_scaling_enabled(&token_id));
+
+    assert_auth!(
+        operator,
+        client.set_decimal_scaling(&operator, &token_id, &true)
+    );
+
+    goldie::assert!(events::fmt_last_emitted_event::<DecimalScalingSetEvent>(
+        &env
+    ));
+
+    assert!(client.is_decimal_scaling_enabled(&token_id));
+
+    client
+        .mock_all_auths()
+        .set_decimal_scaling(&operator, &token_id, &false);
+
+    assert!(!client.is_decimal_scaling_enabled(&token_id));
+}
+
+#[test]
+fn set_decimal_scaling_fails_if_not_operator_or_deployer() {
+    let (env, client, _, _, _) = setup_env();
+    let deployer = Address::generate(&env);
+    let (token_id, _) = setup_its_token(&env, &client, &deployer, 100);
+
+    assert_contract_err!(
+        client
+            .mock_all_auths()
+            .try_set_decimal_scaling(&Address::generate(&env), &token_id, &true),
+        ContractError::NotOperatorOrTokenDeployer
+    );
+}
+
+#[test]
+fn interchain_transfer_fails_with_precision_loss() {
+    let (env, client, _, _, _) = setup_env();
+    let amount = SCALING_FACTOR + 1;
+    let sender = Address::generate(&env);
+    let (token_id, _) = setup_its_token(&env, &client, &sender, amount);
+    let destination_chain = String::from_str(&env, "ethereum");
+
+    client
+        .mock_all_auths()
+        .set_trusted_chain(&destination_chain);
+    client.mock_all_auths().set_remote_token_decimals(
+        &sender,
+        &token_id,
+        &destination_chain,
+        &REMOTE_DECIMALS,
+    );
+
+    assert_contract_err!(
+        client.mock_all_auths().try_interchain_transfer(
+            &sender,
+            &token_id,
+            &destination_chain,
+            &Bytes::from_hex(&env, "4F4495243837681061C4743b74B3eEdf548D56A5"),
+            &amount,
+            &None,
+            &None
+        ),
+        ContractError::AmountPrecisionLoss
+    );
+}
+
+#[test]
+fn interchain_transfer_succeeds_without_scaling() {
+    let (env, client, _, _, _) = setup_env();
+    let amount = 2 * SCALING_FACTOR;
+    let sender = Address::generate(&env);
+    let (token_id, _) = setup_its_token(&env, &client, &sender, amount);
+    let destination_chain = String::from_str(&env, "ethereum");
+
+    client
+        .mock_all_auths()
+        .set_trusted_chain(&destination_chain);
+    client.mock_all_auths().set_remote_token_decimals(
+        &sender,
+        &token_id,
+        &destination_chain,
+        &REMOTE_DECIMALS,
+    );
+
+    client.mock_all_auths().interchain_transfer(
+        &sender,
+        &token_id,
+        &destination_chain,
+        &Bytes::from_hex(&env, "4F4495243837681061C4743b74B3eEdf548D56A5"),
+        &amount,
+        &None,
+        &None,
+    );
+
+    goldie::assert!(events::fmt_last_emitted_event::<ContractCalledEvent>(&env));
+}
+
+#[test]
+fn interchain_transfer_scales_amount_to_remote_decimals() {
+    let (env, client, _, _, _) = setup_env();
+    let amount = 2 * SCALING_FACTOR;
+    let sender = Address::generate(&env);
+    let (token_id, _) = setup_its_token(&env, &client, &sender, amount);
+    let destination_chain = String::from_str(&env, "ethereum");
+
+    client
+        .mock_all_auths()
+        .set_trusted_chain(&destination_chain);
+    client.mock_all_auths().set_remote_token_decimals(
+        &sender,
+        &token_id,
+        &destination_chain,
+        &REMOTE_DECIMALS,
+    );
+    client
+        .mock_all_auths()
+        .set_decimal_scaling(&sender, &token_id, &true);
+
+    client.mock_all_auths().interchain_transfer(
+        &sender,
+        &token_id,
+        &destination_chain,
+        &Bytes::from_hex(&env, "4F4495243837681061C4743b74B3eEdf548D56A5"),
+        &amount,
+        &None,
+        &None,
+    );
+
+    goldie::assert!(events::fmt_last_emitted_event::<ContractCalledEvent>(&env));
+}
+
+#[test]
+fn interchain_transfer_execute_scales_amount_to_local_decimals() {
+    let (env, client, gateway_client, _, signers) = setup_env();
+    let deployer = Address::generate(&env);
+    let (token_id, _) = setup_its_token(&env, &client, &deployer, 100);
+    let original_source_chain = String::from_str(&env, "ethereum");
+    let recipient = Address::generate(&env);
+    let remote_amount = 5;
+
+    client
+        .mock_all_auths()
+        .set_trusted_chain(&original_source_chain);
+    client.mock_all_auths().set_remote_token_decimals(
+        &deployer,
+        &token_id,
+        &original_source_chain,
+        &REMOTE_DECIMALS,
+    );
+    client
+        .mock_all_auths()
+        .set_decimal_scaling(&deployer, &token_id, &true);
+
+    let payload = HubMessage::ReceiveFromHub {
+        source_chain: original_source_chain,
+        message: Message::InterchainTransfer(InterchainTransfer {
+            token_id: token_id.clone(),
+            source_address: Address::generate(&env).to_string_bytes(),
+            destination_address: recipient.to_string_bytes(),
+            amount: remote_amount,
+            data: None,
+        }),
+    }
+    .abi_encode(&env)
+    .unwrap();
+    let source_chain = client.its_hub_chain_name();
+    let source_address = client.its_hub_address();
+    let message_id = String::from_str(&env, "test");
+
+    let messages = vec![
+        &env,
+        GatewayMessage {
+            source_chain: source_chain.clone(),
+            message_id: message_id.clone(),
+            source_address: source_address.clone(),
+            contract_address: client.address.clone(),
+            payload_hash: env.crypto().keccak256(&payload).into(),
+        },
+    ];
+
+    approve_gateway_messages(&env, &gateway_client, signers, messages);
+
+    client.execute(&source_chain, &message_id, &source_address, &payload);
+
+    let token = TokenClient::new(&env, &client.registered_token_address(&token_id));
+    assert_eq!(token.balance(&recipient), remote_amount * SCALING_FACTOR);
+}
@@ -4,10 +4,12 @@ use stellar_axelar_std::testutils::{Address as _, AuthorizedFunction, Authorized
 use stellar_axelar_std::token::{self, StellarAssetClient};
 use stellar_axelar_std::types::Token;
 use stellar_axelar_std::{
-    auth_invocation, events, Address, Bytes, BytesN, IntoVal, String, Symbol,
+    assert_contract_err, auth_invocation, events, vec, Address, Bytes, BytesN, IntoVal, String,
+    Symbol,
 };
 
 use super::utils::{setup_env, TokenMetadataExt};
+use crate::error::ContractError;
 use crate::event::InterchainTokenDeploymentStartedEvent;
 use crate::tests::utils::{
     INTERCHAIN_TOKEN_DEPLOYED_EVENT_IDX, INTERCHAIN_TOKEN_DEPLOYED_WITHOUT_GAS_TOKEN_EVENT_IDX,
@@ -87,6 +89,7 @@ fn deploy_remote_canonical_token_succeeds() {
             its_hub_address,
             payload,
             spender.clone(),
+            spender.clone(),
             gas_token.clone(),
             Bytes::new(&env)
         ),
@@ -204,3 +207,62 @@ fn deploy_remote_canonical_token_succeeds_without_name_truncation() {
         InterchainTokenDeploymentStartedEvent,
     >(&env, INTERCHAIN_TOKEN_DEPLOYED_EVENT_IDX));
 }
+
+#[test]
+fn deploy_remote_canonical_tokens_succeeds() {
+    let (env, client, _, _, _) = setup_env();
+    let spender = Address::generate(&env);
+    let gas_token = setup_gas_token(&env, &spender);
+    let token_address = client.native_token_address();
+    let first_chain = String::from_str(&env, "ethereum");
+    let second_chain = String::from_str(&env, "avalanche");
+
+    let token_id = client.register_canonical_token(&token_address);
+
+    client.mock_all_auths().set_trusted_chain(&first_chain);
+    client.mock_all_auths().set_trusted_chain(&second_chain);
+
+    let deployed_token_id = client.mock_all_auths().deploy_remote_canonical_tokens(
+        &token_address,
+        &spender,
+        &vec![
+            &env,
+            (first_chain.clone(), Some(gas_token)),
+            (second_chain.clone(), None),
+        ],
+    );
+
+    assert_eq!(token_id, deployed_token_id);
+
+    goldie::assert!(events::fmt_emitted_event_at_idx::<
+        InterchainTokenDeploymentStartedEvent,
+    >(
+        &env,
+        INTERCHAIN_TOKEN_DEPLOYED_WITHOUT_GAS_TOKEN_EVENT_IDX
+    ));
+
+    // A single authorization covers all deployments
+    assert_eq!(env.auths().len(), 1);
+    assert_eq!(env.auths()[0].0, spender);
+
+    for chain in [first_chain, second_chain] {
+        assert!(client.remote_token_decimals(&token_id, &chain).is_some());
+    }
+}
+
+#[test]
+fn deploy_remote_canonical_tokens_fails_with_empty_destination_chains() {
+    let (env, client, _, _, _) = setup_env();
+    let token_address = client.native_token_address();
+
+    client.register_canonical_token(&token_address);
+
+    assert_contract_err!(
+        client.mock_all_auths().try_deploy_remote_canonical_tokens(
+            &token_address,
+            &Address::generate(&env),
+            &vec![&env]
+        ),
+        ContractError::EmptyDestinationChains
+    );
+}
@@ -3,7 +3,8 @@ use stellar_axelar_gas_service::testutils::setup_gas_token;
 use stellar_axelar_std::testutils::{Address as _, AuthorizedFunction, AuthorizedInvocation};
 use stellar_axelar_std::types::Token;
 use stellar_axelar_std::{
-    assert_contract_err, auth_invocation, events, Address, Bytes, BytesN, IntoVal, String, Symbol,
+    assert_contract_err, auth_invocation, events, vec, Address, Bytes, BytesN, IntoVal, String,
+    Symbol,
 };
 
 use super::utils::{setup_env, TokenMetadataExt};
@@ -12,7 +13,7 @@ use crate::event::InterchainTokenDeploymentStartedEvent;
 use crate::tests::utils::{
     INTERCHAIN_TOKEN_DEPLOYED_EVENT_IDX, INTERCHAIN_TOKEN_DEPLOYED_WITHOUT_GAS_TOKEN_EVENT_IDX,
 };
-use crate::types::{DeployInterchainToken, HubMessage, Message};
+use crate::types::{DeployInterchainToken, GasPayment, HubMessage, Message};
 
 #[test]
 fn deploy_remote_interchain_token_succeeds() {
@@ -81,6 +82,7 @@ fn deploy_remote_interchain_token_succeeds() {
             its_hub_address,
             payload,
             &sender,
+            &sender,
             gas_token.clone(),
             &Bytes::new(&env)
         ),
@@ -244,3 +246,161 @@ fn deploy_remote_token_fails_local_deployment() {
         ContractError::InvalidDestinationChain
     );
 }
+
+#[test]
+fn deploy_remote_interchain_tokens_succeeds() {
+    let (env, client, _, _, _) = setup_env();
+
+    let sender = Address::generate(&env);
+    let gas_token = setup_gas_token(&env, &sender);
+    let salt = BytesN::<32>::from_array(&env, &[1; 32]);
+    let token_metadata = TokenMetadata::new(&env, "name", "symbol", 6);
+
+    let token_id =
+        client
+            .mock_all_auths()
+            .deploy_interchain_token(&sender, &salt, &token_metadata, &1, &None);
+
+    let first_chain = String::from_str(&env, "ethereum");
+    let second_chain = String::from_str(&env, "avalanche");
+
+    client.mock_all_auths().set_trusted_chain(&first_chain);
+    client.mock_all_auths().set_trusted_chain(&second_chain);
+
+    let deployed_token_id = client.mock_all_auths().deploy_remote_interchain_tokens(
+        &sender,
+        &salt,
+        &vec![
+            &env,
+            (first_chain.clone(), Some(gas_token)),
+            (second_chain.clone(), None),
+        ],
+    );
+
+    assert_eq!(token_id, deployed_token_id);
+
+    goldie::assert!(events::fmt_emitted_event_at_idx::<
+        InterchainTokenDeploymentStartedEvent,
+    >(
+        &env,
+        INTERCHAIN_TOKEN_DEPLOYED_WITHOUT_GAS_TOKEN_EVENT_IDX
+    ));
+
+    // A single authorization covers all deployments
+    assert_eq!(env.auths().len(), 1);
+    assert_eq!(env.auths()[0].0, sender);
+
+    for chain in [first_chain, second_chain] {
+        assert_eq!(
+            client.remote_token_decimals(&token_id, &chain),
+            Some(token_metadata.decimal)
+        );
+    }
+}
+
+#[test]
+fn deploy_remote_interchain_tokens_fails_if_any_chain_is_untrusted() {
+    let (env, client, _, _, _) = setup_env();
+
+    let sender = Address::generate(&env);
+    let salt = BytesN::<32>::from_array(&env, &[1; 32]);
+    let token_metadata = TokenMetadata::new(&env, "name", "symbol", 6);
+
+    let token_id =
+        client
+            .mock_all_auths()
+            .deploy_interchain_token(&sender, &salt, &token_metadata, &1, &None);
+
+    let trusted_chain = String::from_str(&env, "ethereum");
+    client.mock_all_auths().set_trusted_chain(&trusted_chain);
+
+    assert_contract_err!(
+        client.mock_all_auths().try_deploy_remote_interchain_tokens(
+            &sender,
+            &salt,
+            &vec![
+                &env,
+                (trusted_chain.clone(), None::<Token>),
+                (String::from_str(&env, "avalanche"), None),
+            ],
+        ),
+        ContractError::UntrustedChain
+    );
+
+    assert_eq!(
+        client.remote_token_decimals(&token_id, &trusted_chain),
+        None
+    );
+}
+
+#[test]
+fn deploy_remote_interchain_tokens_fails_with_empty_destination_chains() {
+    let (env, client, _, _, _) = setup_env();
+
+    let sender = Address::generate(&env);
+    let salt = BytesN::<32>::from_array(&env, &[1; 32]);
+    let token_metadata = TokenMetadata::new(&env, "name", "symbol", 6);
+
+    client
+        .mock_all_auths()
+        .deploy_interchain_token(&sender, &salt, &token_metadata, &1, &None);
+
+    assert_contract_err!(
+        client
+            .mock_all_auths()
+            .try_deploy_remote_interchain_tokens(&sender, &salt, &vec![&env]),
+        ContractError::EmptyDestinationChains
+    );
+}
+
+#[test]
+fn deploy_remote_token_with_gas_succeeds_with_sponsor() {
+    let (env, client, _, gas_service, _) = setup_env();
+
+    let deployer = Address::generate(&env);
+    let sponsor = Address::generate(&env);
+    let gas_token = setup_gas_token(&env, &sponsor);
+    let salt = BytesN::<32>::from_array(&env, &[1; 32]);
+    let destination_chain = String::from_str(&env, "ethereum");
+
+    let token_id = client.mock_all_auths().deploy_interchain_token(
+        &deployer,
+        &salt,
+        &TokenMetadata::new(&env, "name", "symbol", 6),
+        &1,
+        &None,
+    );
+    client
+        .mock_all_auths()
+        .set_trusted_chain(&destination_chain);
+
+    let deployed_token_id = client.mock_all_auths().deploy_remote_token_with_gas(
+        &deployer,
+        &salt,
+        &destination_chain,
+        &None,
+        &GasPayment {
+            spender: sponsor.clone(),
+            token: gas_token.clone(),
+            metadata: Bytes::new(&env),
+            refund_address: sponsor.clone(),
+        },
+    );
+
+    assert_eq!(deployed_token_id, token_id);
+
+    let authorizers: std::vec::Vec<Address> = env
+        .auths()
+        .into_iter()
+        .map(|(address, _)| address)
+        .collect();
+    assert!(authorizers.contains(&deployer));
+    assert!(authorizers.contains(&sponsor));
+
+    let gas_token_client = gas_token.client(&env);
+    assert_eq!(gas_token_client.balance(&sponsor), 0);
+    assert_eq!(
+        gas_token_client.balance(&gas_service.address),
+        gas_token.amount
+    );
+}
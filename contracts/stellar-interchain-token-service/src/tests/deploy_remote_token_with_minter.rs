@@ -0,0 +1,352 @@
+use soroban_token_sdk::metadata::TokenMetadata;
+use stellar_axelar_gas_service::testutils::setup_gas_token;
+use stellar_axelar_std::testutils::Address as _;
+use stellar_axelar_std::token::TokenClient;
+use stellar_axelar_std::types::Token;
+use stellar_axelar_std::{
+    assert_auth, assert_contract_err, events, Address, Bytes, BytesN, Env, String,
+};
+
+use super::utils::{setup_env, TokenMetadataExt};
+use crate::error::ContractError;
+use crate::event::{
+    InterchainTokenDeploymentStartedEvent, RemoteMinterApprovedEvent, RemoteMinterRevokedEvent,
+};
+use crate::tests::utils::INTERCHAIN_TOKEN_DEPLOYED_WITHOUT_GAS_TOKEN_EVENT_IDX;
+use crate::types::GasPayment;
+use crate::InterchainTokenServiceClient;
+
+struct RemoteMinterTestData {
+    deployer: Address,
+    minter: Address,
+    salt: BytesN<32>,
+    token_id: BytesN<32>,
+    destination_chain: String,
+    destination_minter: Bytes,
+}
+
+fn setup_remote_minter_test(
+    env: &Env,
+    client: &InterchainTokenServiceClient,
+) -> RemoteMinterTestData {
+    let deployer = Address::generate(env);
+    let minter = Address::generate(env);
+    let salt = BytesN::<32>::from_array(env, &[1; 32]);
+    let token_metadata = TokenMetadata::new(env, "name", "symbol", 6);
+
+    let token_id = client.mock_all_auths().deploy_interchain_token(
+        &deployer,
+        &salt,
+        &token_metadata,
+        &0,
+        &Some(minter.clone()),
+    );
+
+    let destination_chain = String::from_str(env, "ethereum");
+    client
+        .mock_all_auths()
+        .set_trusted_chain(&destination_chain);
+
+    RemoteMinterTestData {
+        deployer,
+        minter,
+        salt,
+        token_id,
+        destination_chain,
+        destination_minter: Bytes::from_array(env, &[2; 20]),
+    }
+}
+
+fn approve_remote_minter(client: &InterchainTokenServiceClient, data: &RemoteMinterTestData) {
+    client.mock_all_auths().approve_remote_minter(
+        &data.minter,
+        &data.deployer,
+        &data.salt,
+        &data.destination_chain,
+        &data.destination_minter,
+    );
+}
+
+#[test]
+fn approve_remote_minter_succeeds() {
+    let (env, client, _, _, _) = setup_env();
+    let data = setup_remote_minter_test(&env, &client);
+
+    assert_auth!(
+        data.minter,
+        client.approve_remote_minter(
+            &data.minter,
+            &data.deployer,
+            &data.salt,
+            &data.destination_chain,
+            &data.destination_minter,
+        )
+    );
+
+    goldie::assert!(events::fmt_last_emitted_event::<RemoteMinterApprovedEvent>(
+        &env
+    ));
+}
+
+#[test]
+fn approve_remote_minter_fails_if_not_minter() {
+    let (env, client, _, _, _) = setup_env();
+    let data = setup_remote_minter_test(&env, &client);
+
+    assert_contract_err!(
+        client.mock_all_auths().try_approve_remote_minter(
+            &Address::generate(&env),
+            &data.deployer,
+            &data.salt,
+            &data.destination_chain,
+            &data.destination_minter,
+        ),
+        ContractError::NotMinter
+    );
+}
+
+#[test]
+fn approve_remote_minter_fails_with_invalid_token_id() {
+    let (env, client, _, _, _) = setup_env();
+    let data = setup_remote_minter_test(&env, &client);
+
+    assert_contract_err!(
+        client.mock_all_auths().try_approve_remote_minter(
+            &data.minter,
+            &data.deployer,
+            &BytesN::<32>::from_array(&env, &[3; 32]),
+            &data.destination_chain,
+            &data.destination_minter,
+        ),
+        ContractError::InvalidTokenId
+    );
+}
+
+#[test]
+fn revoke_remote_minter_succeeds() {
+    let (env, client, _, _, _) = setup_env();
+    let data = setup_remote_minter_test(&env, &client);
+
+    approve_remote_minter(&client, &data);
+
+    assert_auth!(
+        data.minter,
+        client.revoke_remote_minter(
+            &data.minter,
+            &data.deployer,
+            &data.salt,
+            &data.destination_chain,
+        )
+    );
+
+    goldie::assert!(events::fmt_last_emitted_event::<RemoteMinterRevokedEvent>(
+        &env
+    ));
+
+    assert_contract_err!(
+        client.mock_all_auths().try_deploy_remote_token_with_minter(
+            &data.deployer,
+            &data.salt,
+            &data.minter,
+            &data.destination_chain,
+            &data.destination_minter,
+            &None::<Token>,
+        ),
+        ContractError::RemoteMinterNotApproved
+    );
+}
+
+#[test]
+fn revoke_remote_minter_fails_if_not_approved() {
+    let (env, client, _, _, _) = setup_env();
+    let data = setup_remote_minter_test(&env, &client);
+
+    assert_contract_err!(
+        client.mock_all_auths().try_revoke_remote_minter(
+            &data.minter,
+            &data.deployer,
+            &data.salt,
+            &data.destination_chain,
+        ),
+        ContractError::RemoteMinterNotApproved
+    );
+}
+
+#[test]
+fn deploy_remote_token_with_minter_succeeds() {
+    let (env, client, _, _, _) = setup_env();
+    let data = setup_remote_minter_test(&env, &client);
+
+    approve_remote_minter(&client, &data);
+
+    let deployed_token_id = client.mock_all_auths().deploy_remote_token_with_minter(
+        &data.deployer,
+        &data.salt,
+        &data.minter,
+        &data.destination_chain,
+        &data.destination_minter,
+        &None::<Token>,
+    );
+
+    assert_eq!(deployed_token_id, data.token_id);
+
+    goldie::assert!(events::fmt_emitted_event_at_idx::<
+        InterchainTokenDeploymentStartedEvent,
+    >(
+        &env,
+        INTERCHAIN_TOKEN_DEPLOYED_WITHOUT_GAS_TOKEN_EVENT_IDX
+    ));
+}
+
+#[test]
+fn deploy_remote_token_with_minter_consumes_approval() {
+    let (env, client, _, _, _) = setup_env();
+    let data = setup_remote_minter_test(&env, &client);
+
+    approve_remote_minter(&client, &data);
+
+    client.mock_all_auths().deploy_remote_token_with_minter(
+        &data.deployer,
+        &data.salt,
+        &data.minter,
+        &data.destination_chain,
+        &data.destination_minter,
+        &None::<Token>,
+    );
+
+    assert_contract_err!(
+        client.mock_all_auths().try_deploy_remote_token_with_minter(
+            &data.deployer,
+            &data.salt,
+            &data.minter,
+            &data.destination_chain,
+            &data.destination_minter,
+            &None::<Token>,
+        ),
+        ContractError::RemoteMinterNotApproved
+    );
+}
+
+#[test]
+fn deploy_remote_token_with_minter_fails_without_approval() {
+    let (env, client, _, _, _) = setup_env();
+    let data = setup_remote_minter_test(&env, &client);
+
+    assert_contract_err!(
+        client.mock_all_auths().try_deploy_remote_token_with_minter(
+            &data.deployer,
+            &data.salt,
+            &data.minter,
+            &data.destination_chain,
+            &data.destination_minter,
+            &None::<Token>,
+        ),
+        ContractError::RemoteMinterNotApproved
+    );
+}
+
+#[test]
+fn deploy_remote_token_with_minter_fails_with_unapproved_destination_minter() {
+    let (env, client, _, _, _) = setup_env();
+    let data = setup_remote_minter_test(&env, &client);
+
+    approve_remote_minter(&client, &data);
+
+    assert_contract_err!(
+        client.mock_all_auths().try_deploy_remote_token_with_minter(
+            &data.deployer,
+            &data.salt,
+            &data.minter,
+            &data.destination_chain,
+            &Bytes::from_array(&env, &[3; 20]),
+            &None::<Token>,
+        ),
+        ContractError::RemoteMinterNotApproved
+    );
+}
+
+#[test]
+fn deploy_remote_token_with_minter_fails_if_not_minter() {
+    let (env, client, _, _, _) = setup_env();
+    let data = setup_remote_minter_test(&env, &client);
+
+    approve_remote_minter(&client, &data);
+
+    assert_contract_err!(
+        client.mock_all_auths().try_deploy_remote_token_with_minter(
+            &data.deployer,
+            &data.salt,
+            &Address::generate(&env),
+            &data.destination_chain,
+            &data.destination_minter,
+            &None::<Token>,
+        ),
+        ContractError::NotMinter
+    );
+}
+
+#[test]
+fn deploy_remote_token_with_minter_fails_when_paused() {
+    let (env, client, _, _, _) = setup_env();
+    let data = setup_remote_minter_test(&env, &client);
+
+    approve_remote_minter(&client, &data);
+    client.mock_all_auths().pause();
+
+    assert_contract_err!(
+        client.mock_all_auths().try_deploy_remote_token_with_minter(
+            &data.deployer,
+            &data.salt,
+            &data.minter,
+            &data.destination_chain,
+            &data.destination_minter,
+            &None::<Token>,
+        ),
+        ContractError::ContractPaused
+    );
+}
+
+#[test]
+fn deploy_remote_token_with_gas_succeeds_with_minter() {
+    let (env, client, _, gas_service, _) = setup_env();
+    let data = setup_remote_minter_test(&env, &client);
+    let sponsor = Address::generate(&env);
+    let gas_token = setup_gas_token(&env, &sponsor);
+
+    approve_remote_minter(&client, &data);
+
+    let deployed_token_id = client.mock_all_auths().deploy_remote_token_with_gas(
+        &data.deployer,
+        &data.salt,
+        &data.destination_chain,
+        &Some((data.minter.clone(), data.destination_minter.clone())),
+        &GasPayment {
+            spender: sponsor.clone(),
+            token: gas_token.clone(),
+            metadata: Bytes::new(&env),
+            refund_address: sponsor.clone(),
+        },
+    );
+
+    assert_eq!(deployed_token_id, data.token_id);
+    assert_eq!(
+        TokenClient::new(&env, &gas_token.address).balance(&gas_service.address),
+        gas_token.amount
+    );
+
+    assert_contract_err!(
+        client.mock_all_auths().try_deploy_remote_token_with_gas(
+            &data.deployer,
+            &data.salt,
+            &data.destination_chain,
+            &Some((data.minter, data.destination_minter)),
+            &GasPayment {
+                spender: sponsor.clone(),
+                token: gas_token,
+                metadata: Bytes::new(&env),
+                refund_address: sponsor,
+            },
+        ),
+        ContractError::RemoteMinterNotApproved
+    );
+}
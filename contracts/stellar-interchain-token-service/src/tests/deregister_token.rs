@@ -0,0 +1,287 @@
+use stellar_axelar_std::interfaces::OwnableClient;
+use stellar_axelar_std::testutils::Address as _;
+use stellar_axelar_std::token::{StellarAssetClient, TokenClient};
+use stellar_axelar_std::{
+    assert_auth, assert_auth_err, assert_contract_err, events, vec, Address, BytesN, String,
+};
+use stellar_interchain_token::InterchainTokenClient;
+
+use super::utils::{
+    interchain_transfer, register_custom_token, register_stellar_asset, setup_env,
+    DESTINATION_CHAIN,
+};
+use crate::error::ContractError;
+use crate::event::TokenDeregisteredEvent;
+use crate::testutils::setup_its_token;
+use crate::types::TokenManagerType;
+
+#[test]
+fn deregister_token_succeeds_by_owner() {
+    let (env, client, _, _, _) = setup_env();
+    let owner = client.owner();
+    let token_address = register_stellar_asset(&env);
+    let token_id = client.register_canonical_token(&token_address);
+
+    assert!(client.is_token_registered(&token_id));
+    assert!(!client.is_token_deregistered(&token_id));
+
+    assert_auth!(owner, client.deregister_token(&owner, &token_id));
+
+    goldie::assert!(events::fmt_last_emitted_event::<TokenDeregisteredEvent>(
+        &env
+    ));
+
+    assert!(!client.is_token_registered(&token_id));
+    assert!(client.is_token_deregistered(&token_id));
+    assert_eq!(client.registered_token_info(&token_id), None);
+    assert_eq!(client.registered_token_count(), 0);
+    assert_eq!(
+        client.token_ids_by_address(&token_address, &0, &10),
+        vec![&env]
+    );
+}
+
+#[test]
+fn deregister_token_succeeds_by_token_deployer() {
+    let (env, client, _, _, _) = setup_env();
+    let deployer = Address::generate(&env);
+    let token_id = register_custom_token(
+        &env,
+        &client,
+        &deployer,
+        &register_stellar_asset(&env),
+        TokenManagerType::LockUnlock,
+    );
+
+    assert_auth!(deployer, client.deregister_token(&deployer, &token_id));
+
+    assert!(client.is_token_deregistered(&token_id));
+    assert_eq!(client.token_deployer(&token_id), None);
+}
+
+#[test]
+fn deregister_token_recovers_locked_funds() {
+    let (env, client, _, _, _) = setup_env();
+    let owner = client.owner();
+    let token_address = register_stellar_asset(&env);
+    let token_id = client.register_canonical_token(&token_address);
+    let token_manager = client.deployed_token_manager(&token_id);
+
+    StellarAssetClient::new(&env, &token_address)
+        .mock_all_auths()
+        .mint(&token_manager, &1000);
+
+    client.mock_all_auths().deregister_token(&owner, &token_id);
+
+    let token = TokenClient::new(&env, &token_address);
+    assert_eq!(token.balance(&owner), 1000);
+    assert_eq!(token.balance(&token_manager), 0);
+}
+
+#[test]
+fn deregister_token_keeps_token_ownership_if_token_deployer() {
+    let (env, client, _, _, _) = setup_env();
+    let deployer = Address::generate(&env);
+    let (token_id, _) = setup_its_token(&env, &client, &deployer, 100);
+    let token_address = client.registered_token_address(&token_id);
+    let token_manager = client.deployed_token_manager(&token_id);
+
+    client
+        .mock_all_auths()
+        .deregister_token(&deployer, &token_id);
+
+    assert!(!InterchainTokenClient::new(&env, &token_address).is_minter(&token_manager));
+    assert_eq!(
+        OwnableClient::new(&env, &token_address).owner(),
+        client.address
+    );
+}
+
+#[test]
+fn deregister_token_hands_token_ownership_to_owner() {
+    let (env, client, _, _, _) = setup_env();
+    let (token_id, _) = setup_its_token(&env, &client, &Address::generate(&env), 100);
+    let token_address = client.registered_token_address(&token_id);
+    let owner = client.owner();
+
+    client.mock_all_auths().deregister_token(&owner, &token_id);
+
+    assert_eq!(OwnableClient::new(&env, &token_address).owner(), owner);
+}
+
+#[test]
+fn deregister_token_clears_compliance_hook() {
+    let (env, client, _, _, _) = setup_env();
+    let deployer = Address::generate(&env);
+    let token_id = register_custom_token(
+        &env,
+        &client,
+        &deployer,
+        &register_stellar_asset(&env),
+        TokenManagerType::LockUnlock,
+    );
+
+    client.mock_all_auths().set_compliance_hook(
+        &deployer,
+        &token_id,
+        &Some(Address::generate(&env)),
+    );
+
+    client
+        .mock_all_auths()
+        .deregister_token(&deployer, &token_id);
+
+    assert_eq!(client.compliance_hook(&token_id), None);
+}
+
+#[test]
+fn deregister_token_clears_token_settings() {
+    let (env, client, _, _, _) = setup_env();
+    let deployer = Address::generate(&env);
+    let token_id = register_custom_token(
+        &env,
+        &client,
+        &deployer,
+        &register_stellar_asset(&env),
+        TokenManagerType::LockUnlock,
+    );
+
+    client
+        .mock_all_auths()
+        .set_flow_limit(&token_id, &Some(1000));
+    client.mock_all_auths().set_fee_rate(&token_id, &Some(30));
+    client
+        .mock_all_auths()
+        .set_decimal_scaling(&deployer, &token_id, &true);
+    client.mock_all_auths().pause_token(&token_id);
+
+    client
+        .mock_all_auths()
+        .deregister_token(&client.owner(), &token_id);
+
+    assert_eq!(client.flow_limit(&token_id), None);
+    assert_eq!(client.fee_rate(&token_id), None);
+    assert!(!client.is_decimal_scaling_enabled(&token_id));
+    assert!(!client.is_token_paused(&token_id));
+}
+
+#[test]
+fn deregister_token_succeeds_by_owner_after_transfer() {
+    let (env, client, _, _, _) = setup_env();
+    let sender = Address::generate(&env);
+    let (token_id, _) = setup_its_token(&env, &client, &sender, 100);
+
+    client
+        .mock_all_auths()
+        .set_trusted_chain(&String::from_str(&env, DESTINATION_CHAIN));
+    interchain_transfer(&env, &client, &sender, &token_id, 100);
+
+    client
+        .mock_all_auths()
+        .deregister_token(&client.owner(), &token_id);
+
+    assert!(client.is_token_deregistered(&token_id));
+}
+
+#[test]
+fn deregister_token_fails_by_token_deployer_after_transfer() {
+    let (env, client, _, _, _) = setup_env();
+    let sender = Address::generate(&env);
+    let (token_id, _) = setup_its_token(&env, &client, &sender, 100);
+
+    client
+        .mock_all_auths()
+        .set_trusted_chain(&String::from_str(&env, DESTINATION_CHAIN));
+    interchain_transfer(&env, &client, &sender, &token_id, 100);
+
+    assert_contract_err!(
+        client
+            .mock_all_auths()
+            .try_deregister_token(&sender, &token_id),
+        ContractError::TokenAlreadyTransferred
+    );
+}
+
+#[test]
+fn deregister_token_fails_if_not_owner_or_token_deployer() {
+    let (env, client, _, _, _) = setup_env();
+    let token_id = register_custom_token(
+        &env,
+        &client,
+        &Address::generate(&env),
+        &register_stellar_asset(&env),
+        TokenManagerType::LockUnlock,
+    );
+
+    assert_contract_err!(
+        client
+            .mock_all_auths()
+            .try_deregister_token(&Address::generate(&env), &token_id),
+        ContractError::NotOwnerOrTokenDeployer
+    );
+}
+
+#[test]
+fn deregister_token_fails_with_invalid_auth() {
+    let (env, client, _, _, _) = setup_env();
+    let deployer = Address::generate(&env);
+    let token_id = register_custom_token(
+        &env,
+        &client,
+        &deployer,
+        &register_stellar_asset(&env),
+        TokenManagerType::LockUnlock,
+    );
+
+    assert_auth_err!(
+        Address::generate(&env),
+        client.deregister_token(&deployer, &token_id)
+    );
+}
+
+#[test]
+fn deregister_token_fails_with_invalid_token_id() {
+    let (env, client, _, _, _) = setup_env();
+
+    assert_contract_err!(
+        client
+            .mock_all_auths()
+            .try_deregister_token(&client.owner(), &BytesN::from_array(&env, &[1; 32])),
+        ContractError::InvalidTokenId
+    );
+}
+
+#[test]
+fn register_token_fails_if_deregistered() {
+    let (env, client, _, _, _) = setup_env();
+    let deployer = Address::generate(&env);
+    let token_address = register_stellar_asset(&env);
+    let custom_token_id = register_custom_token(
+        &env,
+        &client,
+        &deployer,
+        &token_address,
+        TokenManagerType::LockUnlock,
+    );
+    let canonical_token_id = client.register_canonical_token(&token_address);
+
+    for token_id in [&custom_token_id, &canonical_token_id] {
+        client
+            .mock_all_auths()
+            .deregister_token(&client.owner(), token_id);
+    }
+
+    assert_contract_err!(
+        client.try_register_canonical_token(&token_address),
+        ContractError::TokenDeregistered
+    );
+    assert_contract_err!(
+        client.mock_all_auths().try_register_custom_token(
+            &deployer,
+            &BytesN::<32>::from_array(&env, &[1; 32]),
+            &token_address,
+            &TokenManagerType::LockUnlock,
+        ),
+        ContractError::TokenDeregistered
+    );
+}
@@ -3,9 +3,12 @@ use stellar_axelar_gateway::types::Message as GatewayMessage;
 use stellar_axelar_std::address::AddressExt;
 use stellar_axelar_std::testutils::Address as _;
 use stellar_axelar_std::traits::BytesExt;
-use stellar_axelar_std::{assert_auth_err, events, token, vec, Address, Bytes, BytesN, String};
+use stellar_axelar_std::{
+    assert_auth, assert_auth_err, events, token, vec, Address, Bytes, BytesN, String,
+};
 
 use super::utils::setup_env;
+use crate::event::{ContractExecutionFailedEvent, NonRevertingExecutionSetEvent};
 use crate::testutils::setup_its_token;
 use crate::types::{HubMessage, InterchainTransfer, Message};
 
@@ -246,3 +249,104 @@ fn interchain_transfer_execute_fails_if_payload_is_len_one() {
 
     client.execute(&source_chain, &message_id, &source_address, &payload);
 }
+
+#[test]
+fn set_non_reverting_execution_succeeds() {
+    let (env, client, _, _, _) = setup_env();
+
+    let executable_id = env.register(test::ExecutableContract, (client.address.clone(),));
+
+    assert!(!client.is_non_reverting_execution(&executable_id));
+
+    assert_auth!(
+        executable_id,
+        client.set_non_reverting_execution(&executable_id, &true)
+    );
+
+    goldie::assert!(events::fmt_last_emitted_event::<
+        NonRevertingExecutionSetEvent,
+    >(&env));
+
+    assert!(client.is_non_reverting_execution(&executable_id));
+
+    client
+        .mock_all_auths()
+        .set_non_reverting_execution(&executable_id, &false);
+
+    assert!(!client.is_non_reverting_execution(&executable_id));
+}
+
+#[test]
+fn set_non_reverting_execution_fails_without_contract_auth() {
+    let (env, client, _, _, _) = setup_env();
+
+    let executable_id = env.register(test::ExecutableContract, (client.address.clone(),));
+
+    assert_auth_err!(
+        Address::generate(&env),
+        client.set_non_reverting_execution(&executable_id, &true)
+    );
+}
+
+#[test]
+fn interchain_transfer_execute_keeps_tokens_if_non_reverting_execution_fails() {
+    let (env, client, gateway_client, _, signers) = setup_env();
+
+    let executable_id = env.register(test::ExecutableContract, (client.address.clone(),));
+    client
+        .mock_all_auths()
+        .set_non_reverting_execution(&executable_id, &true);
+
+    let sender = Address::generate(&env).to_string_bytes();
+    let source_chain = client.its_hub_chain_name();
+    let source_address = client.its_hub_address();
+
+    let amount = 1000;
+    let deployer = Address::generate(&env);
+    let (token_id, _) = setup_its_token(&env, &client, &deployer, amount);
+    let data_with_len_1 = Bytes::from_slice(&env, &[1]);
+    let destination_address = executable_id.to_string_bytes();
+    let original_source_chain = String::from_str(&env, "ethereum");
+    client
+        .mock_all_auths()
+        .set_trusted_chain(&original_source_chain);
+
+    let msg = HubMessage::ReceiveFromHub {
+        source_chain: original_source_chain,
+        message: Message::InterchainTransfer(InterchainTransfer {
+            token_id: token_id.clone(),
+            source_address: sender,
+            destination_address,
+            amount,
+            data: Some(data_with_len_1),
+        }),
+    };
+    let payload = msg.abi_encode(&env).unwrap();
+    let payload_hash: BytesN<32> = env.crypto().keccak256(&payload).into();
+
+    let message_id = String::from_str(&env, "test");
+
+    let messages = vec![
+        &env,
+        GatewayMessage {
+            source_chain: source_chain.clone(),
+            message_id: message_id.clone(),
+            source_address: source_address.clone(),
+            contract_address: client.address.clone(),
+            payload_hash,
+        },
+    ];
+    let data_hash = get_approve_hash(&env, messages.clone());
+    let proof = generate_proof(&env, data_hash, signers);
+
+    gateway_client.approve_messages(&messages, &proof);
+
+    client.execute(&source_chain, &message_id, &source_address, &payload);
+    goldie::assert!(events::fmt_last_emitted_event::<ContractExecutionFailedEvent>(&env));
+
+    let token = token::TokenClient::new(&env, &client.registered_token_address(&token_id));
+    assert_eq!(token.balance(&executable_id), amount);
+
+    let executable_client = test::ExecutableContractClient::new(&env, &executable_id);
+    assert_eq!(executable_client.message(), None);
+}
@@ -1,11 +1,14 @@
 use soroban_token_sdk::metadata::TokenMetadata;
-use stellar_axelar_gateway::testutils::approve_gateway_messages;
+use stellar_axelar_gateway::testutils::{approve_gateway_messages, TestSignerSet};
 use stellar_axelar_gateway::types::Message as GatewayMessage;
+use stellar_axelar_gateway::AxelarGatewayClient;
 use stellar_axelar_std::address::AddressExt;
 use stellar_axelar_std::testutils::Address as _;
 use stellar_axelar_std::token::{StellarAssetClient, TokenClient};
-use stellar_axelar_std::{assert_contract_err, events, vec, Address, Bytes, BytesN, Env, String};
-use stellar_interchain_token::InterchainTokenClient;
+use stellar_axelar_std::{
+    assert_contract_err, events, vec, Address, Bytes, BytesN, Env, MuxedAddress, String,
+};
+use stellar_interchain_token::{InterchainToken, InterchainTokenClient};
 
 use super::utils::{setup_env, TokenMetadataExt};
 use crate::error::ContractError;
@@ -17,9 +20,12 @@ use crate::testutils::setup_its_token;
 use crate::types::{
     DeployInterchainToken, HubMessage, InterchainTransfer, LinkToken, Message, TokenManagerType,
 };
+use crate::InterchainTokenServiceClient;
 
 const LINK_TOKEN_TEST_MESSAGE_ID: &str = "test";
 const LINK_TOKEN_TEST_ORIGINAL_SOURCE_CHAIN: &str = "ethereum";
+const MUXED_RECIPIENT: &str =
+    "MA7QYNF7SOWQ3GLR2BGMZEHXAVIRZA4KVWLTJJFC7MGXUA74P7UJUAAAAAAAAEWWQ57DW";
 
 struct LinkTokenTestData {
     hub_message: HubMessage,
@@ -188,6 +194,124 @@ fn interchain_transfer_message_canonical_token_execute_succeeds() {
     );
 }
 
+fn execute_interchain_transfer_to(
+    env: &Env,
+    client: &InterchainTokenServiceClient,
+    gateway_client: &AxelarGatewayClient,
+    signers: TestSignerSet,
+    token_id: BytesN<32>,
+    destination_address: Bytes,
+    amount: i128,
+) {
+    let source_chain = client.its_hub_chain_name();
+    let source_address = client.its_hub_address();
+    let original_source_chain = String::from_str(env, "ethereum");
+
+    client
+        .mock_all_auths()
+        .set_trusted_chain(&original_source_chain);
+
+    let msg = HubMessage::ReceiveFromHub {
+        source_chain: original_source_chain,
+        message: Message::InterchainTransfer(InterchainTransfer {
+            token_id,
+            source_address: Address::generate(env).to_string_bytes(),
+            destination_address,
+            amount,
+            data: None,
+        }),
+    };
+    let message_id = String::from_str(env, "test");
+    let payload = msg.abi_encode(env).unwrap();
+    let payload_hash: BytesN<32> = env.crypto().keccak256(&payload).into();
+
+    let messages = vec![
+        env,
+        GatewayMessage {
+            source_chain: source_chain.clone(),
+            message_id: message_id.clone(),
+            source_address: source_address.clone(),
+            contract_address: client.address.clone(),
+            payload_hash,
+        },
+    ];
+
+    approve_gateway_messages(env, gateway_client, signers, messages);
+
+    client.execute(&source_chain, &message_id, &source_address, &payload);
+}
+
+#[test]
+fn interchain_transfer_message_execute_succeeds_with_muxed_destination() {
+    let (env, client, gateway_client, _, signers) = setup_env();
+
+    let amount = 1000;
+    let (token_id, _) = setup_its_token(&env, &client, &Address::generate(&env), amount);
+    let recipient = MuxedAddress::from_str(&env, MUXED_RECIPIENT);
+
+    execute_interchain_transfer_to(
+        &env,
+        &client,
+        &gateway_client,
+        signers,
+        token_id.clone(),
+        Bytes::from_slice(&env, MUXED_RECIPIENT.as_bytes()),
+        amount,
+    );
+
+    goldie::assert!(events::fmt_last_emitted_event::<
+        InterchainTransferReceivedEvent,
+    >(&env));
+
+    let token = TokenClient::new(&env, &client.registered_token_address(&token_id));
+    assert_eq!(token.balance(&recipient.address()), amount);
+}
+
+#[test]
+fn interchain_transfer_message_lock_unlock_execute_succeeds_with_muxed_destination() {
+    let (env, client, gateway_client, _, signers) = setup_env();
+
+    let amount = 1000;
+    let deployer = Address::generate(&env);
+    // The pinned v1.0.0 token wasm predates muxed transfers, so the current contract is registered natively
+    let token_address = env.register(
+        InterchainToken,
+        (
+            deployer.clone(),
+            None::<Address>,
+            BytesN::<32>::from_array(&env, &[1; 32]),
+            TokenMetadata::new(&env, "Test", "TEST", 7),
+        ),
+    );
+    let token_id = client.mock_all_auths().register_custom_token(
+        &deployer,
+        &BytesN::<32>::from_array(&env, &[2; 32]),
+        &token_address,
+        &TokenManagerType::LockUnlock,
+    );
+    let token_manager = client.deployed_token_manager(&token_id);
+    let recipient = MuxedAddress::from_str(&env, MUXED_RECIPIENT);
+
+    InterchainTokenClient::new(&env, &token_address)
+        .mock_all_auths()
+        .mint(&token_manager, &amount);
+
+    execute_interchain_transfer_to(
+        &env,
+        &client,
+        &gateway_client,
+        signers,
+        token_id,
+        Bytes::from_slice(&env, MUXED_RECIPIENT.as_bytes()),
+        amount,
+    );
+
+    let token = TokenClient::new(&env, &token_address);
+    assert_eq!(token.balance(&token_manager), 0);
+    assert_eq!(token.balance(&recipient.address()), amount);
+    assert_eq!(recipient.id(), Some(1_234_567));
+}
+
 #[test]
 fn deploy_interchain_token_message_execute_succeeds() {
     let (env, client, gateway_client, _, signers) = setup_env();
@@ -0,0 +1,226 @@
+use soroban_token_sdk::metadata::TokenMetadata;
+use stellar_axelar_std::interfaces::OwnableClient;
+use stellar_axelar_std::testutils::Address as _;
+use stellar_axelar_std::token::{StellarAssetClient, TokenClient};
+use stellar_axelar_std::{
+    assert_auth, assert_auth_err, assert_contract_err, events, vec, Address, BytesN,
+};
+use stellar_interchain_token::{InterchainToken, InterchainTokenClient};
+
+use super::utils::{register_custom_token, register_stellar_asset, setup_env, TokenMetadataExt};
+use crate::error::ContractError;
+use crate::event::{TokenDeregisteredEvent, TokenExitedEvent};
+use crate::testutils::setup_its_token;
+use crate::types::TokenManagerType;
+
+#[test]
+fn exit_token_unlocks_locked_funds() {
+    let (env, client, _, _, _) = setup_env();
+    let recipient = Address::generate(&env);
+    let token_address = register_stellar_asset(&env);
+    let token_id = client.register_canonical_token(&token_address);
+    let token_manager = client.deployed_token_manager(&token_id);
+    let owner = client.owner();
+
+    StellarAssetClient::new(&env, &token_address)
+        .mock_all_auths()
+        .mint(&token_manager, &1000);
+
+    assert_auth!(owner, client.exit_token(&token_id, &recipient));
+
+    let exited_event = events::fmt_emitted_event_at_idx::<TokenExitedEvent>(&env, -2);
+    let deregistered_event = events::fmt_last_emitted_event::<TokenDeregisteredEvent>(&env);
+
+    goldie::assert!([exited_event, deregistered_event].join("\n\n"));
+
+    let token = TokenClient::new(&env, &token_address);
+    assert_eq!(token.balance(&recipient), 1000);
+    assert_eq!(token.balance(&token_manager), 0);
+    assert_eq!(client.registered_token_info(&token_id), None);
+    assert!(client.is_token_deregistered(&token_id));
+    assert_eq!(client.registered_token_count(), 0);
+    assert_eq!(
+        client.token_ids_by_address(&token_address, &0, &10),
+        vec![&env]
+    );
+}
+
+#[test]
+fn exit_token_transfers_admin() {
+    let (env, client, _, _, _) = setup_env();
+    let recipient = Address::generate(&env);
+    let token_address = register_stellar_asset(&env);
+    let token = StellarAssetClient::new(&env, &token_address);
+    let token_id = register_custom_token(
+        &env,
+        &client,
+        &Address::generate(&env),
+        &token_address,
+        TokenManagerType::MintBurn,
+    );
+
+    token
+        .mock_all_auths()
+        .set_admin(&client.deployed_token_manager(&token_id));
+
+    client.mock_all_auths().exit_token(&token_id, &recipient);
+
+    assert_eq!(token.admin(), recipient);
+    assert_eq!(client.registered_token_info(&token_id), None);
+}
+
+#[test]
+fn exit_token_succeeds_if_token_manager_is_not_admin() {
+    let (env, client, _, _, _) = setup_env();
+    let token_address = register_stellar_asset(&env);
+    let admin = StellarAssetClient::new(&env, &token_address).admin();
+    let token_id = register_custom_token(
+        &env,
+        &client,
+        &Address::generate(&env),
+        &token_address,
+        TokenManagerType::MintBurn,
+    );
+
+    client
+        .mock_all_auths()
+        .exit_token(&token_id, &Address::generate(&env));
+
+    assert_eq!(StellarAssetClient::new(&env, &token_address).admin(), admin);
+    assert_eq!(client.registered_token_info(&token_id), None);
+}
+
+#[test]
+fn exit_token_deregisters_mint_burn_from_token() {
+    let (env, client, _, _, _) = setup_env();
+    let token_address = env.register(
+        InterchainToken,
+        (
+            Address::generate(&env),
+            None::<Address>,
+            BytesN::<32>::from_array(&env, &[2; 32]),
+            TokenMetadata::new(&env, "Test", "TEST", 7),
+        ),
+    );
+    let token_id = register_custom_token(
+        &env,
+        &client,
+        &Address::generate(&env),
+        &token_address,
+        TokenManagerType::MintBurnFrom,
+    );
+
+    client
+        .mock_all_auths()
+        .exit_token(&token_id, &Address::generate(&env));
+
+    assert_eq!(client.registered_token_info(&token_id), None);
+}
+
+#[test]
+fn exit_token_fails_if_token_manager_is_still_minter() {
+    let (env, client, _, _, _) = setup_env();
+    let token_owner = Address::generate(&env);
+    let token_address = env.register(
+        InterchainToken,
+        (
+            token_owner,
+            None::<Address>,
+            BytesN::<32>::from_array(&env, &[2; 32]),
+            TokenMetadata::new(&env, "Test", "TEST", 7),
+        ),
+    );
+    let token = InterchainTokenClient::new(&env, &token_address);
+
+    for token_manager_type in [TokenManagerType::MintBurn, TokenManagerType::MintBurnFrom] {
+        let token_id = register_custom_token(
+            &env,
+            &client,
+            &Address::generate(&env),
+            &token_address,
+            token_manager_type,
+        );
+        let token_manager = client.deployed_token_manager(&token_id);
+        token.mock_all_auths().add_minter(&token_manager);
+
+        assert_contract_err!(
+            client
+                .mock_all_auths()
+                .try_exit_token(&token_id, &Address::generate(&env)),
+            ContractError::TokenManagerStillMinter
+        );
+
+        token.mock_all_auths().remove_minter(&token_manager);
+
+        client
+            .mock_all_auths()
+            .exit_token(&token_id, &Address::generate(&env));
+
+        assert!(client.is_token_deregistered(&token_id));
+    }
+}
+
+#[test]
+fn exit_token_removes_token_manager_as_minter() {
+    let (env, client, _, _, _) = setup_env();
+    let recipient = Address::generate(&env);
+    let (token_id, _) = setup_its_token(&env, &client, &Address::generate(&env), 100);
+    let token_address = client.registered_token_address(&token_id);
+    let token_manager = client.deployed_token_manager(&token_id);
+
+    client.mock_all_auths().exit_token(&token_id, &recipient);
+
+    assert!(!InterchainTokenClient::new(&env, &token_address).is_minter(&token_manager));
+    assert_eq!(OwnableClient::new(&env, &token_address).owner(), recipient);
+    assert_eq!(client.registered_token_info(&token_id), None);
+}
+
+#[test]
+fn exit_token_keeps_registry_contiguous() {
+    let (env, client, _, _, _) = setup_env();
+    let token_ids: [BytesN<32>; 3] =
+        core::array::from_fn(|_| client.register_canonical_token(&register_stellar_asset(&env)));
+
+    client
+        .mock_all_auths()
+        .exit_token(&token_ids[0], &Address::generate(&env));
+
+    assert_eq!(client.registered_token_count(), 2);
+    assert_eq!(
+        client.registered_token_ids(&0, &10),
+        vec![&env, token_ids[2].clone(), token_ids[1].clone()]
+    );
+
+    client
+        .mock_all_auths()
+        .exit_token(&token_ids[1], &Address::generate(&env));
+
+    assert_eq!(
+        client.registered_token_ids(&0, &10),
+        vec![&env, token_ids[2].clone()]
+    );
+}
+
+#[test]
+fn exit_token_fails_with_invalid_token_id() {
+    let (env, client, _, _, _) = setup_env();
+
+    assert_contract_err!(
+        client.mock_all_auths().try_exit_token(
+            &BytesN::from_array(&env, &[1; 32]),
+            &Address::generate(&env)
+        ),
+        ContractError::InvalidTokenId
+    );
+}
+
+#[test]
+fn exit_token_fails_with_invalid_auth() {
+    let (env, client, _, _, _) = setup_env();
+    let token_id = client.register_canonical_token(&register_stellar_asset(&env));
+
+    assert_auth_err!(
+        Address::generate(&env),
+        client.exit_token(&token_id, &Address::generate(&env))
+    );
+}
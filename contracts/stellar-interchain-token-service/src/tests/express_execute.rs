@@ -0,0 +1,259 @@
+use stellar_axelar_std::testutils::Address as _;
+use stellar_axelar_std::token::TokenClient;
+use stellar_axelar_std::{assert_contract_err, events, Address, BytesN, Env, String};
+
+use super::utils::{approve_hub_transfer, setup_env, setup_hub_transfer, HubTransferTestData};
+use crate::error::ContractError;
+use crate::event::{ExpressExecutedEvent, ExpressExecutionFulfilledEvent};
+use crate::testutils::setup_its_token;
+use crate::types::{DeployInterchainToken, HubMessage, Message};
+use crate::InterchainTokenServiceClient;
+
+fn setup_express_transfer(
+    env: &Env,
+    client: &InterchainTokenServiceClient,
+) -> (HubTransferTestData, Address) {
+    let amount = 1000;
+    let express_executor = Address::generate(env);
+    let (token_id, _) = setup_its_token(env, client, &express_executor, amount);
+
+    (
+        setup_hub_transfer(env, client, token_id, amount, None),
+        express_executor,
+    )
+}
+
+#[test]
+fn express_execute_succeeds() {
+    let (env, client, _, _, _) = setup_env();
+    let (data, express_executor) = setup_express_transfer(&env, &client);
+    let token_address = client.registered_token_address(&data.token_id);
+    let payload_hash: BytesN<32> = env.crypto().keccak256(&data.payload).into();
+
+    client.mock_all_auths().express_execute(
+        &express_executor,
+        &data.source_chain,
+        &data.message_id,
+        &data.source_address,
+        &data.payload,
+    );
+
+    goldie::assert!(events::fmt_emitted_event_at_idx::<ExpressExecutedEvent>(
+        &env, -3
+    ));
+
+    let token = TokenClient::new(&env, &token_address);
+    assert_eq!(token.balance(&express_executor), 0);
+    assert_eq!(token.balance(&data.destination_address), data.amount);
+    assert_eq!(
+        client.express_executor(
+            &data.source_chain,
+            &data.message_id,
+            &data.source_address,
+            &payload_hash
+        ),
+        Some(express_executor)
+    );
+}
+
+#[test]
+fn execute_after_express_execute_repays_express_executor() {
+    let (env, client, gateway_client, _, signers) = setup_env();
+    let (data, express_executor) = setup_express_transfer(&env, &client);
+    let token_address = client.registered_token_address(&data.token_id);
+    let payload_hash: BytesN<32> = env.crypto().keccak256(&data.payload).into();
+
+    client.mock_all_auths().express_execute(
+        &express_executor,
+        &data.source_chain,
+        &data.message_id,
+        &data.source_address,
+        &data.payload,
+    );
+
+    approve_hub_transfer(&env, &client, &gateway_client, signers, &data);
+
+    client.execute(
+        &data.source_chain,
+        &data.message_id,
+        &data.source_address,
+        &data.payload,
+    );
+
+    goldie::assert!(events::fmt_last_emitted_event::<
+        ExpressExecutionFulfilledEvent,
+    >(&env));
+
+    let token = TokenClient::new(&env, &token_address);
+    assert_eq!(token.balance(&express_executor), data.amount);
+    assert_eq!(token.balance(&data.destination_address), data.amount);
+    assert_eq!(
+        client.express_executor(
+            &data.source_chain,
+            &data.message_id,
+            &data.source_address,
+            &payload_hash
+        ),
+        None
+    );
+}
+
+#[test]
+fn execute_after_express_execute_repays_fronted_amount_if_decimals_change() {
+    let (env, client, gateway_client, _, signers) = setup_env();
+    let (data, express_executor) = setup_express_transfer(&env, &client);
+    let token_address = client.registered_token_address(&data.token_id);
+
+    client.mock_all_auths().express_execute(
+        &express_executor,
+        &data.source_chain,
+        &data.message_id,
+        &data.source_address,
+        &data.payload,
+    );
+
+    // The deployer of the token starts scaling amounts from the source chain with fewer decimals
+    client.mock_all_auths().set_remote_token_decimals(
+        &express_executor,
+        &data.token_id,
+        &String::from_str(&env, "ethereum"),
+        &17,
+    );
+    client
+        .mock_all_auths()
+        .set_decimal_scaling(&express_executor, &data.token_id, &true);
+
+    approve_hub_transfer(&env, &client, &gateway_client, signers, &data);
+
+    client.execute(
+        &data.source_chain,
+        &data.message_id,
+        &data.source_address,
+        &data.payload,
+    );
+
+    let token = TokenClient::new(&env, &token_address);
+    assert_eq!(token.balance(&express_executor), data.amount);
+}
+
+#[test]
+fn express_execute_fails_if_already_express_executed() {
+    let (env, client, _, _, _) = setup_env();
+    let (data, express_executor) = setup_express_transfer(&env, &client);
+
+    client.mock_all_auths().express_execute(
+        &express_executor,
+        &data.source_chain,
+        &data.message_id,
+        &data.source_address,
+        &data.payload,
+    );
+
+    assert_contract_err!(
+        client.mock_all_auths().try_express_execute(
+            &Address::generate(&env),
+            &data.source_chain,
+            &data.message_id,
+            &data.source_address,
+            &data.payload,
+        ),
+        ContractError::ExpressExecutorAlreadySet
+    );
+}
+
+#[test]
+fn express_execute_fails_if_message_already_executed() {
+    let (env, client, gateway_client, _, signers) = setup_env();
+    let (data, express_executor) = setup_express_transfer(&env, &client);
+
+    approve_hub_transfer(&env, &client, &gateway_client, signers, &data);
+
+    client.execute(
+        &data.source_chain,
+        &data.message_id,
+        &data.source_address,
+        &data.payload,
+    );
+
+    assert_contract_err!(
+        client.mock_all_auths().try_express_execute(
+            &express_executor,
+            &data.source_chain,
+            &data.message_id,
+            &data.source_address,
+            &data.payload,
+        ),
+        ContractError::MessageAlreadyExecuted
+    );
+}
+
+#[test]
+fn express_execute_fails_with_invalid_message_type() {
+    let (env, client, _, _, _) = setup_env();
+    let original_source_chain = String::from_str(&env, "ethereum");
+    client
+        .mock_all_auths()
+        .set_trusted_chain(&original_source_chain);
+
+    let payload = HubMessage::ReceiveFromHub {
+        source_chain: original_source_chain,
+        message: Message::DeployInterchainToken(DeployInterchainToken {
+            token_id: BytesN::from_array(&env, &[1u8; 32]),
+            name: String::from_str(&env, "Test"),
+            symbol: String::from_str(&env, "TEST"),
+            decimals: 18,
+            minter: None,
+        }),
+    }
+    .abi_encode(&env)
+    .unwrap();
+
+    assert_contract_err!(
+        client.mock_all_auths().try_express_execute(
+            &Address::generate(&env),
+            &client.its_hub_chain_name(),
+            &String::from_str(&env, "test"),
+            &client.its_hub_address(),
+            &payload,
+        ),
+        ContractError::InvalidMessageType
+    );
+}
+
+#[test]
+fn express_execute_fails_when_paused() {
+    let (env, client, _, _, _) = setup_env();
+    let (data, express_executor) = setup_express_transfer(&env, &client);
+
+    client.mock_all_auths().pause();
+
+    assert_contract_err!(
+        client.mock_all_auths().try_express_execute(
+            &express_executor,
+            &data.source_chain,
+            &data.message_id,
+            &data.source_address,
+            &data.payload,
+        ),
+        ContractError::ContractPaused
+    );
+}
+
+#[test]
+fn express_execute_fails_when_token_paused() {
+    let (env, client, _, _, _) = setup_env();
+    let (data, express_executor) = setup_express_transfer(&env, &client);
+
+    client.mock_all_auths().pause_token(&data.token_id);
+
+    assert_contract_err!(
+        client.mock_all_auths().try_express_execute(
+            &express_executor,
+            &data.source_chain,
+            &data.message_id,
+            &data.source_address,
+            &data.payload,
+        ),
+        ContractError::TokenPaused
+    );
+}
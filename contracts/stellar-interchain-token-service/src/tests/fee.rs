@@ -0,0 +1,245 @@
+use stellar_axelar_gas_service::testutils::setup_gas_token;
+use stellar_axelar_std::testutils::Address as _;
+use stellar_axelar_std::token::TokenClient;
+use stellar_axelar_std::traits::BytesExt;
+use stellar_axelar_std::{
+    assert_auth, assert_auth_err, assert_contract_err, events, Address, Bytes, BytesN, Env, String,
+};
+
+use super::utils::{interchain_transfer, setup_env, DESTINATION_CHAIN};
+use crate::error::ContractError;
+use crate::event::{
+    DefaultFeeRateSetEvent, FeeRateSetEvent, FeeReceiverSetEvent, InterchainTransferSentEvent,
+};
+use crate::testutils::setup_its_token;
+use crate::InterchainTokenServiceClient;
+
+fn setup_sender(
+    env: &Env,
+    client: &InterchainTokenServiceClient,
+    amount: i128,
+) -> (Address, BytesN<32>) {
+    let sender = Address::generate(env);
+    let (token_id, _) = setup_its_token(env, client, &sender, amount);
+
+    client
+        .mock_all_auths()
+        .set_trusted_chain(&String::from_str(env, DESTINATION_CHAIN));
+
+    (sender, token_id)
+}
+
+#[test]
+fn set_fee_receiver_succeeds() {
+    let (env, client, _, _, _) = setup_env();
+    let receiver = Address::generate(&env);
+
+    assert_eq!(client.fee_receiver(), None);
+
+    assert_auth!(
+        client.owner(),
+        client.set_fee_receiver(&Some(receiver.clone()))
+    );
+
+    goldie::assert!(events::fmt_last_emitted_event::<FeeReceiverSetEvent>(&env));
+
+    assert_eq!(client.fee_receiver(), Some(receiver));
+
+    client.mock_all_auths().set_fee_receiver(&None);
+
+    assert_eq!(client.fee_receiver(), None);
+}
+
+#[test]
+fn set_default_fee_rate_succeeds() {
+    let (env, client, _, _, _) = setup_env();
+
+    assert_eq!(client.default_fee_rate(), 0);
+
+    assert_auth!(client.owner(), client.set_default_fee_rate(&30_u32));
+
+    goldie::assert!(events::fmt_last_emitted_event::<DefaultFeeRateSetEvent>(
+        &env
+    ));
+
+    assert_eq!(client.default_fee_rate(), 30);
+}
+
+#[test]
+fn set_fee_rate_succeeds() {
+    let (env, client, _, _, _) = setup_env();
+    let token_id = BytesN::<32>::from_array(&env, &[1; 32]);
+
+    assert_eq!(client.fee_rate(&token_id), None);
+
+    assert_auth!(
+        client.owner(),
+        client.set_fee_rate(&token_id, &Some(50_u32))
+    );
+
+    goldie::assert!(events::fmt_last_emitted_event::<FeeRateSetEvent>(&env));
+
+    assert_eq!(client.fee_rate(&token_id), Some(50));
+
+    client.mock_all_auths().set_fee_rate(&token_id, &None);
+
+    assert_eq!(client.fee_rate(&token_id), None);
+}
+
+#[test]
+fn set_fee_config_fails_with_invalid_auth() {
+    let (env, client, _, _, _) = setup_env();
+    let user = Address::generate(&env);
+    let token_id = BytesN::<32>::from_array(&env, &[1; 32]);
+
+    assert_auth_err!(user, client.set_fee_receiver(&Some(user.clone())));
+    assert_auth_err!(user, client.set_default_fee_rate(&30_u32));
+    assert_auth_err!(user, client.set_fee_rate(&token_id, &Some(30_u32)));
+}
+
+#[test]
+fn set_fee_rate_fails_with_invalid_rate() {
+    let (env, client, _, _, _) = setup_env();
+    let token_id = BytesN::<32>::from_array(&env, &[1; 32]);
+
+    assert_contract_err!(
+        client.mock_all_auths().try_set_default_fee_rate(&10_001),
+        ContractError::InvalidFeeRate
+    );
+    assert_contract_err!(
+        client
+            .mock_all_auths()
+            .try_set_fee_rate(&token_id, &Some(10_001)),
+        ContractError::InvalidFeeRate
+    );
+}
+
+#[test]
+fn interchain_transfer_charges_default_fee() {
+    let (env, client, _, _, _) = setup_env();
+    let amount = 1000;
+    let (sender, token_id) = setup_sender(&env, &client, amount);
+    let receiver = Address::generate(&env);
+
+    client
+        .mock_all_auths()
+        .set_fee_receiver(&Some(receiver.clone()));
+    client.mock_all_auths().set_default_fee_rate(&100);
+
+    interchain_transfer(&env, &client, &sender, &token_id, amount);
+
+    goldie::assert!(events::fmt_emitted_event_at_idx::<
+        InterchainTransferSentEvent,
+    >(&env, -4));
+
+    let token = TokenClient::new(&env, &client.registered_token_address(&token_id));
+    assert_eq!(token.balance(&sender), 0);
+    assert_eq!(token.balance(&receiver), 10);
+}
+
+#[test]
+fn interchain_transfer_charges_token_fee_over_default_fee() {
+    let (env, client, _, _, _) = setup_env();
+    let amount = 1000;
+    let (sender, token_id) = setup_sender(&env, &client, amount);
+    let receiver = Address::generate(&env);
+
+    client
+        .mock_all_auths()
+        .set_fee_receiver(&Some(receiver.clone()));
+    client.mock_all_auths().set_default_fee_rate(&100);
+    client.mock_all_auths().set_fee_rate(&token_id, &Some(250));
+
+    interchain_transfer(&env, &client, &sender, &token_id, amount);
+
+    let token = TokenClient::new(&env, &client.registered_token_address(&token_id));
+    assert_eq!(token.balance(&receiver), 25);
+}
+
+#[test]
+fn interchain_transfer_charges_no_fee_without_fee_receiver() {
+    let (env, client, _, _, _) = setup_env();
+    let amount = 1000;
+    let (sender, token_id) = setup_sender(&env, &client, amount);
+
+    client.mock_all_auths().set_default_fee_rate(&100);
+
+    interchain_transfer(&env, &client, &sender, &token_id, amount);
+
+    let event = events::fmt_emitted_event_at_idx::<InterchainTransferSentEvent>(&env, -4);
+    assert!(event.contains("fee: 0"));
+}
+
+#[test]
+fn interchain_transfer_rounds_fee_down() {
+    let (env, client, _, _, _) = setup_env();
+    let amount = 99;
+    let (sender, token_id) = setup_sender(&env, &client, amount);
+    let receiver = Address::generate(&env);
+
+    client
+        .mock_all_auths()
+        .set_fee_receiver(&Some(receiver.clone()));
+    client.mock_all_auths().set_default_fee_rate(&100);
+
+    interchain_transfer(&env, &client, &sender, &token_id, amount);
+
+    let token = TokenClient::new(&env, &client.registered_token_address(&token_id));
+    assert_eq!(token.balance(&receiver), 0);
+}
+
+#[test]
+fn interchain_transfer_rounds_fee_up_to_remote_decimals() {
+    let (env, client, _, _, _) = setup_env();
+    let amount = 1000;
+    let (sender, token_id) = setup_sender(&env, &client, amount);
+    let receiver = Address::generate(&env);
+    let destination_chain = String::from_str(&env, DESTINATION_CHAIN);
+
+    client
+        .mock_all_auths()
+        .set_fee_receiver(&Some(receiver.clone()));
+    client.mock_all_auths().set_default_fee_rate(&30);
+    client
+        .mock_all_auths()
+        .set_remote_token_decimals(&sender, &token_id, &destination_chain, &17);
+    client
+        .mock_all_auths()
+        .set_decimal_scaling(&sender, &token_id, &true);
+
+    interchain_transfer(&env, &client, &sender, &token_id, amount);
+
+    let event = events::fmt_emitted_event_at_idx::<InterchainTransferSentEvent>(&env, -4);
+    assert!(event.contains("amount: 990"));
+    assert!(event.contains("fee: 10"));
+
+    let token = TokenClient::new(&env, &client.registered_token_address(&token_id));
+    assert_eq!(token.balance(&receiver), 10);
+}
+
+#[test]
+fn interchain_transfer_fails_if_fee_consumes_amount() {
+    let (env, client, _, _, _) = setup_env();
+    let amount = 1000;
+    let (sender, token_id) = setup_sender(&env, &client, amount);
+
+    client
+        .mock_all_auths()
+        .set_fee_receiver(&Some(Address::generate(&env)));
+    client
+        .mock_all_auths()
+        .set_fee_rate(&token_id, &Some(10_000));
+
+    assert_contract_err!(
+        client.mock_all_auths().try_interchain_transfer(
+            &sender,
+            &token_id,
+            &String::from_str(&env, DESTINATION_CHAIN),
+            &Bytes::from_hex(&env, "4F4495243837681061C4743b74B3eEdf548D56A5"),
+            &amount,
+            &None,
+            &Some(setup_gas_token(&env, &sender)),
+        ),
+        ContractError::InvalidAmount
+    );
+}
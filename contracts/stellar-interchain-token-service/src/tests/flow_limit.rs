@@ -14,7 +14,7 @@ use stellar_interchain_token::InterchainTokenClient;
 
 use super::utils::setup_env;
 use crate::error::ContractError;
-use crate::event::FlowLimitSetEvent;
+use crate::event::{ChainFlowLimitSetEvent, FlowLimitSetEvent};
 use crate::testutils::setup_its_token;
 use crate::types::{HubMessage, InterchainTransfer, Message};
 use crate::InterchainTokenServiceClient;
@@ -90,6 +90,25 @@ fn approve_its_transfer(
     gateway: &GatewayConfig,
     token_id: &BytesN<32>,
     amount: i128,
+) -> ApprovedMessage {
+    approve_its_transfer_from(
+        env,
+        client,
+        gateway,
+        token_id,
+        amount,
+        client.its_hub_chain_name(),
+    )
+}
+
+/// Approves an inbound transfer originating from the `original_source_chain`, routed through the ITS Hub.
+fn approve_its_transfer_from(
+    env: &Env,
+    client: &InterchainTokenServiceClient,
+    gateway: &GatewayConfig,
+    token_id: &BytesN<32>,
+    amount: i128,
+    original_source_chain: String,
 ) -> ApprovedMessage {
     let sender = Address::generate(env).to_string_bytes();
     let recipient = Address::generate(env).to_string_bytes();
@@ -97,7 +116,7 @@ fn approve_its_transfer(
     let source_address = client.its_hub_address();
 
     let msg = HubMessage::ReceiveFromHub {
-        source_chain: source_chain.clone(),
+        source_chain: original_source_chain,
         message: Message::InterchainTransfer(InterchainTransfer {
             token_id: token_id.clone(),
             source_address: sender,
@@ -293,6 +312,211 @@ fn set_flow_limit_to_none_succeeds() {
     assert_eq!(client.flow_limit(&token.id), None);
 }
 
+#[test]
+fn set_chain_flow_limit_succeeds() {
+    let (env, client, _, _, _) = setup_env();
+    let token_id = BytesN::from_array(&env, &[1; 32]);
+    let chain = String::from_str(&env, "ethereum");
+
+    assert_eq!(client.chain_flow_limit(&token_id, &chain), None);
+
+    assert_auth!(
+        client.operator(),
+        client.set_chain_flow_limit(&token_id, &chain, &Some(dummy_flow_limit()))
+    );
+    goldie::assert!(events::fmt_last_emitted_event::<ChainFlowLimitSetEvent>(
+        &env
+    ));
+
+    assert_eq!(
+        client.chain_flow_limit(&token_id, &chain),
+        Some(dummy_flow_limit())
+    );
+    assert_eq!(client.flow_limit(&token_id), None);
+}
+
+#[test]
+fn set_chain_flow_limit_to_none_succeeds() {
+    let (env, client, _, token) = setup();
+    let chain = String::from_str(&env, "ethereum");
+
+    client
+        .mock_all_auths()
+        .set_chain_flow_limit(&token.id, &chain, &Some(dummy_flow_limit()));
+
+    assert_auth!(
+        client.operator(),
+        client.set_chain_flow_limit(&token.id, &chain, &None::<i128>)
+    );
+
+    assert_eq!(client.chain_flow_limit(&token.id, &chain), None);
+}
+
+#[test]
+fn set_chain_flow_limit_fails_on_negative_limit() {
+    let (env, client, _, _, _) = setup_env();
+    let token_id = BytesN::from_array(&env, &[1; 32]);
+    let chain = String::from_str(&env, "ethereum");
+
+    assert_contract_err!(
+        client
+            .mock_all_auths()
+            .try_set_chain_flow_limit(&token_id, &chain, &Some(-1)),
+        ContractError::InvalidFlowLimit
+    );
+}
+
+#[test]
+fn chain_flow_limit_is_enforced_in_addition_to_flow_limit() {
+    let (env, client, _, token) = setup();
+    let (destination_chain, destination_address, data) = dummy_transfer_params(&env);
+    let other_chain = String::from_str(&env, "avalanche");
+    let chain_flow_limit = dummy_flow_limit() / 10;
+
+    client.mock_all_auths().set_chain_flow_limit(
+        &token.id,
+        &destination_chain,
+        &Some(chain_flow_limit),
+    );
+
+    for chain in [&destination_chain, &other_chain] {
+        client.mock_all_auths().set_trusted_chain(chain);
+    }
+
+    let token_client =
+        InterchainTokenClient::new(&env, &client.registered_token_address(&token.id));
+    token_client
+        .mock_all_auths()
+        .mint(&token.deployer, &dummy_flow_limit());
+
+    client.mock_all_auths().interchain_transfer(
+        &token.deployer,
+        &token.id,
+        &destination_chain,
+        &destination_address,
+        &chain_flow_limit,
+        &data,
+        &None,
+    );
+
+    assert_eq!(
+        client.flow_out_amount_for_chain(&token.id, &destination_chain),
+        chain_flow_limit
+    );
+    assert_eq!(client.flow_out_amount(&token.id), chain_flow_limit);
+
+    assert_contract_err!(
+        client.mock_all_auths().try_interchain_transfer(
+            &token.deployer,
+            &token.id,
+            &destination_chain,
+            &destination_address,
+            &1,
+            &data,
+            &None,
+        ),
+        ContractError::FlowLimitExceeded
+    );
+
+    // Transfers to other chains are only subject to the global flow limit
+    client.mock_all_auths().interchain_transfer(
+        &token.deployer,
+        &token.id,
+        &other_chain,
+        &destination_address,
+        &(dummy_flow_limit() - chain_flow_limit),
+        &data,
+        &None,
+    );
+
+    assert_eq!(client.flow_out_amount_for_chain(&token.id, &other_chain), 0);
+    assert_eq!(client.flow_out_amount(&token.id), dummy_flow_limit());
+}
+
+#[test]
+fn chain_flow_limit_tracks_inbound_flow_per_source_chain() {
+    let (env, client, gateway, token) = setup();
+    let source_chain = client.its_hub_chain_name();
+    let amount = dummy_flow_limit() / 10;
+
+    client
+        .mock_all_auths()
+        .set_chain_flow_limit(&token.id, &source_chain, &Some(amount));
+
+    execute_its_transfer(&env, &client, &gateway, &token.id, amount);
+
+    assert_eq!(
+        client.flow_in_amount_for_chain(&token.id, &source_chain),
+        amount
+    );
+
+    let msg = approve_its_transfer(&env, &client, &gateway, &token.id, 1);
+    assert_contract_err!(
+        client.try_execute(
+            &msg.source_chain,
+            &msg.message_id,
+            &msg.source_address,
+            &msg.payload,
+        ),
+        ContractError::FlowLimitExceeded
+    );
+
+    env.ledger()
+        .set_timestamp(env.ledger().timestamp() + EPOCH_TIME);
+
+    assert_eq!(client.flow_in_amount_for_chain(&token.id, &source_chain), 0);
+}
+
+#[test]
+fn chain_flow_limit_keeps_inbound_flows_of_source_chains_separate() {
+    let (env, client, gateway, token) = setup();
+    let source_chain = client.its_hub_chain_name();
+    let other_source_chain = String::from_str(&env, "ethereum");
+    let amount = dummy_flow_limit() / 10;
+
+    client
+        .mock_all_auths()
+        .set_trusted_chain(&other_source_chain);
+    for chain in [&source_chain, &other_source_chain] {
+        client
+            .mock_all_auths()
+            .set_chain_flow_limit(&token.id, chain, &Some(amount));
+    }
+
+    execute_its_transfer(&env, &client, &gateway, &token.id, amount);
+
+    assert_eq!(
+        client.flow_in_amount_for_chain(&token.id, &other_source_chain),
+        0
+    );
+
+    // The inbound flow from the other source chain isn't limited by the flow already received from the first one
+    let msg = approve_its_transfer_from(
+        &env,
+        &client,
+        &gateway,
+        &token.id,
+        amount,
+        other_source_chain.clone(),
+    );
+    client.execute(
+        &msg.source_chain,
+        &msg.message_id,
+        &msg.source_address,
+        &msg.payload,
+    );
+
+    assert_eq!(
+        client.flow_in_amount_for_chain(&token.id, &source_chain),
+        amount
+    );
+    assert_eq!(
+        client.flow_in_amount_for_chain(&token.id, &other_source_chain),
+        amount
+    );
+    assert_eq!(client.flow_in_amount(&token.id), amount * 2);
+}
+
 #[test]
 fn set_flow_limit_fails_on_negative_limit() {
     let (env, client, _, _, _) = setup_env();
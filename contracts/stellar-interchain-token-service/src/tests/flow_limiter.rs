@@ -0,0 +1,194 @@
+use stellar_axelar_std::testutils::Address as _;
+use stellar_axelar_std::{assert_auth, assert_contract_err, events, vec, Address, BytesN};
+
+use super::utils::setup_env;
+use crate::error::ContractError;
+use crate::event::{FlowLimitSetEvent, FlowLimiterAddedEvent, FlowLimiterRemovedEvent};
+use crate::testutils::setup_its_token;
+
+#[test]
+fn token_deployer_is_recorded_on_deployment() {
+    let (env, client, _, _, _) = setup_env();
+    let deployer = Address::generate(&env);
+    let (token_id, _) = setup_its_token(&env, &client, &deployer, 0);
+
+    assert_eq!(client.token_deployer(&token_id), Some(deployer));
+}
+
+#[test]
+fn add_flow_limiter_by_token_deployer_succeeds() {
+    let (env, client, _, _, _) = setup_env();
+    let deployer = Address::generate(&env);
+    let flow_limiter = Address::generate(&env);
+    let (token_id, _) = setup_its_token(&env, &client, &deployer, 0);
+
+    assert!(!client.is_flow_limiter(&token_id, &flow_limiter));
+
+    assert_auth!(
+        deployer,
+        client.add_flow_limiter(&deployer, &token_id, &flow_limiter)
+    );
+    goldie::assert!(events::fmt_last_emitted_event::<FlowLimiterAddedEvent>(
+        &env
+    ));
+
+    assert!(client.is_flow_limiter(&token_id, &flow_limiter));
+}
+
+#[test]
+fn add_flow_limiter_by_operator_succeeds() {
+    let (env, client, _, _, _) = setup_env();
+    let operator = client.operator();
+    let flow_limiter = Address::generate(&env);
+    let token_id = BytesN::from_array(&env, &[1; 32]);
+
+    assert_auth!(
+        operator,
+        client.add_flow_limiter(&operator, &token_id, &flow_limiter)
+    );
+
+    assert!(client.is_flow_limiter(&token_id, &flow_limiter));
+}
+
+#[test]
+fn add_flow_limiter_fails_if_not_operator_or_token_deployer() {
+    let (env, client, _, _, _) = setup_env();
+    let deployer = Address::generate(&env);
+    let caller = Address::generate(&env);
+    let (token_id, _) = setup_its_token(&env, &client, &deployer, 0);
+
+    assert_contract_err!(
+        client
+            .mock_all_auths()
+            .try_add_flow_limiter(&caller, &token_id, &caller),
+        ContractError::NotOperatorOrTokenDeployer
+    );
+}
+
+#[test]
+fn add_flow_limiter_fails_if_already_added() {
+    let (env, client, _, _, _) = setup_env();
+    let deployer = Address::generate(&env);
+    let flow_limiter = Address::generate(&env);
+    let (token_id, _) = setup_its_token(&env, &client, &deployer, 0);
+
+    client
+        .mock_all_auths()
+        .add_flow_limiter(&deployer, &token_id, &flow_limiter);
+
+    assert_contract_err!(
+        client
+            .mock_all_auths()
+            .try_add_flow_limiter(&deployer, &token_id, &flow_limiter),
+        ContractError::FlowLimiterAlreadyAdded
+    );
+}
+
+#[test]
+fn remove_flow_limiter_succeeds() {
+    let (env, client, _, _, _) = setup_env();
+    let deployer = Address::generate(&env);
+    let flow_limiter = Address::generate(&env);
+    let (token_id, _) = setup_its_token(&env, &client, &deployer, 0);
+
+    client
+        .mock_all_auths()
+        .add_flow_limiter(&deployer, &token_id, &flow_limiter);
+
+    assert_auth!(
+        deployer,
+        client.remove_flow_limiter(&deployer, &token_id, &flow_limiter)
+    );
+    goldie::assert!(events::fmt_last_emitted_event::<FlowLimiterRemovedEvent>(
+        &env
+    ));
+
+    assert!(!client.is_flow_limiter(&token_id, &flow_limiter));
+}
+
+#[test]
+fn remove_flow_limiter_fails_if_not_flow_limiter() {
+    let (env, client, _, _, _) = setup_env();
+    let deployer = Address::generate(&env);
+    let flow_limiter = Address::generate(&env);
+    let (token_id, _) = setup_its_token(&env, &client, &deployer, 0);
+
+    assert_contract_err!(
+        client
+            .mock_all_auths()
+            .try_remove_flow_limiter(&deployer, &token_id, &flow_limiter),
+        ContractError::NotFlowLimiter
+    );
+}
+
+#[test]
+fn set_flow_limits_by_flow_limiter_succeeds() {
+    let (env, client, _, _, _) = setup_env();
+    let deployer = Address::generate(&env);
+    let flow_limiter = Address::generate(&env);
+    let (token_id, _) = setup_its_token(&env, &client, &deployer, 0);
+    let flow_limit = Some(1000i128);
+
+    client
+        .mock_all_auths()
+        .add_flow_limiter(&deployer, &token_id, &flow_limiter);
+
+    let flow_limits = vec![&env, (token_id.clone(), flow_limit)];
+
+    assert_auth!(
+        flow_limiter,
+        client.set_flow_limits(&flow_limiter, &flow_limits)
+    );
+    goldie::assert!(events::fmt_last_emitted_event::<FlowLimitSetEvent>(&env));
+
+    assert_eq!(client.flow_limit(&token_id), flow_limit);
+}
+
+#[test]
+fn set_flow_limits_by_operator_succeeds() {
+    let (env, client, _, _, _) = setup_env();
+    let operator = client.operator();
+    let token_ids = [
+        BytesN::from_array(&env, &[1; 32]),
+        BytesN::from_array(&env, &[2; 32]),
+    ];
+
+    let flow_limits = vec![
+        &env,
+        (token_ids[0].clone(), Some(1000i128)),
+        (token_ids[1].clone(), None),
+    ];
+
+    assert_auth!(operator, client.set_flow_limits(&operator, &flow_limits));
+
+    assert_eq!(client.flow_limit(&token_ids[0]), Some(1000));
+    assert_eq!(client.flow_limit(&token_ids[1]), None);
+}
+
+#[test]
+fn set_flow_limits_fails_if_not_flow_limiter_of_all_tokens() {
+    let (env, client, _, _, _) = setup_env();
+    let deployer = Address::generate(&env);
+    let flow_limiter = Address::generate(&env);
+    let (token_id, _) = setup_its_token(&env, &client, &deployer, 0);
+    let other_token_id = BytesN::from_array(&env, &[2; 32]);
+
+    client
+        .mock_all_auths()
+        .add_flow_limiter(&deployer, &token_id, &flow_limiter);
+
+    let flow_limits = vec![
+        &env,
+        (token_id.clone(), Some(1000i128)),
+        (other_token_id, Some(1000i128)),
+    ];
+
+    assert_contract_err!(
+        client
+            .mock_all_auths()
+            .try_set_flow_limits(&flow_limiter, &flow_limits),
+        ContractError::NotFlowLimiter
+    );
+
+    assert_eq!(client.flow_limit(&token_id), None);
+}
@@ -0,0 +1,94 @@
+use soroban_token_sdk::metadata::TokenMetadata;
+use stellar_axelar_std::testutils::Address as _;
+use stellar_axelar_std::{
+    assert_auth, assert_auth_err, assert_contract_err, events, Address, BytesN, String,
+};
+
+use super::utils::setup_env;
+use crate::error::ContractError;
+use crate::event::InterchainTokenFactorySetEvent;
+
+#[test]
+fn set_interchain_token_factory_succeeds() {
+    let (env, client, _, _, _) = setup_env();
+    let factory = Address::generate(&env);
+
+    assert_eq!(client.interchain_token_factory(), None);
+
+    assert_auth!(
+        client.owner(),
+        client.set_interchain_token_factory(&factory)
+    );
+
+    goldie::assert!(events::fmt_last_emitted_event::<
+        InterchainTokenFactorySetEvent,
+    >(&env));
+
+    assert_eq!(client.interchain_token_factory(), Some(factory));
+}
+
+#[test]
+fn set_interchain_token_factory_fails_if_not_owner() {
+    let (env, client, _, _, _) = setup_env();
+
+    assert_auth_err!(
+        Address::generate(&env),
+        client.set_interchain_token_factory(&Address::generate(&env))
+    );
+}
+
+#[test]
+fn set_interchain_token_factory_fails_if_already_set() {
+    let (env, client, _, _, _) = setup_env();
+
+    client
+        .mock_all_auths()
+        .set_interchain_token_factory(&Address::generate(&env));
+
+    assert_contract_err!(
+        client
+            .mock_all_auths()
+            .try_set_interchain_token_factory(&Address::generate(&env)),
+        ContractError::InterchainTokenFactoryAlreadySet
+    );
+}
+
+#[test]
+fn interchain_token_id_of_factory_differs_from_other_deployers() {
+    let (env, client, _, _, _) = setup_env();
+    let factory = Address::generate(&env);
+    let salt = BytesN::<32>::from_array(&env, &[1; 32]);
+    let token_id = client.interchain_token_id(&factory, &salt);
+
+    client
+        .mock_all_auths()
+        .set_interchain_token_factory(&factory);
+
+    assert_ne!(client.interchain_token_id(&factory, &salt), token_id);
+}
+
+#[test]
+fn deploy_interchain_token_by_factory_uses_factory_token_id() {
+    let (env, client, _, _, _) = setup_env();
+    let factory = Address::generate(&env);
+    let salt = BytesN::<32>::from_array(&env, &[1; 32]);
+
+    client
+        .mock_all_auths()
+        .set_interchain_token_factory(&factory);
+
+    let token_id = client.mock_all_auths().deploy_interchain_token(
+        &factory,
+        &salt,
+        &TokenMetadata {
+            name: String::from_str(&env, "Test"),
+            symbol: String::from_str(&env, "TEST"),
+            decimal: 18,
+        },
+        &1,
+        &None,
+    );
+
+    assert_eq!(token_id, client.interchain_token_id(&factory, &salt));
+    assert_eq!(client.token_deployer(&token_id), Some(factory));
+}
@@ -1,4 +1,5 @@
 use soroban_token_sdk::metadata::TokenMetadata;
+use stellar_axelar_gas_service::event::GasPaidEvent;
 use stellar_axelar_gas_service::testutils::setup_gas_token;
 use stellar_axelar_std::testutils::Address as _;
 use stellar_axelar_std::token::{StellarAssetClient, TokenClient};
@@ -11,7 +12,7 @@ use crate::error::ContractError;
 use crate::event::InterchainTransferSentEvent;
 use crate::tests::utils::TokenMetadataExt;
 use crate::testutils::setup_its_token;
-use crate::types::TokenManagerType;
+use crate::types::{GasPayment, TokenManagerType};
 use crate::InterchainTokenServiceClient;
 
 fn dummy_transfer_params(env: &Env) -> (String, Bytes, Option<Bytes>) {
@@ -373,3 +374,112 @@ fn interchain_transfer_fails_with_invalid_token() {
         ContractError::InvalidTokenId
     );
 }
+
+#[test]
+fn interchain_transfer_with_gas_succeeds_with_sponsor() {
+    let (env, client, _, _, _) = setup_env();
+
+    let amount = 1000;
+    let sender = Address::generate(&env);
+    let (token_id, _) = setup_its_token(&env, &client, &sender, amount);
+    let sponsor = Address::generate(&env);
+    let gas_token = setup_gas_token(&env, &sponsor);
+    let (destination_chain, destination_address, data) = dummy_transfer_params(&env);
+
+    client
+        .mock_all_auths()
+        .set_trusted_chain(&destination_chain);
+
+    client.mock_all_auths().interchain_transfer_with_gas(
+        &sender,
+        &token_id,
+        &destination_chain,
+        &destination_address,
+        &amount,
+        &data,
+        &GasPayment {
+            spender: sponsor.clone(),
+            token: gas_token.clone(),
+            metadata: Bytes::from_hex(&env, "1234"),
+            refund_address: sponsor.clone(),
+        },
+    );
+
+    let authorizers: std::vec::Vec<Address> = env
+        .auths()
+        .into_iter()
+        .map(|(address, _)| address)
+        .collect();
+    assert_eq!(authorizers.len(), 2);
+    assert!(authorizers.contains(&sender));
+    assert!(authorizers.contains(&sponsor));
+
+    goldie::assert!(events::fmt_emitted_event_at_idx::<GasPaidEvent>(&env, -2));
+
+    assert_eq!(
+        TokenClient::new(&env, &gas_token.address).balance(&sponsor),
+        0
+    );
+}
+
+#[test]
+fn interchain_transfer_with_gas_succeeds_with_refund_address() {
+    let (env, client, _, _, _) = setup_env();
+
+    let amount = 1000;
+    let (sender, _, token_id) = setup_sender(&env, &client, amount);
+    let sponsor = Address::generate(&env);
+    let gas_token = setup_gas_token(&env, &sponsor);
+    let (destination_chain, destination_address, data) = dummy_transfer_params(&env);
+
+    client
+        .mock_all_auths()
+        .set_trusted_chain(&destination_chain);
+
+    client.mock_all_auths().interchain_transfer_with_gas(
+        &sender,
+        &token_id,
+        &destination_chain,
+        &destination_address,
+        &amount,
+        &data,
+        &GasPayment {
+            spender: sponsor,
+            token: gas_token,
+            metadata: Bytes::new(&env),
+            refund_address: sender.clone(),
+        },
+    );
+
+    goldie::assert!(events::fmt_emitted_event_at_idx::<GasPaidEvent>(&env, -2));
+}
+
+#[test]
+fn interchain_transfer_with_gas_fails_with_zero_amount() {
+    let (env, client, _, _, _) = setup_env();
+
+    let (sender, gas_token, token_id) = setup_sender(&env, &client, 1000);
+    let (destination_chain, destination_address, data) = dummy_transfer_params(&env);
+
+    client
+        .mock_all_auths()
+        .set_trusted_chain(&destination_chain);
+
+    assert_contract_err!(
+        client.mock_all_auths().try_interchain_transfer_with_gas(
+            &sender,
+            &token_id,
+            &destination_chain,
+            &destination_address,
+            &0,
+            &data,
+            &GasPayment {
+                spender: sender.clone(),
+                token: gas_token,
+                metadata: Bytes::new(&env),
+                refund_address: sender.clone(),
+            },
+        ),
+        ContractError::InvalidAmount
+    );
+}
@@ -99,6 +99,7 @@ fn link_token_succeeds_with_token_manager_type_lock_unlock() {
         &destination_token_address,
         &token_manager_type,
         &None::<Bytes>,
+        &None,
         &Some(gas_token.clone()),
     );
 
@@ -127,6 +128,7 @@ fn link_token_succeeds_with_token_manager_type_lock_unlock() {
             its_hub_address,
             payload,
             deployer.clone(),
+            deployer.clone(),
             gas_token.clone(),
             Bytes::new(&env)
         ),
@@ -142,6 +144,7 @@ fn link_token_succeeds_with_token_manager_type_lock_unlock() {
             destination_token_address,
             token_manager_type,
             None::<Bytes>,
+            None::<u32>,
             Some(gas_token)
         ),
         gas_service_auth
@@ -240,6 +243,7 @@ fn link_token_succeeds_with_token_manager_type_mint_burn() {
         &destination_token_address,
         &token_manager_type,
         &None::<Bytes>,
+        &None,
         &Some(gas_token.clone()),
     );
 
@@ -268,6 +272,7 @@ fn link_token_succeeds_with_token_manager_type_mint_burn() {
             its_hub_address,
             payload,
             deployer.clone(),
+            deployer.clone(),
             gas_token.clone(),
             Bytes::new(&env)
         ),
@@ -283,6 +288,7 @@ fn link_token_succeeds_with_token_manager_type_mint_burn() {
             destination_token_address,
             token_manager_type,
             None::<Bytes>,
+            None::<u32>,
             Some(gas_token)
         ),
         gas_service_auth
@@ -388,6 +394,7 @@ fn link_token_succeeds_with_token_manager_type_mint_burn_from() {
         &destination_token_address,
         &token_manager_type,
         &None::<Bytes>,
+        &None,
         &Some(gas_token.clone()),
     );
 
@@ -416,6 +423,7 @@ fn link_token_succeeds_with_token_manager_type_mint_burn_from() {
             its_hub_address,
             payload,
             deployer.clone(),
+            deployer.clone(),
             gas_token.clone(),
             Bytes::new(&env)
         ),
@@ -431,6 +439,7 @@ fn link_token_succeeds_with_token_manager_type_mint_burn_from() {
             destination_token_address,
             token_manager_type,
             None::<Bytes>,
+            None::<u32>,
             Some(gas_token)
         ),
         gas_service_auth
@@ -482,6 +491,7 @@ fn link_token_succeeds_without_gas_token() {
         &destination_token_address,
         &token_manager_type,
         &link_params,
+        &None,
         &gas_token,
     );
 
@@ -501,6 +511,7 @@ fn link_token_succeeds_without_gas_token() {
             destination_token_address,
             token_manager_type,
             link_params,
+            None::<u32>,
             gas_token
         )
     );
@@ -508,6 +519,82 @@ fn link_token_succeeds_without_gas_token() {
     assert_eq!(env.auths(), link_token_auth);
 }
 
+#[test]
+fn link_token_records_destination_token_decimals() {
+    let (env, client, _, _, _) = setup_env();
+    let LinkTokenTestData {
+        deployer,
+        token,
+        salt,
+        destination_chain,
+        destination_token_address,
+    } = setup_link_token_test_data(&env);
+    let token_manager_type = TokenManagerType::LockUnlock;
+
+    client.mock_all_auths().register_custom_token(
+        &deployer,
+        &salt,
+        &token.address(),
+        &token_manager_type,
+    );
+    client
+        .mock_all_auths()
+        .set_trusted_chain(&destination_chain);
+
+    let token_id = client.mock_all_auths().link_token(
+        &deployer,
+        &salt,
+        &destination_chain,
+        &destination_token_address,
+        &token_manager_type,
+        &None,
+        &Some(18),
+        &None,
+    );
+
+    assert_eq!(
+        client.remote_token_decimals(&token_id, &destination_chain),
+        Some(18)
+    );
+}
+
+#[test]
+fn link_token_fails_with_invalid_destination_token_decimals() {
+    let (env, client, _, _, _) = setup_env();
+    let LinkTokenTestData {
+        deployer,
+        token,
+        salt,
+        destination_chain,
+        destination_token_address,
+    } = setup_link_token_test_data(&env);
+    let token_manager_type = TokenManagerType::LockUnlock;
+
+    client.mock_all_auths().register_custom_token(
+        &deployer,
+        &salt,
+        &token.address(),
+        &token_manager_type,
+    );
+    client
+        .mock_all_auths()
+        .set_trusted_chain(&destination_chain);
+
+    assert_contract_err!(
+        client.mock_all_auths().try_link_token(
+            &deployer,
+            &salt,
+            &destination_chain,
+            &destination_token_address,
+            &token_manager_type,
+            &None,
+            &Some(u8::MAX as u32 + 1),
+            &None,
+        ),
+        ContractError::InvalidTokenDecimals
+    );
+}
+
 #[test]
 fn link_token_fails_when_paused() {
     let (env, client, _, _, _) = setup_env();
@@ -533,6 +620,7 @@ fn link_token_fails_when_paused() {
             &destination_token_address,
             &token_manager_type,
             &link_params,
+            &None,
             &gas_token
         ),
         ContractError::ContractPaused
@@ -562,6 +650,7 @@ fn link_token_fails_with_native_interchain_token_type() {
             &destination_token_address,
             &token_manager_type,
             &link_params,
+            &None,
             &gas_token
         ),
         ContractError::InvalidTokenManagerType
@@ -600,6 +689,7 @@ fn link_token_fails_with_invalid_destination_chain() {
             &destination_token_address,
             &token_manager_type,
             &link_params,
+            &None,
             &gas_token
         ),
         ContractError::InvalidDestinationChain
@@ -629,6 +719,7 @@ fn link_token_fails_with_invalid_token_id() {
             &destination_token_address,
             &token_manager_type,
             &link_params,
+            &None,
             &gas_token
         ),
         ContractError::InvalidTokenId
@@ -665,6 +756,7 @@ fn link_token_fails_with_untrusted_chain() {
             &destination_token_address,
             &token_manager_type,
             &link_params,
+            &None,
             &gas_token
         ),
         ContractError::UntrustedChain
@@ -695,6 +787,7 @@ fn link_token_fails_with_empty_destination_token_address() {
             &Bytes::new(&env),
             &token_manager_type,
             &link_params,
+            &None,
             &gas_token
         ),
         ContractError::InvalidDestinationTokenAddress
@@ -0,0 +1,214 @@
+use stellar_axelar_gateway::testutils::{approve_gateway_messages, TestSignerSet};
+use stellar_axelar_gateway::types::Message as GatewayMessage;
+use stellar_axelar_gateway::AxelarGatewayClient;
+use stellar_axelar_std::address::AddressExt;
+use stellar_axelar_std::testutils::Address as _;
+use stellar_axelar_std::token::StellarAssetClient;
+use stellar_axelar_std::traits::BytesExt;
+use stellar_axelar_std::{assert_contract_err, vec, Address, Bytes, BytesN, Env, String};
+
+use super::utils::{interchain_transfer, setup_env, DESTINATION_CHAIN};
+use crate::error::ContractError;
+use crate::testutils::setup_its_token;
+use crate::types::{HubMessage, InterchainTransfer, LockedBalance, Message, TokenManagerType};
+use crate::InterchainTokenServiceClient;
+
+fn setup_lock_unlock_token(
+    env: &Env,
+    client: &InterchainTokenServiceClient,
+) -> (BytesN<32>, StellarAssetClient<'static>) {
+    let token_address = env
+        .register_stellar_asset_contract_v2(Address::generate(env))
+        .address();
+    let token_id = client.register_canonical_token(&token_address);
+
+    client
+        .mock_all_auths()
+        .set_trusted_chain(&String::from_str(env, DESTINATION_CHAIN));
+
+    (token_id, StellarAssetClient::new(env, &token_address))
+}
+
+/// Mints `amount` tokens to a new sender and transfers them interchain.
+fn mint_and_transfer(
+    env: &Env,
+    client: &InterchainTokenServiceClient,
+    token: &StellarAssetClient,
+    token_id: &BytesN<32>,
+    amount: i128,
+) {
+    let sender = Address::generate(env);
+    token.mock_all_auths().mint(&sender, &amount);
+
+    interchain_transfer(env, client, &sender, token_id, amount);
+}
+
+fn execute_interchain_transfer(
+    env: &Env,
+    client: &InterchainTokenServiceClient,
+    gateway_client: &AxelarGatewayClient,
+    signers: &TestSignerSet,
+    token_id: &BytesN<32>,
+    amount: i128,
+) {
+    let message_id = String::from_str(env, "test");
+    let payload = HubMessage::ReceiveFromHub {
+        source_chain: String::from_str(env, DESTINATION_CHAIN),
+        message: Message::InterchainTransfer(InterchainTransfer {
+            token_id: token_id.clone(),
+            source_address: Bytes::from_hex(env, "4F4495243837681061C4743b74B3eEdf548D56A5"),
+            destination_address: Address::generate(env).to_string_bytes(),
+            amount,
+            data: None,
+        }),
+    }
+    .abi_encode(env)
+    .unwrap();
+
+    approve_gateway_messages(
+        env,
+        gateway_client,
+        signers.clone(),
+        vec![
+            env,
+            GatewayMessage {
+                source_chain: client.its_hub_chain_name(),
+                message_id: message_id.clone(),
+                source_address: client.its_hub_address(),
+                contract_address: client.address.clone(),
+                payload_hash: env.crypto().keccak256(&payload).into(),
+            },
+        ],
+    );
+
+    client.execute(
+        &client.its_hub_chain_name(),
+        &message_id,
+        &client.its_hub_address(),
+        &payload,
+    );
+}
+
+#[test]
+fn interchain_transfer_increases_locked_amount() {
+    let (env, client, _, _, _) = setup_env();
+    let (token_id, token) = setup_lock_unlock_token(&env, &client);
+
+    assert_eq!(client.locked_amount(&token_id), 0);
+
+    mint_and_transfer(&env, &client, &token, &token_id, 1000);
+    mint_and_transfer(&env, &client, &token, &token_id, 500);
+
+    assert_eq!(client.locked_amount(&token_id), 1500);
+    assert_eq!(
+        client.locked_balance(&token_id),
+        LockedBalance {
+            locked_amount: 1500,
+            token_manager_balance: 1500,
+            surplus: 0,
+        }
+    );
+}
+
+#[test]
+fn interchain_transfer_execute_decreases_locked_amount() {
+    let (env, client, gateway_client, _, signers) = setup_env();
+    let (token_id, token) = setup_lock_unlock_token(&env, &client);
+
+    mint_and_transfer(&env, &client, &token, &token_id, 1000);
+    execute_interchain_transfer(&env, &client, &gateway_client, &signers, &token_id, 400);
+
+    assert_eq!(client.locked_amount(&token_id), 600);
+    assert_eq!(client.locked_balance(&token_id).token_manager_balance, 600);
+}
+
+#[test]
+fn locked_balance_reports_tokens_sent_to_token_manager_directly() {
+    let (env, client, _, _, _) = setup_env();
+    let (token_id, token) = setup_lock_unlock_token(&env, &client);
+
+    mint_and_transfer(&env, &client, &token, &token_id, 1000);
+    token
+        .mock_all_auths()
+        .mint(&client.deployed_token_manager(&token_id), &250);
+
+    assert_eq!(
+        client.locked_balance(&token_id),
+        LockedBalance {
+            locked_amount: 1000,
+            token_manager_balance: 1250,
+            surplus: 250,
+        }
+    );
+}
+
+#[test]
+fn locked_amount_does_not_drop_below_zero() {
+    let (env, client, gateway_client, _, signers) = setup_env();
+    let (token_id, token) = setup_lock_unlock_token(&env, &client);
+
+    mint_and_transfer(&env, &client, &token, &token_id, 100);
+    token
+        .mock_all_auths()
+        .mint(&client.deployed_token_manager(&token_id), &900);
+    execute_interchain_transfer(&env, &client, &gateway_client, &signers, &token_id, 400);
+
+    assert_eq!(
+        client.locked_balance(&token_id),
+        LockedBalance {
+            locked_amount: 0,
+            token_manager_balance: 600,
+            surplus: 600,
+        }
+    );
+}
+
+#[test]
+fn migrate_token_manager_type_clears_locked_amount() {
+    let (env, client, _, _, _) = setup_env();
+    let token_address = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    let token = StellarAssetClient::new(&env, &token_address);
+    let token_id = client.mock_all_auths().register_custom_token(
+        &Address::generate(&env),
+        &BytesN::<32>::from_array(&env, &[1; 32]),
+        &token_address,
+        &TokenManagerType::LockUnlock,
+    );
+
+    client
+        .mock_all_auths()
+        .set_trusted_chain(&String::from_str(&env, DESTINATION_CHAIN));
+    mint_and_transfer(&env, &client, &token, &token_id, 1000);
+    token
+        .mock_all_auths()
+        .set_admin(&client.deployed_token_manager(&token_id));
+
+    client
+        .mock_all_auths()
+        .migrate_token_manager_type(&token_id, &TokenManagerType::MintBurn);
+
+    assert_eq!(client.locked_amount(&token_id), 0);
+}
+
+#[test]
+fn locked_balance_fails_with_invalid_token_id() {
+    let (env, client, _, _, _) = setup_env();
+
+    assert_contract_err!(
+        client.try_locked_balance(&BytesN::from_array(&env, &[1; 32])),
+        ContractError::InvalidTokenId
+    );
+}
+
+#[test]
+fn locked_balance_fails_with_invalid_token_manager_type() {
+    let (env, client, _, _, _) = setup_env();
+    let (token_id, _) = setup_its_token(&env, &client, &Address::generate(&env), 100);
+
+    assert_contract_err!(
+        client.try_locked_balance(&token_id),
+        ContractError::InvalidTokenManagerType
+    );
+}
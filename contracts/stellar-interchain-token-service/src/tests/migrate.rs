@@ -0,0 +1,247 @@
+use stellar_axelar_std::testutils::Address as _;
+use stellar_axelar_std::token::StellarAssetClient;
+use stellar_axelar_std::{
+    assert_auth, assert_auth_err, assert_contract_err, vec, Address, BytesN, Env,
+};
+
+use super::utils::setup_env;
+use crate::error::ContractError;
+use crate::testutils::setup_its_token;
+use crate::InterchainTokenServiceClient;
+use crate::{registry, storage};
+use migrating::MigratingDataKey;
+
+mod migrating {
+    #![allow(non_camel_case_types)]
+
+    use stellar_axelar_std::{contracttype, soroban_sdk};
+
+    /// Mirrors the data key used by the upgradable interface to track an ongoing migration.
+    #[contracttype]
+    pub enum MigratingDataKey {
+        Interfaces_Migrating,
+    }
+}
+
+/// Puts the contract in the migrating state, as after an upgrade.
+fn start_migration(env: &Env, client: &InterchainTokenServiceClient) {
+    env.as_contract(&client.address, || {
+        env.storage()
+            .instance()
+            .set(&MigratingDataKey::Interfaces_Migrating, &());
+    });
+}
+
+/// Completes the migration without backfilling further tokens.
+fn complete_migration(env: &Env, client: &InterchainTokenServiceClient) {
+    client.mock_all_auths().migrate(&vec![env]);
+}
+
+/// Removes the token registry entries, to simulate tokens registered before the upgrade.
+fn clear_registry(env: &Env, client: &InterchainTokenServiceClient, token_ids: &[BytesN<32>]) {
+    env.as_contract(&client.address, || {
+        for token_id in token_ids {
+            let token_address = storage::token_id_config(env, token_id.clone()).token_address;
+
+            registry::deregister_token(env, token_id.clone(), token_address);
+        }
+    });
+}
+
+#[test]
+fn backfill_registry_backfills_token_registry() {
+    let (env, client, _, _, _) = setup_env();
+    let owner = client.owner();
+    let deployer = Address::generate(&env);
+
+    let (interchain_token_id, _) = setup_its_token(&env, &client, &deployer, 100);
+    let token_address = client.native_token_address();
+    let canonical_token_id = client.register_canonical_token(&token_address);
+
+    clear_registry(
+        &env,
+        &client,
+        &[interchain_token_id.clone(), canonical_token_id.clone()],
+    );
+    assert_eq!(client.registered_token_count(), 0);
+
+    start_migration(&env, &client);
+
+    assert_auth!(
+        owner,
+        client.backfill_registry(&vec![
+            &env,
+            interchain_token_id.clone(),
+            canonical_token_id.clone()
+        ])
+    );
+
+    complete_migration(&env, &client);
+
+    assert_eq!(client.registered_token_count(), 2);
+    assert_eq!(
+        client.registered_token_ids(&0, &10),
+        vec![&env, interchain_token_id, canonical_token_id.clone()]
+    );
+    assert_eq!(
+        client.token_ids_by_address(&token_address, &0, &10),
+        vec![&env, canonical_token_id]
+    );
+}
+
+#[test]
+fn backfill_registry_backfills_locked_amount() {
+    let (env, client, _, _, _) = setup_env();
+    let token_address = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    let token_id = client.register_canonical_token(&token_address);
+
+    StellarAssetClient::new(&env, &token_address)
+        .mock_all_auths()
+        .mint(&client.deployed_token_manager(&token_id), &1000);
+
+    clear_registry(&env, &client, &[token_id.clone()]);
+
+    start_migration(&env, &client);
+
+    client
+        .mock_all_auths()
+        .backfill_registry(&vec![&env, token_id.clone()]);
+
+    complete_migration(&env, &client);
+
+    assert_eq!(client.locked_amount(&token_id), 1000);
+}
+
+#[test]
+fn backfill_registry_skips_already_registered_tokens() {
+    let (env, client, _, _, _) = setup_env();
+    let deployer = Address::generate(&env);
+
+    let (token_id, _) = setup_its_token(&env, &client, &deployer, 100);
+
+    start_migration(&env, &client);
+
+    client
+        .mock_all_auths()
+        .backfill_registry(&vec![&env, token_id.clone(), token_id.clone()]);
+
+    complete_migration(&env, &client);
+
+    assert_eq!(client.registered_token_count(), 1);
+    assert_eq!(client.registered_token_ids(&0, &10), vec![&env, token_id]);
+}
+
+#[test]
+fn backfill_registry_fails_with_invalid_token_id() {
+    let (env, client, _, _, _) = setup_env();
+
+    start_migration(&env, &client);
+
+    assert_contract_err!(
+        client
+            .mock_all_auths()
+            .try_backfill_registry(&vec![&env, BytesN::from_array(&env, &[1; 32])]),
+        ContractError::InvalidTokenId
+    );
+}
+
+#[test]
+fn backfill_registry_does_not_overwrite_locked_amount_of_registered_tokens() {
+    let (env, client, _, _, _) = setup_env();
+    let token_address = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    let token_id = client.register_canonical_token(&token_address);
+
+    StellarAssetClient::new(&env, &token_address)
+        .mock_all_auths()
+        .mint(&client.deployed_token_manager(&token_id), &1000);
+
+    start_migration(&env, &client);
+
+    client
+        .mock_all_auths()
+        .backfill_registry(&vec![&env, token_id.clone()]);
+
+    complete_migration(&env, &client);
+
+    assert_eq!(client.locked_amount(&token_id), 0);
+}
+
+#[test]
+fn backfill_registry_fails_with_invalid_auth() {
+    let (env, client, _, _, _) = setup_env();
+    let user = Address::generate(&env);
+    let deployer = Address::generate(&env);
+
+    let (token_id, _) = setup_its_token(&env, &client, &deployer, 100);
+
+    start_migration(&env, &client);
+
+    let token_ids = vec![&env, token_id];
+
+    assert_auth_err!(user, client.backfill_registry(&token_ids));
+}
+
+#[test]
+fn backfill_registry_fails_if_not_migrating() {
+    let (env, client, _, _, _) = setup_env();
+    let (token_id, _) = setup_its_token(&env, &client, &Address::generate(&env), 100);
+
+    assert_contract_err!(
+        client
+            .mock_all_auths()
+            .try_backfill_registry(&vec![&env, token_id]),
+        ContractError::MigrationNotAllowed
+    );
+}
+
+#[test]
+fn migrate_backfills_last_batch() {
+    let (env, client, _, _, _) = setup_env();
+    let owner = client.owner();
+    let deployer = Address::generate(&env);
+
+    let (interchain_token_id, _) = setup_its_token(&env, &client, &deployer, 100);
+    let canonical_token_id = client.register_canonical_token(&client.native_token_address());
+
+    clear_registry(
+        &env,
+        &client,
+        &[interchain_token_id.clone(), canonical_token_id.clone()],
+    );
+
+    start_migration(&env, &client);
+
+    client
+        .mock_all_auths()
+        .backfill_registry(&vec![&env, interchain_token_id.clone()]);
+
+    assert_auth!(
+        owner,
+        client.migrate(&vec![&env, canonical_token_id.clone()])
+    );
+
+    assert_eq!(
+        client.registered_token_ids(&0, &10),
+        vec![&env, interchain_token_id.clone(), canonical_token_id]
+    );
+    assert_contract_err!(
+        client
+            .mock_all_auths()
+            .try_backfill_registry(&vec![&env, interchain_token_id]),
+        ContractError::MigrationNotAllowed
+    );
+}
+
+#[test]
+fn migrate_fails_if_not_migrating() {
+    let (env, client, _, _, _) = setup_env();
+
+    assert_contract_err!(
+        client.mock_all_auths().try_migrate(&vec![&env]),
+        ContractError::MigrationNotAllowed
+    );
+}
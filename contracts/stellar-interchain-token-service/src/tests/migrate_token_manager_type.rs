@@ -0,0 +1,284 @@
+use soroban_token_sdk::metadata::TokenMetadata;
+use stellar_axelar_std::testutils::Address as _;
+use stellar_axelar_std::token::{StellarAssetClient, TokenClient};
+use stellar_axelar_std::{
+    assert_auth, assert_auth_err, assert_contract_err, events, Address, BytesN,
+};
+use stellar_interchain_token::{InterchainToken, InterchainTokenClient};
+
+use super::utils::{register_custom_token, setup_env, TokenMetadataExt};
+use crate::error::ContractError;
+use crate::event::TokenManagerTypeMigratedEvent;
+use crate::testutils::setup_its_token;
+use crate::types::TokenManagerType;
+
+#[test]
+fn migrate_token_manager_type_from_lock_unlock_to_mint_burn_succeeds() {
+    let (env, client, _, _, _) = setup_env();
+    let locked_amount = 1000;
+    let token_address = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    let token = StellarAssetClient::new(&env, &token_address);
+
+    let token_id = register_custom_token(
+        &env,
+        &client,
+        &Address::generate(&env),
+        &token_address,
+        TokenManagerType::LockUnlock,
+    );
+    let token_manager = client.deployed_token_manager(&token_id);
+    token.mock_all_auths().mint(&token_manager, &locked_amount);
+    token.mock_all_auths().set_admin(&token_manager);
+
+    assert_auth!(
+        client.owner(),
+        client.migrate_token_manager_type(&token_id, &TokenManagerType::MintBurn)
+    );
+
+    goldie::assert!(events::fmt_last_emitted_event::<
+        TokenManagerTypeMigratedEvent,
+    >(&env));
+
+    assert_eq!(
+        client.token_manager_type(&token_id),
+        TokenManagerType::MintBurn
+    );
+    assert_eq!(client.deployed_token_manager(&token_id), token_manager);
+    assert_eq!(client.registered_token_address(&token_id), token_address);
+    assert_eq!(
+        TokenClient::new(&env, &token_address).balance(&token_manager),
+        0
+    );
+}
+
+#[test]
+fn migrate_token_manager_type_from_lock_unlock_to_mint_burn_from_succeeds() {
+    let (env, client, _, _, _) = setup_env();
+    let owner = Address::generate(&env);
+    let token_address = env.register(
+        InterchainToken,
+        (
+            owner,
+            None::<Address>,
+            BytesN::<32>::from_array(&env, &[2; 32]),
+            TokenMetadata::new(&env, "Test", "TEST", 7),
+        ),
+    );
+
+    let token_id = register_custom_token(
+        &env,
+        &client,
+        &Address::generate(&env),
+        &token_address,
+        TokenManagerType::LockUnlock,
+    );
+    let token_manager = client.deployed_token_manager(&token_id);
+    InterchainTokenClient::new(&env, &token_address)
+        .mock_all_auths()
+        .add_minter(&token_manager);
+
+    client
+        .mock_all_auths()
+        .migrate_token_manager_type(&token_id, &TokenManagerType::MintBurnFrom);
+
+    assert_eq!(
+        client.token_manager_type(&token_id),
+        TokenManagerType::MintBurnFrom
+    );
+}
+
+#[test]
+fn migrate_token_manager_type_from_mint_burn_to_lock_unlock_succeeds() {
+    let (env, client, _, _, _) = setup_env();
+    let token_address = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+
+    let token_id = register_custom_token(
+        &env,
+        &client,
+        &Address::generate(&env),
+        &token_address,
+        TokenManagerType::MintBurn,
+    );
+
+    StellarAssetClient::new(&env, &token_address)
+        .mock_all_auths()
+        .mint(&client.deployed_token_manager(&token_id), &1000);
+
+    client
+        .mock_all_auths()
+        .migrate_token_manager_type(&token_id, &TokenManagerType::LockUnlock);
+
+    assert_eq!(
+        client.token_manager_type(&token_id),
+        TokenManagerType::LockUnlock
+    );
+    assert_eq!(client.locked_amount(&token_id), 1000);
+}
+
+#[test]
+fn migrate_token_manager_type_to_lock_unlock_fails_if_token_manager_is_admin() {
+    let (env, client, _, _, _) = setup_env();
+    let token_address = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    let token_id = register_custom_token(
+        &env,
+        &client,
+        &Address::generate(&env),
+        &token_address,
+        TokenManagerType::MintBurn,
+    );
+
+    StellarAssetClient::new(&env, &token_address)
+        .mock_all_auths()
+        .set_admin(&client.deployed_token_manager(&token_id));
+
+    assert_contract_err!(
+        client
+            .mock_all_auths()
+            .try_migrate_token_manager_type(&token_id, &TokenManagerType::LockUnlock),
+        ContractError::TokenManagerStillMinter
+    );
+}
+
+#[test]
+fn migrate_token_manager_type_to_lock_unlock_fails_if_token_manager_is_minter() {
+    let (env, client, _, _, _) = setup_env();
+    let token_address = env.register(
+        InterchainToken,
+        (
+            Address::generate(&env),
+            None::<Address>,
+            BytesN::<32>::from_array(&env, &[2; 32]),
+            TokenMetadata::new(&env, "Test", "TEST", 7),
+        ),
+    );
+    let token_id = register_custom_token(
+        &env,
+        &client,
+        &Address::generate(&env),
+        &token_address,
+        TokenManagerType::MintBurnFrom,
+    );
+    let token = InterchainTokenClient::new(&env, &token_address);
+    let token_manager = client.deployed_token_manager(&token_id);
+    token.mock_all_auths().add_minter(&token_manager);
+
+    assert_contract_err!(
+        client
+            .mock_all_auths()
+            .try_migrate_token_manager_type(&token_id, &TokenManagerType::LockUnlock),
+        ContractError::TokenManagerStillMinter
+    );
+
+    token.mock_all_auths().remove_minter(&token_manager);
+
+    client
+        .mock_all_auths()
+        .migrate_token_manager_type(&token_id, &TokenManagerType::LockUnlock);
+
+    assert_eq!(client.locked_amount(&token_id), 0);
+}
+
+#[test]
+fn migrate_token_manager_type_fails_with_invalid_auth() {
+    let (env, client, _, _, _) = setup_env();
+    let token_address = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    let token_id = register_custom_token(
+        &env,
+        &client,
+        &Address::generate(&env),
+        &token_address,
+        TokenManagerType::MintBurn,
+    );
+
+    assert_auth_err!(
+        Address::generate(&env),
+        client.migrate_token_manager_type(&token_id, &TokenManagerType::LockUnlock)
+    );
+}
+
+#[test]
+fn migrate_token_manager_type_fails_with_invalid_token_id() {
+    let (env, client, _, _, _) = setup_env();
+
+    assert_contract_err!(
+        client.mock_all_auths().try_migrate_token_manager_type(
+            &BytesN::from_array(&env, &[1; 32]),
+            &TokenManagerType::LockUnlock
+        ),
+        ContractError::InvalidTokenId
+    );
+}
+
+#[test]
+fn migrate_token_manager_type_fails_with_invalid_token_manager_types() {
+    let (env, client, _, _, _) = setup_env();
+    let token_address = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    let token_id = register_custom_token(
+        &env,
+        &client,
+        &Address::generate(&env),
+        &token_address,
+        TokenManagerType::LockUnlock,
+    );
+    let (native_token_id, _) = setup_its_token(&env, &client, &Address::generate(&env), 100);
+
+    for (token_id, token_manager_type) in [
+        (&token_id, TokenManagerType::LockUnlock),
+        (&token_id, TokenManagerType::NativeInterchainToken),
+        (&native_token_id, TokenManagerType::MintBurn),
+        (&native_token_id, TokenManagerType::LockUnlock),
+    ] {
+        assert_contract_err!(
+            client
+                .mock_all_auths()
+                .try_migrate_token_manager_type(token_id, &token_manager_type),
+            ContractError::InvalidTokenManagerType
+        );
+    }
+}
+
+#[test]
+fn migrate_token_manager_type_fails_if_token_manager_is_not_minter() {
+    let (env, client, _, _, _) = setup_env();
+    let token_address = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    let token_id = register_custom_token(
+        &env,
+        &client,
+        &Address::generate(&env),
+        &token_address,
+        TokenManagerType::LockUnlock,
+    );
+
+    for token_manager_type in [TokenManagerType::MintBurn, TokenManagerType::MintBurnFrom] {
+        assert_contract_err!(
+            client
+                .mock_all_auths()
+                .try_migrate_token_manager_type(&token_id, &token_manager_type),
+            ContractError::TokenManagerNotMinter
+        );
+    }
+
+    // The admin role of a Stellar Classic Asset doesn't grant the `mint_from` permission
+    StellarAssetClient::new(&env, &token_address)
+        .mock_all_auths()
+        .set_admin(&client.deployed_token_manager(&token_id));
+
+    assert_contract_err!(
+        client
+            .mock_all_auths()
+            .try_migrate_token_manager_type(&token_id, &TokenManagerType::MintBurnFrom),
+        ContractError::TokenManagerNotMinter
+    );
+}
@@ -0,0 +1,140 @@
+use stellar_axelar_std::testutils::Address as _;
+use stellar_axelar_std::{assert_auth, assert_auth_err, assert_contract_err, events, Address};
+use stellar_interchain_token::InterchainTokenClient;
+
+use super::utils::setup_env;
+use crate::error::ContractError;
+use crate::event::{MintershipRenouncedEvent, MintershipTransferredEvent};
+use crate::testutils::setup_its_token;
+
+#[test]
+fn transfer_mintership_succeeds() {
+    let (env, client, _, _, _) = setup_env();
+    let minter = Address::generate(&env);
+    let new_minter = Address::generate(&env);
+    let (token_id, _) = setup_its_token(&env, &client, &minter, 100);
+    let token = InterchainTokenClient::new(&env, &client.registered_token_address(&token_id));
+
+    assert_auth!(
+        minter,
+        client.transfer_mintership(&minter, &token_id, &new_minter)
+    );
+
+    goldie::assert!(events::fmt_last_emitted_event::<MintershipTransferredEvent>(&env));
+
+    assert!(!token.is_minter(&minter));
+    assert!(token.is_minter(&new_minter));
+    assert!(token.is_minter(&client.deployed_token_manager(&token_id)));
+}
+
+#[test]
+fn transfer_mintership_fails_without_minter_auth() {
+    let (env, client, _, _, _) = setup_env();
+    let minter = Address::generate(&env);
+    let (token_id, _) = setup_its_token(&env, &client, &minter, 100);
+
+    assert_auth_err!(
+        Address::generate(&env),
+        client.transfer_mintership(&minter, &token_id, &Address::generate(&env))
+    );
+}
+
+#[test]
+fn transfer_mintership_fails_if_not_minter() {
+    let (env, client, _, _, _) = setup_env();
+    let minter = Address::generate(&env);
+    let (token_id, _) = setup_its_token(&env, &client, &minter, 100);
+
+    assert_contract_err!(
+        client.mock_all_auths().try_transfer_mintership(
+            &Address::generate(&env),
+            &token_id,
+            &Address::generate(&env)
+        ),
+        ContractError::NotMinter
+    );
+}
+
+#[test]
+fn transfer_mintership_fails_for_token_manager() {
+    let (env, client, _, _, _) = setup_env();
+    let minter = Address::generate(&env);
+    let (token_id, _) = setup_its_token(&env, &client, &minter, 100);
+    let token_manager = client.deployed_token_manager(&token_id);
+
+    assert_contract_err!(
+        client.mock_all_auths().try_transfer_mintership(
+            &token_manager,
+            &token_id,
+            &Address::generate(&env)
+        ),
+        ContractError::NotMinter
+    );
+}
+
+#[test]
+fn transfer_mintership_fails_if_new_minter_is_already_minter() {
+    let (env, client, _, _, _) = setup_env();
+    let minter = Address::generate(&env);
+    let (token_id, _) = setup_its_token(&env, &client, &minter, 100);
+    let token_manager = client.deployed_token_manager(&token_id);
+
+    assert_contract_err!(
+        client
+            .mock_all_auths()
+            .try_transfer_mintership(&minter, &token_id, &token_manager),
+        ContractError::InvalidMinter
+    );
+}
+
+#[test]
+fn transfer_mintership_fails_with_invalid_token_manager_type() {
+    let (env, client, _, _, _) = setup_env();
+    let minter = Address::generate(&env);
+    let token_address = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    let token_id = client
+        .mock_all_auths()
+        .register_canonical_token(&token_address);
+
+    assert_contract_err!(
+        client.mock_all_auths().try_transfer_mintership(
+            &minter,
+            &token_id,
+            &Address::generate(&env)
+        ),
+        ContractError::InvalidTokenManagerType
+    );
+}
+
+#[test]
+fn renounce_mintership_succeeds() {
+    let (env, client, _, _, _) = setup_env();
+    let minter = Address::generate(&env);
+    let (token_id, _) = setup_its_token(&env, &client, &minter, 100);
+    let token = InterchainTokenClient::new(&env, &client.registered_token_address(&token_id));
+
+    assert_auth!(minter, client.renounce_mintership(&minter, &token_id));
+
+    goldie::assert!(events::fmt_last_emitted_event::<MintershipRenouncedEvent>(
+        &env
+    ));
+
+    assert!(!token.is_minter(&minter));
+    assert!(token.is_minter(&client.deployed_token_manager(&token_id)));
+}
+
+#[test]
+fn renounce_mintership_fails_if_not_minter() {
+    let (env, client, _, _, _) = setup_env();
+    let minter = Address::generate(&env);
+    let (token_id, _) = setup_its_token(&env, &client, &minter, 100);
+
+    assert_contract_err!(
+        client
+            .mock_all_auths()
+            .try_renounce_mintership(&Address::generate(&env), &token_id),
+        ContractError::NotMinter
+    );
+}
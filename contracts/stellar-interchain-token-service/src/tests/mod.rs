@@ -1,18 +1,38 @@
+mod call_contract_with_token;
+mod compliance;
+mod decimals;
 mod deploy_interchain_token;
 mod deploy_remote_canonical_token;
 mod deploy_remote_interchain_token;
+mod deploy_remote_token_with_minter;
 mod deployer;
+mod deregister_token;
 mod executable;
 mod execute;
+mod exit_token;
+mod express_execute;
+mod fee;
 mod flow_limit;
+mod flow_limiter;
+mod interchain_token_factory;
 mod interchain_transfer;
 mod link_token;
+mod locked_amount;
 mod message_routing;
+mod migrate;
+mod migrate_token_manager_type;
+mod mintership;
 mod pause;
+mod recover_interchain_transfer;
 mod register_canonical_token;
 mod register_custom_token;
 mod register_token_metadata;
+mod registry;
 mod token_id;
+mod token_info;
+mod token_metadata_override;
+mod token_pause;
 mod transfer_token_admin;
 mod trusted_chain;
+mod update_token_metadata;
 mod utils;
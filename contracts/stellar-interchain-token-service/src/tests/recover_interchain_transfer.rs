@@ -0,0 +1,219 @@
+use stellar_axelar_gateway::event::ContractCalledEvent;
+use stellar_axelar_std::testutils::Address as _;
+use stellar_axelar_std::token::TokenClient;
+use stellar_axelar_std::types::Token;
+use stellar_axelar_std::{
+    assert_auth, assert_auth_err, assert_contract_err, events, Address, Bytes, Env,
+};
+
+use super::utils::{approve_hub_transfer, setup_env, setup_hub_transfer, HubTransferTestData};
+use crate::error::ContractError;
+use crate::event::{
+    InterchainTransferRecoveredEvent, InterchainTransferReturnedEvent, RecoveryAddressSetEvent,
+};
+use crate::testutils::setup_its_token;
+use crate::types::TransferRecovery;
+use crate::InterchainTokenServiceClient;
+
+fn setup_recovery_test(env: &Env, client: &InterchainTokenServiceClient) -> HubTransferTestData {
+    let (token_id, _) = setup_its_token(env, client, &Address::generate(env), 0);
+
+    setup_hub_transfer(
+        env,
+        client,
+        token_id,
+        1000,
+        Some(Bytes::from_array(env, &[1; 4])),
+    )
+}
+
+#[test]
+fn set_recovery_address_succeeds() {
+    let (env, client, _, _, _) = setup_env();
+    let contract = Address::generate(&env);
+    let recovery_address = Some(Address::generate(&env));
+
+    assert_eq!(client.recovery_address(&contract), None);
+
+    assert_auth!(
+        contract,
+        client.set_recovery_address(&contract, &recovery_address)
+    );
+
+    goldie::assert!(events::fmt_last_emitted_event::<RecoveryAddressSetEvent>(
+        &env
+    ));
+
+    assert_eq!(client.recovery_address(&contract), recovery_address);
+
+    client
+        .mock_all_auths()
+        .set_recovery_address(&contract, &None);
+
+    assert_eq!(client.recovery_address(&contract), None);
+}
+
+#[test]
+fn set_recovery_address_fails_without_contract_auth() {
+    let (env, client, _, _, _) = setup_env();
+
+    assert_auth_err!(
+        Address::generate(&env),
+        client.set_recovery_address(&Address::generate(&env), &Some(Address::generate(&env)))
+    );
+}
+
+#[test]
+fn recover_interchain_transfer_to_recovery_address_succeeds() {
+    let (env, client, gateway_client, _, signers) = setup_env();
+    let data = setup_recovery_test(&env, &client);
+    let recovery_address = Address::generate(&env);
+
+    client
+        .mock_all_auths()
+        .set_recovery_address(&data.destination_address, &Some(recovery_address.clone()));
+    approve_hub_transfer(&env, &client, &gateway_client, signers, &data);
+
+    client.mock_all_auths().recover_interchain_transfer(
+        &data.source_chain,
+        &data.message_id,
+        &data.source_address,
+        &data.payload,
+        &TransferRecovery::RecoveryAddress,
+        &None,
+    );
+
+    goldie::assert!(events::fmt_last_emitted_event::<
+        InterchainTransferRecoveredEvent,
+    >(&env));
+
+    let token = TokenClient::new(&env, &client.registered_token_address(&data.token_id));
+    assert_eq!(token.balance(&recovery_address), data.amount);
+    assert_eq!(token.balance(&data.destination_address), 0);
+
+    assert_contract_err!(
+        client.try_execute(
+            &data.source_chain,
+            &data.message_id,
+            &data.source_address,
+            &data.payload
+        ),
+        ContractError::NotApproved
+    );
+}
+
+#[test]
+fn recover_interchain_transfer_to_sender_succeeds() {
+    let (env, client, gateway_client, _, signers) = setup_env();
+    let data = setup_recovery_test(&env, &client);
+
+    approve_hub_transfer(&env, &client, &gateway_client, signers, &data);
+
+    client.mock_all_auths().recover_interchain_transfer(
+        &data.source_chain,
+        &data.message_id,
+        &data.source_address,
+        &data.payload,
+        &TransferRecovery::ReturnToSender,
+        &None,
+    );
+
+    goldie::assert!([
+        events::fmt_emitted_event_at_idx::<InterchainTransferReturnedEvent>(&env, -2),
+        events::fmt_last_emitted_event::<ContractCalledEvent>(&env)
+    ]
+    .join("\n\n"));
+
+    let token = TokenClient::new(&env, &client.registered_token_address(&data.token_id));
+    assert_eq!(token.balance(&data.destination_address), 0);
+}
+
+#[test]
+fn recover_interchain_transfer_to_sender_marks_token_as_transferred() {
+    let (env, client, gateway_client, _, signers) = setup_env();
+    let deployer = Address::generate(&env);
+    let (token_id, _) = setup_its_token(&env, &client, &deployer, 0);
+    let data = setup_hub_transfer(
+        &env,
+        &client,
+        token_id,
+        1000,
+        Some(Bytes::from_array(&env, &[1; 4])),
+    );
+
+    approve_hub_transfer(&env, &client, &gateway_client, signers, &data);
+
+    client.mock_all_auths().recover_interchain_transfer(
+        &data.source_chain,
+        &data.message_id,
+        &data.source_address,
+        &data.payload,
+        &TransferRecovery::ReturnToSender,
+        &None,
+    );
+
+    assert_contract_err!(
+        client
+            .mock_all_auths()
+            .try_deregister_token(&deployer, &data.token_id),
+        ContractError::TokenAlreadyTransferred
+    );
+}
+
+#[test]
+fn recover_interchain_transfer_fails_without_recovery_address() {
+    let (env, client, gateway_client, _, signers) = setup_env();
+    let data = setup_recovery_test(&env, &client);
+
+    approve_hub_transfer(&env, &client, &gateway_client, signers, &data);
+
+    assert_contract_err!(
+        client.mock_all_auths().try_recover_interchain_transfer(
+            &data.source_chain,
+            &data.message_id,
+            &data.source_address,
+            &data.payload,
+            &TransferRecovery::RecoveryAddress,
+            &None,
+        ),
+        ContractError::RecoveryAddressNotSet
+    );
+}
+
+#[test]
+fn recover_interchain_transfer_fails_if_not_approved() {
+    let (env, client, _, _, _) = setup_env();
+    let data = setup_recovery_test(&env, &client);
+
+    assert_contract_err!(
+        client.mock_all_auths().try_recover_interchain_transfer(
+            &data.source_chain,
+            &data.message_id,
+            &data.source_address,
+            &data.payload,
+            &TransferRecovery::ReturnToSender,
+            &None,
+        ),
+        ContractError::NotApproved
+    );
+}
+
+#[test]
+fn recover_interchain_transfer_fails_if_not_operator() {
+    let (env, client, gateway_client, _, signers) = setup_env();
+    let data = setup_recovery_test(&env, &client);
+
+    approve_hub_transfer(&env, &client, &gateway_client, signers, &data);
+
+    assert_auth_err!(
+        Address::generate(&env),
+        client.recover_interchain_transfer(
+            &data.source_chain,
+            &data.message_id,
+            &data.source_address,
+            &data.payload,
+            &TransferRecovery::ReturnToSender,
+            &None::<Token>,
+        )
+    );
+}
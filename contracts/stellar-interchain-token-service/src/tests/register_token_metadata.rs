@@ -1,6 +1,6 @@
 use stellar_axelar_gas_service::testutils::setup_gas_token;
 use stellar_axelar_std::testutils::Address as _;
-use stellar_axelar_std::{assert_auth_err, assert_contract_err, events, Address, String};
+use stellar_axelar_std::{assert_auth_err, assert_contract_err, events, vec, Address, String};
 
 use super::utils::setup_env;
 use crate::error::ContractError;
@@ -76,3 +76,91 @@ fn register_token_metadata_fails_with_unauthorized() {
         client.register_token_metadata(&token.address(), &spender, &Some(gas_token.clone()))
     );
 }
+
+#[test]
+fn register_tokens_metadata_succeeds() {
+    let (env, client, _, _, _) = setup_env();
+    let spender = Address::generate(&env);
+    let first_token = env.register_stellar_asset_contract_v2(Address::generate(&env));
+    let second_token = env.register_stellar_asset_contract_v2(Address::generate(&env));
+
+    client
+        .mock_all_auths()
+        .set_trusted_chain(&client.its_hub_chain_name());
+
+    client.mock_all_auths().register_tokens_metadata(
+        &vec![
+            &env,
+            (first_token.address(), Some(setup_gas_token(&env, &spender))),
+            (second_token.address(), None),
+        ],
+        &spender,
+    );
+
+    let first_event = events::fmt_emitted_event_at_idx::<TokenMetadataRegisteredEvent>(&env, -3);
+    let second_event = events::fmt_emitted_event_at_idx::<TokenMetadataRegisteredEvent>(
+        &env,
+        REGISTER_TOKEN_METADATA_EVENT_IDX,
+    );
+
+    goldie::assert!([first_event, second_event].join("\n\n"));
+}
+
+#[test]
+fn register_tokens_metadata_fails_with_empty_tokens() {
+    let (env, client, _, _, _) = setup_env();
+
+    assert_contract_err!(
+        client
+            .mock_all_auths()
+            .try_register_tokens_metadata(&vec![&env], &Address::generate(&env)),
+        ContractError::EmptyTokenAddresses
+    );
+}
+
+#[test]
+fn register_tokens_metadata_fails_with_invalid_token() {
+    let (env, client, _, _, _) = setup_env();
+    let token = env.register_stellar_asset_contract_v2(Address::generate(&env));
+
+    assert_contract_err!(
+        client.mock_all_auths().try_register_tokens_metadata(
+            &vec![
+                &env,
+                (token.address(), None),
+                (Address::generate(&env), None)
+            ],
+            &Address::generate(&env)
+        ),
+        ContractError::InvalidTokenAddress
+    );
+}
+
+#[test]
+fn register_tokens_metadata_fails_when_paused() {
+    let (env, client, _, _, _) = setup_env();
+    let token = env.register_stellar_asset_contract_v2(Address::generate(&env));
+
+    client.mock_all_auths().pause();
+
+    assert_contract_err!(
+        client.try_register_tokens_metadata(
+            &vec![&env, (token.address(), None)],
+            &Address::generate(&env)
+        ),
+        ContractError::ContractPaused
+    );
+}
+
+#[test]
+fn register_tokens_metadata_fails_with_unauthorized() {
+    let (env, client, _, _, _) = setup_env();
+    let token = env.register_stellar_asset_contract_v2(Address::generate(&env));
+    let spender = Address::generate(&env);
+    let tokens = vec![
+        &env,
+        (token.address(), Some(setup_gas_token(&env, &spender))),
+    ];
+
+    assert_auth_err!(spender, client.register_tokens_metadata(&tokens, &spender));
+}
@@ -0,0 +1,108 @@
+use stellar_axelar_std::testutils::Address as _;
+use stellar_axelar_std::{vec, Address, BytesN};
+
+use super::utils::setup_env;
+use crate::testutils::setup_its_token;
+use crate::types::TokenManagerType;
+
+#[test]
+fn registry_is_empty_initially() {
+    let (env, client, _, _, _) = setup_env();
+
+    assert_eq!(client.registered_token_count(), 0);
+    assert_eq!(client.registered_token_ids(&0, &10), vec![&env]);
+    assert_eq!(
+        client.token_ids_by_address(&Address::generate(&env), &0, &10),
+        vec![&env]
+    );
+}
+
+#[test]
+fn registered_tokens_are_enumerable() {
+    let (env, client, _, _, _) = setup_env();
+    let deployer = Address::generate(&env);
+
+    let (interchain_token_id, _) = setup_its_token(&env, &client, &deployer, 100);
+    let token_address = client.native_token_address();
+    let canonical_token_id = client.register_canonical_token(&token_address);
+    let custom_token_id = client.mock_all_auths().register_custom_token(
+        &deployer,
+        &BytesN::from_array(&env, &[2; 32]),
+        &token_address,
+        &TokenManagerType::LockUnlock,
+    );
+
+    assert_eq!(client.registered_token_count(), 3);
+    assert_eq!(
+        client.registered_token_ids(&0, &10),
+        vec![
+            &env,
+            interchain_token_id.clone(),
+            canonical_token_id.clone(),
+            custom_token_id.clone()
+        ]
+    );
+    assert_eq!(
+        client.registered_token_ids(&1, &1),
+        vec![&env, canonical_token_id.clone()]
+    );
+    assert_eq!(client.registered_token_ids(&3, &10), vec![&env]);
+
+    assert_eq!(client.token_id_count_by_address(&token_address), 2);
+    assert_eq!(
+        client.token_ids_by_address(&token_address, &0, &10),
+        vec![&env, canonical_token_id, custom_token_id.clone()]
+    );
+    assert_eq!(
+        client.token_ids_by_address(&token_address, &1, &1),
+        vec![&env, custom_token_id]
+    );
+    assert_eq!(
+        client.token_ids_by_address(
+            &client.registered_token_address(&interchain_token_id),
+            &0,
+            &10
+        ),
+        vec![&env, interchain_token_id]
+    );
+}
+
+#[test]
+fn registered_token_ids_is_capped_at_max_page_size() {
+    let (env, client, _, _, _) = setup_env();
+    let deployer = Address::generate(&env);
+    let token_address = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+
+    for i in 0..=crate::registry::MAX_PAGE_SIZE {
+        let mut salt = [0u8; 32];
+        salt[..4].copy_from_slice(&i.to_be_bytes());
+
+        client.mock_all_auths().register_custom_token(
+            &deployer,
+            &BytesN::from_array(&env, &salt),
+            &token_address,
+            &TokenManagerType::LockUnlock,
+        );
+    }
+
+    assert_eq!(
+        client.registered_token_count(),
+        crate::registry::MAX_PAGE_SIZE + 1
+    );
+    assert_eq!(
+        client.registered_token_ids(&0, &u32::MAX).len(),
+        crate::registry::MAX_PAGE_SIZE
+    );
+    assert_eq!(
+        client.token_id_count_by_address(&token_address),
+        crate::registry::MAX_PAGE_SIZE + 1
+    );
+    assert_eq!(
+        client
+            .token_ids_by_address(&token_address, &0, &u32::MAX)
+            .len(),
+        crate::registry::MAX_PAGE_SIZE
+    );
+}
@@ -1,3 +1,4 @@
+use stellar_axelar_std::testutils::Address as _;
 use stellar_axelar_std::{Address, BytesN};
 
 use super::utils::setup_env;
@@ -47,3 +48,24 @@ fn linked_token_id_is_unchanged() {
         client.linked_token_id(&deployer, &salt).to_array()
     ));
 }
+
+#[test]
+fn factory_interchain_token_id_matches_evm_derivation() {
+    let (env, client, _, _, _) = setup_env();
+    let factory = Address::generate(&env);
+    let deploy_salt = BytesN::<32>::from_array(&env, &[1; 32]);
+
+    client
+        .mock_all_auths()
+        .set_interchain_token_factory(&factory);
+
+    // keccak256(abi.encode(keccak256("its-interchain-token-id"), address(0), deploySalt))
+    assert_eq!(
+        hex::encode(
+            client
+                .interchain_token_id(&factory, &deploy_salt)
+                .to_array()
+        ),
+        "8f0be19666dfbf7d000f3356d723dd9f68c219f566aa673cd6c7bf7a74e36200"
+    );
+}
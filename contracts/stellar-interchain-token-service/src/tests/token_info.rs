@@ -0,0 +1,96 @@
+use stellar_axelar_std::testutils::Address as _;
+use stellar_axelar_std::traits::BytesExt;
+use stellar_axelar_std::{assert_contract_err, Address, Bytes, BytesN, String};
+
+use super::utils::setup_env;
+use crate::error::ContractError;
+use crate::storage;
+use crate::testutils::setup_its_token;
+use crate::types::{TokenInfo, TokenManagerType};
+
+#[test]
+fn token_info_succeeds() {
+    let (env, client, _, _, _) = setup_env();
+    let sender = Address::generate(&env);
+    let amount = 100;
+    let flow_limit = 1000;
+    let (token_id, token_metadata) = setup_its_token(&env, &client, &sender, amount);
+    let destination_chain = String::from_str(&env, "ethereum");
+
+    client
+        .mock_all_auths()
+        .set_trusted_chain(&destination_chain);
+    client
+        .mock_all_auths()
+        .set_flow_limit(&token_id, &Some(flow_limit));
+    client.mock_all_auths().interchain_transfer(
+        &sender,
+        &token_id,
+        &destination_chain,
+        &Bytes::from_hex(&env, "4F4495243837681061C4743b74B3eEdf548D56A5"),
+        &amount,
+        &None,
+        &None,
+    );
+
+    assert_eq!(
+        client.token_info(&token_id),
+        TokenInfo {
+            token_address: client.registered_token_address(&token_id),
+            token_manager: client.deployed_token_manager(&token_id),
+            token_manager_type: TokenManagerType::NativeInterchainToken,
+            metadata: token_metadata,
+            flow_limit: Some(flow_limit),
+            flow_in_amount: 0,
+            flow_out_amount: amount,
+        }
+    );
+}
+
+#[test]
+fn token_info_fails_with_invalid_token_id() {
+    let (env, client, _, _, _) = setup_env();
+
+    assert_contract_err!(
+        client.try_token_info(&BytesN::from_array(&env, &[1; 32])),
+        ContractError::InvalidTokenId
+    );
+}
+
+#[test]
+fn registered_token_info_returns_token_info() {
+    let (env, client, _, _, _) = setup_env();
+    let (token_id, _) = setup_its_token(&env, &client, &Address::generate(&env), 100);
+
+    assert_eq!(
+        client.registered_token_info(&token_id),
+        Some(client.token_info(&token_id))
+    );
+}
+
+#[test]
+fn registered_token_info_returns_none_for_unregistered_token() {
+    let (env, client, _, _, _) = setup_env();
+
+    assert_eq!(
+        client.registered_token_info(&BytesN::from_array(&env, &[1; 32])),
+        None
+    );
+}
+
+#[test]
+fn registered_token_info_fails_if_token_metadata_is_unavailable() {
+    let (env, client, _, _, _) = setup_env();
+    let (token_id, _) = setup_its_token(&env, &client, &Address::generate(&env), 100);
+
+    env.as_contract(&client.address, || {
+        let mut token_config = storage::token_id_config(&env, token_id.clone());
+        token_config.token_address = Address::generate(&env);
+        storage::set_token_id_config(&env, token_id.clone(), &token_config);
+    });
+
+    assert_contract_err!(
+        client.try_registered_token_info(&token_id),
+        ContractError::InvalidTokenAddress
+    );
+}
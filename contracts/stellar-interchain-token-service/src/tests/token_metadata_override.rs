@@ -0,0 +1,143 @@
+use stellar_axelar_std::testutils::Address as _;
+use stellar_axelar_std::{assert_auth, assert_contract_err, events, Address, Env, String};
+
+use super::utils::setup_env;
+use crate::error::ContractError;
+use crate::event::{InterchainTokenDeploymentStartedEvent, TokenMetadataOverrideSetEvent};
+use crate::tests::utils::INTERCHAIN_TOKEN_DEPLOYED_WITHOUT_GAS_TOKEN_EVENT_IDX;
+use crate::types::TokenMetadataOverride;
+
+fn metadata_override(env: &Env) -> TokenMetadataOverride {
+    TokenMetadataOverride {
+        name: String::from_str(env, "USD Coin (Stellar)"),
+        symbol: String::from_str(env, "USDC"),
+    }
+}
+
+#[test]
+fn set_token_metadata_override_succeeds_by_owner() {
+    let (env, client, _, _, _) = setup_env();
+    let owner = client.owner();
+    let token_address = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    let metadata = Some(metadata_override(&env));
+
+    assert_eq!(client.token_metadata_override(&token_address), None);
+
+    assert_auth!(
+        owner,
+        client.set_token_metadata_override(&owner, &token_address, &metadata)
+    );
+
+    goldie::assert!(events::fmt_last_emitted_event::<
+        TokenMetadataOverrideSetEvent,
+    >(&env));
+
+    assert_eq!(client.token_metadata_override(&token_address), metadata);
+
+    client
+        .mock_all_auths()
+        .set_token_metadata_override(&owner, &token_address, &None);
+
+    assert_eq!(client.token_metadata_override(&token_address), None);
+}
+
+#[test]
+fn set_token_metadata_override_succeeds_by_token_admin() {
+    let (env, client, _, _, _) = setup_env();
+    let token_admin = Address::generate(&env);
+    let token_address = env
+        .register_stellar_asset_contract_v2(token_admin.clone())
+        .address();
+    let metadata = Some(metadata_override(&env));
+
+    assert_auth!(
+        token_admin,
+        client.set_token_metadata_override(&token_admin, &token_address, &metadata)
+    );
+
+    assert_eq!(client.token_metadata_override(&token_address), metadata);
+}
+
+#[test]
+fn set_token_metadata_override_fails_if_not_owner_or_token_admin() {
+    let (env, client, _, _, _) = setup_env();
+    let token_address = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+
+    assert_contract_err!(
+        client.mock_all_auths().try_set_token_metadata_override(
+            &Address::generate(&env),
+            &token_address,
+            &Some(metadata_override(&env))
+        ),
+        ContractError::NotOwnerOrTokenAdmin
+    );
+}
+
+#[test]
+fn set_token_metadata_override_fails_with_invalid_metadata() {
+    let (env, client, _, _, _) = setup_env();
+    let token_address = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+
+    assert_contract_err!(
+        client.mock_all_auths().try_set_token_metadata_override(
+            &client.owner(),
+            &token_address,
+            &Some(TokenMetadataOverride {
+                name: String::from_str(&env, ""),
+                symbol: String::from_str(&env, "USDC"),
+            })
+        ),
+        ContractError::InvalidTokenName
+    );
+    assert_contract_err!(
+        client.mock_all_auths().try_set_token_metadata_override(
+            &client.owner(),
+            &token_address,
+            &Some(TokenMetadataOverride {
+                name: String::from_str(&env, "USD Coin"),
+                symbol: String::from_str(&env, "USDC世界"),
+            })
+        ),
+        ContractError::InvalidTokenSymbol
+    );
+}
+
+#[test]
+fn deploy_remote_canonical_token_succeeds_with_metadata_override() {
+    let (env, client, _, _, _) = setup_env();
+    let spender = Address::generate(&env);
+    let token_address = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    let destination_chain = String::from_str(&env, "ethereum");
+
+    client
+        .mock_all_auths()
+        .set_trusted_chain(&destination_chain);
+    client.mock_all_auths().set_token_metadata_override(
+        &client.owner(),
+        &token_address,
+        &Some(metadata_override(&env)),
+    );
+    client.register_canonical_token(&token_address);
+
+    client.mock_all_auths().deploy_remote_canonical_token(
+        &token_address,
+        &destination_chain,
+        &spender,
+        &None,
+    );
+
+    goldie::assert!(events::fmt_emitted_event_at_idx::<
+        InterchainTokenDeploymentStartedEvent,
+    >(
+        &env,
+        INTERCHAIN_TOKEN_DEPLOYED_WITHOUT_GAS_TOKEN_EVENT_IDX
+    ));
+}
@@ -0,0 +1,218 @@
+use stellar_axelar_gateway::testutils::approve_gateway_messages;
+use stellar_axelar_gateway::types::Message as GatewayMessage;
+use stellar_axelar_std::address::AddressExt;
+use stellar_axelar_std::testutils::Address as _;
+use stellar_axelar_std::token::{StellarAssetClient, TokenClient};
+use stellar_axelar_std::traits::BytesExt;
+use stellar_axelar_std::{
+    assert_auth, assert_auth_err, assert_contract_err, events, vec, Address, Bytes, BytesN, String,
+};
+
+use super::utils::setup_env;
+use crate::error::ContractError;
+use crate::event::{TokenPausedEvent, TokenUnpausedEvent};
+use crate::testutils::setup_its_token;
+use crate::types::{HubMessage, InterchainTransfer, Message};
+
+#[test]
+fn pause_token_succeeds() {
+    let (env, client, _, _, _) = setup_env();
+    let (token_id, _) = setup_its_token(&env, &client, &Address::generate(&env), 100);
+
+    assert!(!client.is_token_paused(&token_id));
+
+    assert_auth!(client.operator(), client.pause_token(&token_id));
+
+    goldie::assert!(events::fmt_last_emitted_event::<TokenPausedEvent>(&env));
+
+    assert!(client.is_token_paused(&token_id));
+}
+
+#[test]
+fn unpause_token_succeeds() {
+    let (env, client, _, _, _) = setup_env();
+    let (token_id, _) = setup_its_token(&env, &client, &Address::generate(&env), 100);
+
+    client.mock_all_auths().pause_token(&token_id);
+
+    assert_auth!(client.operator(), client.unpause_token(&token_id));
+
+    goldie::assert!(events::fmt_last_emitted_event::<TokenUnpausedEvent>(&env));
+
+    assert!(!client.is_token_paused(&token_id));
+}
+
+#[test]
+fn pause_token_fails_with_invalid_auth() {
+    let (env, client, _, _, _) = setup_env();
+    let (token_id, _) = setup_its_token(&env, &client, &Address::generate(&env), 100);
+
+    assert_auth_err!(Address::generate(&env), client.pause_token(&token_id));
+}
+
+#[test]
+fn pause_token_fails_with_invalid_token_id() {
+    let (env, client, _, _, _) = setup_env();
+
+    assert_contract_err!(
+        client
+            .mock_all_auths()
+            .try_pause_token(&BytesN::from_array(&env, &[1; 32])),
+        ContractError::InvalidTokenId
+    );
+}
+
+#[test]
+fn pause_token_fails_if_already_paused() {
+    let (env, client, _, _, _) = setup_env();
+    let (token_id, _) = setup_its_token(&env, &client, &Address::generate(&env), 100);
+
+    client.mock_all_auths().pause_token(&token_id);
+
+    assert_contract_err!(
+        client.mock_all_auths().try_pause_token(&token_id),
+        ContractError::TokenPaused
+    );
+}
+
+#[test]
+fn unpause_token_fails_if_not_paused() {
+    let (env, client, _, _, _) = setup_env();
+    let (token_id, _) = setup_its_token(&env, &client, &Address::generate(&env), 100);
+
+    assert_contract_err!(
+        client.mock_all_auths().try_unpause_token(&token_id),
+        ContractError::TokenNotPaused
+    );
+}
+
+#[test]
+fn interchain_transfer_fails_when_token_paused() {
+    let (env, client, _, _, _) = setup_env();
+    let sender = Address::generate(&env);
+    let amount = 1000;
+    let (token_id, _) = setup_its_token(&env, &client, &sender, amount);
+    let destination_chain = String::from_str(&env, "ethereum");
+    let destination_address = Bytes::from_hex(&env, "4F4495243837681061C4743b74B3eEdf548D56A5");
+
+    client
+        .mock_all_auths()
+        .set_trusted_chain(&destination_chain);
+    client.mock_all_auths().pause_token(&token_id);
+
+    assert_contract_err!(
+        client.mock_all_auths().try_interchain_transfer(
+            &sender,
+            &token_id,
+            &destination_chain,
+            &destination_address,
+            &amount,
+            &None,
+            &None
+        ),
+        ContractError::TokenPaused
+    );
+
+    client.mock_all_auths().unpause_token(&token_id);
+
+    client.mock_all_auths().interchain_transfer(
+        &sender,
+        &token_id,
+        &destination_chain,
+        &destination_address,
+        &amount,
+        &None,
+        &None,
+    );
+}
+
+#[test]
+fn interchain_transfer_of_other_token_succeeds_when_token_paused() {
+    let (env, client, _, _, _) = setup_env();
+    let sender = Address::generate(&env);
+    let amount = 1000;
+    let (paused_token_id, _) = setup_its_token(&env, &client, &sender, amount);
+    let destination_chain = String::from_str(&env, "ethereum");
+
+    let token_address = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    let token_id = client
+        .mock_all_auths()
+        .register_canonical_token(&token_address);
+    StellarAssetClient::new(&env, &token_address)
+        .mock_all_auths()
+        .mint(&sender, &amount);
+
+    client
+        .mock_all_auths()
+        .set_trusted_chain(&destination_chain);
+    client.mock_all_auths().pause_token(&paused_token_id);
+
+    client.mock_all_auths().interchain_transfer(
+        &sender,
+        &token_id,
+        &destination_chain,
+        &Bytes::from_hex(&env, "4F4495243837681061C4743b74B3eEdf548D56A5"),
+        &amount,
+        &None,
+        &None,
+    );
+
+    assert_eq!(TokenClient::new(&env, &token_address).balance(&sender), 0);
+}
+
+#[test]
+fn execute_interchain_transfer_fails_when_token_paused() {
+    let (env, client, gateway_client, _, signers) = setup_env();
+    let (token_id, _) = setup_its_token(&env, &client, &Address::generate(&env), 100);
+    let original_source_chain = String::from_str(&env, "ethereum");
+    let recipient = Address::generate(&env);
+    let amount = 1000;
+
+    client
+        .mock_all_auths()
+        .set_trusted_chain(&original_source_chain);
+    client.mock_all_auths().pause_token(&token_id);
+
+    let payload = HubMessage::ReceiveFromHub {
+        source_chain: original_source_chain,
+        message: Message::InterchainTransfer(InterchainTransfer {
+            token_id: token_id.clone(),
+            source_address: Address::generate(&env).to_string_bytes(),
+            destination_address: recipient.to_string_bytes(),
+            amount,
+            data: None,
+        }),
+    }
+    .abi_encode(&env)
+    .unwrap();
+    let source_chain = client.its_hub_chain_name();
+    let source_address = client.its_hub_address();
+    let message_id = String::from_str(&env, "test");
+
+    let messages = vec![
+        &env,
+        GatewayMessage {
+            source_chain: source_chain.clone(),
+            message_id: message_id.clone(),
+            source_address: source_address.clone(),
+            contract_address: client.address.clone(),
+            payload_hash: env.crypto().keccak256(&payload).into(),
+        },
+    ];
+
+    approve_gateway_messages(&env, &gateway_client, signers, messages);
+
+    assert_contract_err!(
+        client.try_execute(&source_chain, &message_id, &source_address, &payload),
+        ContractError::TokenPaused
+    );
+
+    client.mock_all_auths().unpause_token(&token_id);
+
+    client.execute(&source_chain, &message_id, &source_address, &payload);
+
+    let token = TokenClient::new(&env, &client.registered_token_address(&token_id));
+    assert_eq!(token.balance(&recipient), amount);
+}
@@ -42,6 +42,7 @@ fn transfer_token_admin_succeeds_with_mint_burn_token_manager_type() {
         &destination_token_address,
         &token_manager_type,
         &None::<Bytes>,
+        &None,
         &Some(gas_token),
     );
 
@@ -1,11 +1,81 @@
+use stellar_axelar_gateway::testutils::{approve_gateway_messages, TestSignerSet};
+use stellar_axelar_gateway::types::Message as GatewayMessage;
+use stellar_axelar_gateway::AxelarGatewayClient;
+use stellar_axelar_std::address::AddressExt;
 use stellar_axelar_std::testutils::Address as _;
+use stellar_axelar_std::traits::BytesExt;
 use stellar_axelar_std::{
-    assert_auth, assert_auth_err, assert_contract_err, events, Address, String,
+    assert_auth, assert_auth_err, assert_contract_err, events, vec, Address, Bytes, BytesN, Env,
+    String,
 };
 
 use super::utils::setup_env;
 use crate::error::ContractError;
-use crate::event::{TrustedChainRemovedEvent, TrustedChainSetEvent};
+use crate::event::{
+    MaxTransferAmountSetEvent, TrustedChainConfigSetEvent, TrustedChainRemovedEvent,
+    TrustedChainSetEvent,
+};
+use crate::testutils::setup_its_token;
+use crate::types::{
+    HubMessage, InterchainTransfer, Message, TrustedChainConfig, TrustedChainStatus,
+};
+use crate::InterchainTokenServiceClient;
+
+const TRANSFER_AMOUNT: i128 = 1000;
+
+struct InboundTransferTestData {
+    token_id: BytesN<32>,
+    source_chain: String,
+    message_id: String,
+    source_address: String,
+    payload: Bytes,
+}
+
+fn setup_inbound_transfer(
+    env: &Env,
+    client: &InterchainTokenServiceClient,
+    gateway_client: &AxelarGatewayClient,
+    signers: TestSignerSet,
+    original_source_chain: &String,
+) -> InboundTransferTestData {
+    let (token_id, _) = setup_its_token(env, client, &Address::generate(env), 0);
+
+    let payload = HubMessage::ReceiveFromHub {
+        source_chain: original_source_chain.clone(),
+        message: Message::InterchainTransfer(InterchainTransfer {
+            token_id: token_id.clone(),
+            source_address: Address::generate(env).to_string_bytes(),
+            destination_address: Address::generate(env).to_string_bytes(),
+            amount: TRANSFER_AMOUNT,
+            data: None,
+        }),
+    }
+    .abi_encode(env)
+    .unwrap();
+
+    let data = InboundTransferTestData {
+        token_id,
+        source_chain: client.its_hub_chain_name(),
+        message_id: String::from_str(env, "test"),
+        source_address: client.its_hub_address(),
+        payload,
+    };
+
+    let messages = vec![
+        env,
+        GatewayMessage {
+            source_chain: data.source_chain.clone(),
+            message_id: data.message_id.clone(),
+            source_address: data.source_address.clone(),
+            contract_address: client.address.clone(),
+            payload_hash: env.crypto().keccak256(&data.payload).into(),
+        },
+    ];
+
+    approve_gateway_messages(env, gateway_client, signers, messages);
+
+    data
+}
 
 #[test]
 fn set_trusted_address() {
@@ -85,3 +155,358 @@ fn remove_trusted_chain_fails_if_not_set() {
         ContractError::TrustedChainNotSet
     );
 }
+
+#[test]
+fn trusted_chain_config_defaults_to_active() {
+    let (env, client, _, _, _) = setup_env();
+    let chain = String::from_str(&env, "chain");
+
+    client.mock_all_auths().set_trusted_chain(&chain);
+
+    assert_eq!(
+        client.trusted_chain_config(&chain),
+        TrustedChainConfig {
+            status: TrustedChainStatus::Active,
+        }
+    );
+}
+
+#[test]
+fn trusted_chain_config_fails_if_not_set() {
+    let (env, client, _, _, _) = setup_env();
+
+    assert_contract_err!(
+        client.try_trusted_chain_config(&String::from_str(&env, "chain")),
+        ContractError::TrustedChainNotSet
+    );
+}
+
+#[test]
+fn set_trusted_chain_config_succeeds() {
+    let (env, client, _, _, _) = setup_env();
+    let chain = String::from_str(&env, "chain");
+    let config = TrustedChainConfig {
+        status: TrustedChainStatus::InboundOnly,
+    };
+
+    client.mock_all_auths().set_trusted_chain(&chain);
+
+    assert_auth!(
+        client.operator(),
+        client.set_trusted_chain_config(&chain, &config)
+    );
+
+    goldie::assert!(events::fmt_last_emitted_event::<TrustedChainConfigSetEvent>(&env));
+
+    assert_eq!(client.trusted_chain_config(&chain), config);
+    assert!(client.is_trusted_chain(&chain));
+}
+
+#[test]
+fn set_trusted_chain_config_fails_if_not_operator() {
+    let (env, client, _, _, _) = setup_env();
+    let chain = String::from_str(&env, "chain");
+    let config = TrustedChainConfig {
+        status: TrustedChainStatus::Paused,
+    };
+
+    client.mock_all_auths().set_trusted_chain(&chain);
+
+    assert_auth_err!(
+        Address::generate(&env),
+        client.set_trusted_chain_config(&chain, &config)
+    );
+}
+
+#[test]
+fn set_trusted_chain_config_fails_if_not_set() {
+    let (env, client, _, _, _) = setup_env();
+
+    assert_contract_err!(
+        client.mock_all_auths().try_set_trusted_chain_config(
+            &String::from_str(&env, "chain"),
+            &TrustedChainConfig {
+                status: TrustedChainStatus::Paused,
+            }
+        ),
+        ContractError::TrustedChainNotSet
+    );
+}
+
+#[test]
+fn remove_trusted_chain_retains_config() {
+    let (env, client, _, _, _) = setup_env();
+    let chain = String::from_str(&env, "chain");
+    let config = TrustedChainConfig {
+        status: TrustedChainStatus::Paused,
+    };
+
+    client.mock_all_auths().set_trusted_chain(&chain);
+    client
+        .mock_all_auths()
+        .set_trusted_chain_config(&chain, &config);
+    client.mock_all_auths().remove_trusted_chain(&chain);
+
+    assert!(!client.is_trusted_chain(&chain));
+    assert_eq!(client.trusted_chain_config(&chain), config);
+
+    client.mock_all_auths().set_trusted_chain(&chain);
+
+    assert_eq!(client.trusted_chain_config(&chain), config);
+}
+
+#[test]
+fn remove_trusted_chain_retains_default_config() {
+    let (env, client, _, _, _) = setup_env();
+    let chain = String::from_str(&env, "chain");
+
+    client.mock_all_auths().set_trusted_chain(&chain);
+    client.mock_all_auths().remove_trusted_chain(&chain);
+
+    assert_eq!(
+        client.trusted_chain_config(&chain),
+        TrustedChainConfig {
+            status: TrustedChainStatus::Active,
+        }
+    );
+}
+
+#[test]
+fn interchain_transfer_fails_if_untrusted_chain_was_previously_trusted() {
+    let (env, client, _, _, _) = setup_env();
+    let sender = Address::generate(&env);
+    let (token_id, _) = setup_its_token(&env, &client, &sender, TRANSFER_AMOUNT);
+    let destination_chain = String::from_str(&env, "ethereum");
+
+    client
+        .mock_all_auths()
+        .set_trusted_chain(&destination_chain);
+    client
+        .mock_all_auths()
+        .remove_trusted_chain(&destination_chain);
+
+    assert_contract_err!(
+        client.mock_all_auths().try_interchain_transfer(
+            &sender,
+            &token_id,
+            &destination_chain,
+            &Bytes::from_hex(&env, "4F4495243837681061C4743b74B3eEdf548D56A5"),
+            &TRANSFER_AMOUNT,
+            &None,
+            &None
+        ),
+        ContractError::UntrustedChain
+    );
+}
+
+#[test]
+fn set_max_transfer_amount_succeeds() {
+    let (env, client, _, _, _) = setup_env();
+    let (token_id, _) = setup_its_token(&env, &client, &Address::generate(&env), 0);
+    let chain = String::from_str(&env, "chain");
+
+    assert_eq!(client.max_transfer_amount(&token_id, &chain), None);
+
+    assert_auth!(
+        client.operator(),
+        client.set_max_transfer_amount(&token_id, &chain, &Some(TRANSFER_AMOUNT))
+    );
+
+    goldie::assert!(events::fmt_last_emitted_event::<MaxTransferAmountSetEvent>(
+        &env
+    ));
+
+    assert_eq!(
+        client.max_transfer_amount(&token_id, &chain),
+        Some(TRANSFER_AMOUNT)
+    );
+
+    client
+        .mock_all_auths()
+        .set_max_transfer_amount(&token_id, &chain, &None);
+
+    assert_eq!(client.max_transfer_amount(&token_id, &chain), None);
+}
+
+#[test]
+fn set_max_transfer_amount_fails_if_not_operator() {
+    let (env, client, _, _, _) = setup_env();
+    let (token_id, _) = setup_its_token(&env, &client, &Address::generate(&env), 0);
+
+    assert_auth_err!(
+        Address::generate(&env),
+        client.set_max_transfer_amount(
+            &token_id,
+            &String::from_str(&env, "chain"),
+            &Some(TRANSFER_AMOUNT)
+        )
+    );
+}
+
+#[test]
+fn set_max_transfer_amount_fails_with_invalid_amount() {
+    let (env, client, _, _, _) = setup_env();
+    let (token_id, _) = setup_its_token(&env, &client, &Address::generate(&env), 0);
+
+    assert_contract_err!(
+        client.mock_all_auths().try_set_max_transfer_amount(
+            &token_id,
+            &String::from_str(&env, "chain"),
+            &Some(0)
+        ),
+        ContractError::InvalidAmount
+    );
+}
+
+#[test]
+fn interchain_transfer_fails_if_outbound_disabled() {
+    let (env, client, _, _, _) = setup_env();
+    let sender = Address::generate(&env);
+    let (token_id, _) = setup_its_token(&env, &client, &sender, TRANSFER_AMOUNT);
+    let destination_chain = String::from_str(&env, "ethereum");
+
+    client
+        .mock_all_auths()
+        .set_trusted_chain(&destination_chain);
+
+    for status in [TrustedChainStatus::InboundOnly, TrustedChainStatus::Paused] {
+        client
+            .mock_all_auths()
+            .set_trusted_chain_config(&destination_chain, &TrustedChainConfig { status });
+
+        assert_contract_err!(
+            client.mock_all_auths().try_interchain_transfer(
+                &sender,
+                &token_id,
+                &destination_chain,
+                &Bytes::from_hex(&env, "4F4495243837681061C4743b74B3eEdf548D56A5"),
+                &TRANSFER_AMOUNT,
+                &None,
+                &None
+            ),
+            ContractError::TrustedChainOutboundDisabled
+        );
+    }
+}
+
+#[test]
+fn interchain_transfer_fails_if_amount_exceeds_chain_limit() {
+    let (env, client, _, _, _) = setup_env();
+    let sender = Address::generate(&env);
+    let (token_id, _) = setup_its_token(&env, &client, &sender, TRANSFER_AMOUNT);
+    let destination_chain = String::from_str(&env, "ethereum");
+    let destination_address = Bytes::from_hex(&env, "4F4495243837681061C4743b74B3eEdf548D56A5");
+
+    client
+        .mock_all_auths()
+        .set_trusted_chain(&destination_chain);
+    client.mock_all_auths().set_max_transfer_amount(
+        &token_id,
+        &destination_chain,
+        &Some(TRANSFER_AMOUNT - 1),
+    );
+
+    assert_contract_err!(
+        client.mock_all_auths().try_interchain_transfer(
+            &sender,
+            &token_id,
+            &destination_chain,
+            &destination_address,
+            &TRANSFER_AMOUNT,
+            &None,
+            &None
+        ),
+        ContractError::TransferAmountExceedsChainLimit
+    );
+
+    client.mock_all_auths().interchain_transfer(
+        &sender,
+        &token_id,
+        &destination_chain,
+        &destination_address,
+        &(TRANSFER_AMOUNT - 1),
+        &None,
+        &None,
+    );
+}
+
+#[test]
+fn execute_fails_if_inbound_disabled() {
+    let (env, client, gateway_client, _, signers) = setup_env();
+    let original_source_chain = String::from_str(&env, "ethereum");
+
+    client
+        .mock_all_auths()
+        .set_trusted_chain(&original_source_chain);
+    client.mock_all_auths().set_trusted_chain_config(
+        &original_source_chain,
+        &TrustedChainConfig {
+            status: TrustedChainStatus::OutboundOnly,
+        },
+    );
+
+    let data = setup_inbound_transfer(
+        &env,
+        &client,
+        &gateway_client,
+        signers,
+        &original_source_chain,
+    );
+
+    assert_contract_err!(
+        client.try_execute(
+            &data.source_chain,
+            &data.message_id,
+            &data.source_address,
+            &data.payload
+        ),
+        ContractError::TrustedChainInboundDisabled
+    );
+}
+
+#[test]
+fn execute_fails_if_amount_exceeds_chain_limit() {
+    let (env, client, gateway_client, _, signers) = setup_env();
+    let original_source_chain = String::from_str(&env, "ethereum");
+
+    client
+        .mock_all_auths()
+        .set_trusted_chain(&original_source_chain);
+
+    let data = setup_inbound_transfer(
+        &env,
+        &client,
+        &gateway_client,
+        signers,
+        &original_source_chain,
+    );
+
+    client.mock_all_auths().set_max_transfer_amount(
+        &data.token_id,
+        &original_source_chain,
+        &Some(TRANSFER_AMOUNT - 1),
+    );
+
+    assert_contract_err!(
+        client.try_execute(
+            &data.source_chain,
+            &data.message_id,
+            &data.source_address,
+            &data.payload
+        ),
+        ContractError::TransferAmountExceedsChainLimit
+    );
+
+    client.mock_all_auths().set_max_transfer_amount(
+        &data.token_id,
+        &original_source_chain,
+        &Some(TRANSFER_AMOUNT),
+    );
+
+    client.execute(
+        &data.source_chain,
+        &data.message_id,
+        &data.source_address,
+        &data.payload,
+    );
+}
@@ -0,0 +1,302 @@
+use soroban_token_sdk::metadata::TokenMetadata;
+use stellar_axelar_gas_service::testutils::setup_gas_token;
+use stellar_axelar_gateway::testutils::{approve_gateway_messages, TestSignerSet};
+use stellar_axelar_gateway::types::Message as GatewayMessage;
+use stellar_axelar_gateway::AxelarGatewayClient;
+use stellar_axelar_std::address::AddressExt;
+use stellar_axelar_std::testutils::Address as _;
+use stellar_axelar_std::{assert_contract_err, events, vec, Address, Bytes, BytesN, Env, String};
+use stellar_interchain_token::{InterchainToken, InterchainTokenClient};
+
+use super::utils::setup_env;
+use crate::error::ContractError;
+use crate::event::{TokenMetadataUpdateReceivedEvent, TokenMetadataUpdateStartedEvent};
+use crate::testutils::setup_its_token;
+use crate::types::{DeployInterchainToken, HubMessage, Message, UpdateTokenMetadata};
+use crate::InterchainTokenServiceClient;
+
+const DESTINATION_CHAIN: &str = "ethereum";
+
+/// The pinned v1.0.0 token wasm predates metadata updates, so the current contract is registered natively in its place
+fn upgrade_token(env: &Env, client: &InterchainTokenServiceClient, token_id: &BytesN<32>) {
+    let token_address = client.registered_token_address(token_id);
+    let token = InterchainTokenClient::new(env, &token_address);
+    let metadata = TokenMetadata {
+        name: token.name(),
+        symbol: token.symbol(),
+        decimal: token.decimals(),
+    };
+
+    env.register_at(
+        &token_address,
+        InterchainToken,
+        (
+            client.address.clone(),
+            None::<Address>,
+            token_id.clone(),
+            metadata,
+        ),
+    );
+}
+
+/// Approves the `message` as received from the ITS Hub, and returns its message ID and payload.
+fn approve_hub_message(
+    env: &Env,
+    client: &InterchainTokenServiceClient,
+    gateway_client: &AxelarGatewayClient,
+    signers: &TestSignerSet,
+    message_id: &str,
+    message: Message,
+) -> (String, Bytes) {
+    let message_id = String::from_str(env, message_id);
+    let payload = HubMessage::ReceiveFromHub {
+        source_chain: String::from_str(env, DESTINATION_CHAIN),
+        message,
+    }
+    .abi_encode(env)
+    .unwrap();
+
+    approve_gateway_messages(
+        env,
+        gateway_client,
+        signers.clone(),
+        vec![
+            env,
+            GatewayMessage {
+                source_chain: client.its_hub_chain_name(),
+                message_id: message_id.clone(),
+                source_address: client.its_hub_address(),
+                contract_address: client.address.clone(),
+                payload_hash: env.crypto().keccak256(&payload).into(),
+            },
+        ],
+    );
+
+    (message_id, payload)
+}
+
+#[test]
+fn update_token_metadata_succeeds() {
+    let (env, client, _, gas_service, _) = setup_env();
+    let minter = Address::generate(&env);
+    let (token_id, _) = setup_its_token(&env, &client, &minter, 100);
+    let gas_token = setup_gas_token(&env, &minter);
+    let name = String::from_str(&env, "Updated");
+    let symbol = String::from_str(&env, "UPD");
+
+    upgrade_token(&env, &client, &token_id);
+    client
+        .mock_all_auths()
+        .set_trusted_chain(&String::from_str(&env, DESTINATION_CHAIN));
+
+    client.mock_all_auths().update_token_metadata(
+        &minter,
+        &token_id,
+        &name,
+        &symbol,
+        &vec![
+            &env,
+            (
+                String::from_str(&env, DESTINATION_CHAIN),
+                Some(gas_token.clone()),
+            ),
+        ],
+    );
+
+    goldie::assert!(events::fmt_emitted_event_at_idx::<
+        TokenMetadataUpdateStartedEvent,
+    >(&env, -4));
+
+    let token = InterchainTokenClient::new(&env, &client.registered_token_address(&token_id));
+    assert_eq!(token.name(), name);
+    assert_eq!(token.symbol(), symbol);
+    assert_eq!(
+        gas_token.client(&env).balance(&gas_service.address),
+        gas_token.amount
+    );
+}
+
+#[test]
+fn update_token_metadata_fails_if_not_minter() {
+    let (env, client, _, _, _) = setup_env();
+    let (token_id, _) = setup_its_token(&env, &client, &Address::generate(&env), 100);
+
+    assert_contract_err!(
+        client.mock_all_auths().try_update_token_metadata(
+            &Address::generate(&env),
+            &token_id,
+            &String::from_str(&env, "Updated"),
+            &String::from_str(&env, "UPD"),
+            &vec![&env],
+        ),
+        ContractError::NotMinter
+    );
+}
+
+#[test]
+fn update_token_metadata_fails_with_invalid_metadata() {
+    let (env, client, _, _, _) = setup_env();
+    let minter = Address::generate(&env);
+    let (token_id, _) = setup_its_token(&env, &client, &minter, 100);
+
+    upgrade_token(&env, &client, &token_id);
+
+    assert_contract_err!(
+        client.mock_all_auths().try_update_token_metadata(
+            &minter,
+            &token_id,
+            &String::from_str(&env, ""),
+            &String::from_str(&env, "UPD"),
+            &vec![&env],
+        ),
+        ContractError::InvalidTokenName
+    );
+}
+
+#[test]
+fn update_token_metadata_fails_with_untrusted_chain() {
+    let (env, client, _, _, _) = setup_env();
+    let minter = Address::generate(&env);
+    let (token_id, _) = setup_its_token(&env, &client, &minter, 100);
+
+    assert_contract_err!(
+        client.mock_all_auths().try_update_token_metadata(
+            &minter,
+            &token_id,
+            &String::from_str(&env, "Updated"),
+            &String::from_str(&env, "UPD"),
+            &vec![&env, (String::from_str(&env, DESTINATION_CHAIN), None)],
+        ),
+        ContractError::UntrustedChain
+    );
+}
+
+#[test]
+fn update_token_metadata_message_execute_succeeds() {
+    let (env, client, gateway_client, _, signers) = setup_env();
+    let token_id = BytesN::from_array(&env, &[1u8; 32]);
+    let name = String::from_str(&env, "Updated");
+    let symbol = String::from_str(&env, "UPD");
+
+    client
+        .mock_all_auths()
+        .set_trusted_chain(&String::from_str(&env, DESTINATION_CHAIN));
+
+    let (message_id, payload) = approve_hub_message(
+        &env,
+        &client,
+        &gateway_client,
+        &signers,
+        "deploy",
+        Message::DeployInterchainToken(DeployInterchainToken {
+            token_id: token_id.clone(),
+            name: String::from_str(&env, "Test"),
+            symbol: String::from_str(&env, "TEST"),
+            decimals: 18,
+            minter: Some(Address::generate(&env).to_string_bytes()),
+        }),
+    );
+    client.execute(
+        &client.its_hub_chain_name(),
+        &message_id,
+        &client.its_hub_address(),
+        &payload,
+    );
+    upgrade_token(&env, &client, &token_id);
+
+    let (message_id, payload) = approve_hub_message(
+        &env,
+        &client,
+        &gateway_client,
+        &signers,
+        "update",
+        Message::UpdateTokenMetadata(UpdateTokenMetadata {
+            token_id: token_id.clone(),
+            name: name.clone(),
+            symbol: symbol.clone(),
+        }),
+    );
+    client.execute(
+        &client.its_hub_chain_name(),
+        &message_id,
+        &client.its_hub_address(),
+        &payload,
+    );
+
+    goldie::assert!(events::fmt_last_emitted_event::<
+        TokenMetadataUpdateReceivedEvent,
+    >(&env));
+
+    let token = InterchainTokenClient::new(&env, &client.registered_token_address(&token_id));
+    assert_eq!(token.name(), name);
+    assert_eq!(token.symbol(), symbol);
+}
+
+#[test]
+fn update_token_metadata_message_execute_fails_for_local_token() {
+    let (env, client, gateway_client, _, signers) = setup_env();
+    let (token_id, _) = setup_its_token(&env, &client, &Address::generate(&env), 100);
+
+    client
+        .mock_all_auths()
+        .set_trusted_chain(&String::from_str(&env, DESTINATION_CHAIN));
+
+    let (message_id, payload) = approve_hub_message(
+        &env,
+        &client,
+        &gateway_client,
+        &signers,
+        "update",
+        Message::UpdateTokenMetadata(UpdateTokenMetadata {
+            token_id,
+            name: String::from_str(&env, "Updated"),
+            symbol: String::from_str(&env, "UPD"),
+        }),
+    );
+
+    assert_contract_err!(
+        client.try_execute(
+            &client.its_hub_chain_name(),
+            &message_id,
+            &client.its_hub_address(),
+            &payload
+        ),
+        ContractError::TokenOriginatesLocally
+    );
+}
+
+#[test]
+fn update_token_metadata_message_execute_fails_for_custom_token() {
+    let (env, client, gateway_client, _, signers) = setup_env();
+    let token_address = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    let token_id = client.register_canonical_token(&token_address);
+
+    client
+        .mock_all_auths()
+        .set_trusted_chain(&String::from_str(&env, DESTINATION_CHAIN));
+
+    let (message_id, payload) = approve_hub_message(
+        &env,
+        &client,
+        &gateway_client,
+        &signers,
+        "update",
+        Message::UpdateTokenMetadata(UpdateTokenMetadata {
+            token_id,
+            name: String::from_str(&env, "Updated"),
+            symbol: String::from_str(&env, "UPD"),
+        }),
+    );
+
+    assert_contract_err!(
+        client.try_execute(
+            &client.its_hub_chain_name(),
+            &message_id,
+            &client.its_hub_address(),
+            &payload
+        ),
+        ContractError::InvalidTokenManagerType
+    );
+}
@@ -1,13 +1,20 @@
 use soroban_token_sdk::metadata::TokenMetadata;
-use stellar_axelar_gas_service::testutils::setup_gas_service;
+use stellar_axelar_gas_service::testutils::{setup_gas_service, setup_gas_token};
 use stellar_axelar_gas_service::AxelarGasServiceClient;
-use stellar_axelar_gateway::testutils::{setup_gateway, TestSignerSet};
+use stellar_axelar_gateway::testutils::{approve_gateway_messages, setup_gateway, TestSignerSet};
+use stellar_axelar_gateway::types::Message as GatewayMessage;
 use stellar_axelar_gateway::AxelarGatewayClient;
-use stellar_axelar_std::{Env, IntoVal};
+use stellar_axelar_std::address::AddressExt;
+use stellar_axelar_std::testutils::Address as _;
+use stellar_axelar_std::traits::BytesExt;
+use stellar_axelar_std::{vec, Address, Bytes, BytesN, Env, IntoVal, String};
 
 use crate::testutils::setup_its;
+use crate::types::{HubMessage, InterchainTransfer, Message, TokenManagerType};
 use crate::InterchainTokenServiceClient;
 
+pub const DESTINATION_CHAIN: &str = "ethereum";
+
 pub const INTERCHAIN_TOKEN_DEPLOYED_EVENT_IDX: i32 = -4;
 pub const INTERCHAIN_TOKEN_DEPLOYED_WITHOUT_GAS_TOKEN_EVENT_IDX: i32 = -2;
 pub const INTERCHAIN_TOKEN_DEPLOYED_NO_SUPPLY_EVENT_IDX: i32 =
@@ -44,3 +51,117 @@ pub fn setup_env<'a>() -> (
 
     (env, client, gateway_client, gas_service_client, signers)
 }
+
+/// An interchain transfer received from the ITS Hub.
+pub struct HubTransferTestData {
+    pub source_chain: String,
+    pub message_id: String,
+    pub source_address: String,
+    pub payload: Bytes,
+    pub token_id: BytesN<32>,
+    pub destination_address: Address,
+    pub amount: i128,
+}
+
+/// Sets up an interchain transfer of `amount` tokens to a new destination address,
+/// received from the ITS Hub and originating from a trusted source chain.
+pub fn setup_hub_transfer(
+    env: &Env,
+    client: &InterchainTokenServiceClient,
+    token_id: BytesN<32>,
+    amount: i128,
+    data: Option<Bytes>,
+) -> HubTransferTestData {
+    let original_source_chain = String::from_str(env, "ethereum");
+    client
+        .mock_all_auths()
+        .set_trusted_chain(&original_source_chain);
+
+    let destination_address = Address::generate(env);
+
+    let payload = HubMessage::ReceiveFromHub {
+        source_chain: original_source_chain,
+        message: Message::InterchainTransfer(InterchainTransfer {
+            token_id: token_id.clone(),
+            source_address: Bytes::from_array(env, &[2; 20]),
+            destination_address: destination_address.to_string_bytes(),
+            amount,
+            data,
+        }),
+    }
+    .abi_encode(env)
+    .unwrap();
+
+    HubTransferTestData {
+        source_chain: client.its_hub_chain_name(),
+        message_id: String::from_str(env, "test"),
+        source_address: client.its_hub_address(),
+        payload,
+        token_id,
+        destination_address,
+        amount,
+    }
+}
+
+/// Approves the interchain transfer on the gateway, so that it can be executed by ITS.
+pub fn approve_hub_transfer(
+    env: &Env,
+    client: &InterchainTokenServiceClient,
+    gateway_client: &AxelarGatewayClient,
+    signers: TestSignerSet,
+    data: &HubTransferTestData,
+) {
+    let messages = vec![
+        env,
+        GatewayMessage {
+            source_chain: data.source_chain.clone(),
+            message_id: data.message_id.clone(),
+            source_address: data.source_address.clone(),
+            contract_address: client.address.clone(),
+            payload_hash: env.crypto().keccak256(&data.payload).into(),
+        },
+    ];
+
+    approve_gateway_messages(env, gateway_client, signers, messages);
+}
+
+/// Registers a new Stellar asset contract, administered by a random address.
+pub fn register_stellar_asset(env: &Env) -> Address {
+    env.register_stellar_asset_contract_v2(Address::generate(env))
+        .address()
+}
+
+/// Registers the `token_address` as a custom token of the `deployer`.
+pub fn register_custom_token(
+    env: &Env,
+    client: &InterchainTokenServiceClient,
+    deployer: &Address,
+    token_address: &Address,
+    token_manager_type: TokenManagerType,
+) -> BytesN<32> {
+    client.mock_all_auths().register_custom_token(
+        deployer,
+        &BytesN::<32>::from_array(env, &[1; 32]),
+        token_address,
+        &token_manager_type,
+    )
+}
+
+/// Sends `amount` tokens from the `sender` to [`DESTINATION_CHAIN`], which must be trusted.
+pub fn interchain_transfer(
+    env: &Env,
+    client: &InterchainTokenServiceClient,
+    sender: &Address,
+    token_id: &BytesN<32>,
+    amount: i128,
+) {
+    client.mock_all_auths().interchain_transfer(
+        sender,
+        token_id,
+        &String::from_str(env, DESTINATION_CHAIN),
+        &Bytes::from_hex(env, "4F4495243837681061C4743b74B3eEdf548D56A5"),
+        &amount,
+        &None,
+        &Some(setup_gas_token(env, sender)),
+    );
+}
@@ -1,16 +1,17 @@
 use stellar_axelar_std::token::TokenClient;
-use stellar_axelar_std::{Address, Env};
+use stellar_axelar_std::{Address, BytesN, Env, MuxedAddress};
 use stellar_interchain_token::InterchainTokenClient;
 use stellar_token_manager::TokenManagerClient;
 
 use crate::error::ContractError;
-use crate::storage::TokenIdConfigValue;
+use crate::storage::{self, TokenIdConfigValue};
 use crate::token_manager::TokenManagerClientExt;
 use crate::types::TokenManagerType;
 
 pub fn take_token(
     env: &Env,
     sender: &Address,
+    token_id: BytesN<32>,
     TokenIdConfigValue {
         token_address,
         token_manager,
@@ -34,15 +35,25 @@ pub fn take_token(
         TokenManagerType::MintBurnFrom => token.burn(sender, &amount),
 
         // Transfer tokens from the sender to the token manager to lock them
-        TokenManagerType::LockUnlock => token.transfer(sender, &token_manager, &amount),
+        TokenManagerType::LockUnlock => {
+            token.transfer(sender, &token_manager, &amount);
+
+            let locked_amount = locked_amount(env, token_id.clone()).saturating_add(amount);
+            storage::set_locked_amount(env, token_id, &locked_amount);
+        }
     }
 
     Ok(())
 }
 
+/// Gives `amount` tokens to the `recipient`.
+///
+/// Tokens that are minted are credited to the account underlying a muxed `recipient`,
+/// while unlocked tokens are transferred to the muxed address itself so the token can attribute them.
 pub fn give_token(
     env: &Env,
-    recipient: &Address,
+    recipient: &MuxedAddress,
+    token_id: BytesN<32>,
     TokenIdConfigValue {
         token_address,
         token_manager,
@@ -56,22 +67,36 @@ pub fn give_token(
         // For NativeInterchainToken and MintBurnFrom,
         // `mint_from` interface allows the token to potentially have multiple minters, one of them being the token manager to mint tokens for ITS
         TokenManagerType::NativeInterchainToken | TokenManagerType::MintBurnFrom => {
-            token_manager.mint_from(env, &token_address, recipient, amount)
+            token_manager.mint_from(env, &token_address, &recipient.address(), amount)
         }
 
         // Transfer previously locked tokens from the token manager to the recipient
+        // Tokens held by the token manager without being locked through ITS can still be unlocked,
+        // so the locked amount doesn't drop below zero
         TokenManagerType::LockUnlock => {
-            token_manager.transfer(env, &token_address, recipient, amount)
+            token_manager.transfer(env, &token_address, recipient, amount);
+
+            let locked_amount = locked_amount(env, token_id.clone())
+                .saturating_sub(amount)
+                .max(0);
+            storage::set_locked_amount(env, token_id, &locked_amount);
         }
 
         // For MintBurn, use direct mint where the token manager mints new tokens
         // This assumes the token manager has minting or admin privileges on the token contract, since `mint` interface doesn't indicate who the caller is
-        TokenManagerType::MintBurn => token_manager.mint(env, &token_address, recipient, amount),
+        TokenManagerType::MintBurn => {
+            token_manager.mint(env, &token_address, &recipient.address(), amount)
+        }
     }
 
     Ok(())
 }
 
+/// Returns the net amount of tokens locked in the token manager of a `LockUnlock` token by interchain transfers.
+pub fn locked_amount(env: &Env, token_id: BytesN<32>) -> i128 {
+    storage::try_locked_amount(env, token_id).unwrap_or(0)
+}
+
 /// Prepares a token manager after it is deployed.
 /// This function handles the post-deployment setup based on the token manager type.
 ///
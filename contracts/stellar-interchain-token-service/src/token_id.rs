@@ -1,6 +1,8 @@
+use alloy_primitives::{Address as EvmAddress, FixedBytes};
+use alloy_sol_types::SolValue;
 use stellar_axelar_std::address::AddressExt;
 use stellar_axelar_std::xdr::ToXdr;
-use stellar_axelar_std::{Address, BytesN, Env};
+use stellar_axelar_std::{Address, Bytes, BytesN, Env};
 
 use crate::error::ContractError;
 use crate::storage;
@@ -110,6 +112,27 @@ pub fn interchain_token_id(
     )
 }
 
+/// Computes the token ID of a token deployed by the interchain token factory with the factory-scoped `deploy_salt`.
+///
+/// Matches `interchainTokenId(TOKEN_FACTORY_DEPLOYER, deploySalt)` of the EVM `InterchainTokenService`,
+/// i.e. the `abi.encode`d hashed prefix, the zero address as the factory deployer, and the deploy salt.
+pub fn factory_interchain_token_id(env: &Env, deploy_salt: BytesN<32>) -> BytesN<32> {
+    let prefix_hash = env
+        .crypto()
+        .keccak256(&Bytes::from_slice(env, PREFIX_TOKEN_ID.as_bytes()));
+
+    let encoded = (
+        FixedBytes::<32>::new(prefix_hash.to_array()),
+        EvmAddress::ZERO,
+        FixedBytes::<32>::new(deploy_salt.to_array()),
+    )
+        .abi_encode_params();
+
+    env.crypto()
+        .keccak256(&Bytes::from_slice(env, &encoded))
+        .into()
+}
+
 pub fn ensure_token_not_registered(
     env: &Env,
     token_id: BytesN<32>,
@@ -118,6 +141,10 @@ pub fn ensure_token_not_registered(
         storage::try_token_id_config(env, token_id.clone()).is_none(),
         ContractError::TokenAlreadyRegistered
     );
+    stellar_axelar_std::ensure!(
+        !storage::is_token_deregistered(env, token_id.clone()),
+        ContractError::TokenDeregistered
+    );
 
     Ok(UnregisteredTokenId::new(token_id))
 }
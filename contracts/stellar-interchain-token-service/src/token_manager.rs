@@ -1,4 +1,4 @@
-use stellar_axelar_std::{vec, Address, Env, IntoVal, Symbol, Val};
+use stellar_axelar_std::{vec, Address, Env, IntoVal, MuxedAddress, Symbol, Val};
 use stellar_token_manager::TokenManagerClient;
 
 pub trait TokenManagerClientExt {
@@ -6,7 +6,10 @@ pub trait TokenManagerClientExt {
     fn set_admin(&self, env: &Env, token_address: &Address, new_admin: &Address);
 
     /// Transfer `amount` of tokens from the token manager to `recipient`.
-    fn transfer(&self, env: &Env, token_address: &Address, recipient: &Address, amount: i128);
+    fn transfer(&self, env: &Env, token_address: &Address, recipient: &MuxedAddress, amount: i128);
+
+    /// Burn `amount` of tokens held by the token manager.
+    fn burn(&self, env: &Env, token_address: &Address, amount: i128);
 
     /// Mint `amount` of tokens to `recipient`.
     fn mint(&self, env: &Env, token_address: &Address, recipient: &Address, amount: i128);
@@ -24,7 +27,7 @@ impl TokenManagerClientExt for TokenManagerClient<'_> {
         );
     }
 
-    fn transfer(&self, env: &Env, token_address: &Address, recipient: &Address, amount: i128) {
+    fn transfer(&self, env: &Env, token_address: &Address, recipient: &MuxedAddress, amount: i128) {
         let _: Val = self.execute(
             token_address,
             &Symbol::new(env, "transfer"),
@@ -37,6 +40,14 @@ impl TokenManagerClientExt for TokenManagerClient<'_> {
         );
     }
 
+    fn burn(&self, env: &Env, token_address: &Address, amount: i128) {
+        let _: Val = self.execute(
+            token_address,
+            &Symbol::new(env, "burn"),
+            &vec![env, self.address.to_val(), amount.into_val(env)],
+        );
+    }
+
     fn mint_from(&self, env: &Env, token_address: &Address, recipient: &Address, amount: i128) {
         let _: Val = self.execute(
             token_address,
@@ -3,6 +3,7 @@ use stellar_axelar_std::string::StringExt;
 use stellar_axelar_std::{ensure, token, Address, Env, String};
 
 use crate::error::ContractError;
+use crate::types::TokenMetadataOverride;
 
 const NATIVE_TOKEN_NAME: &str = "Stellar";
 const NATIVE_TOKEN_SYMBOL: &str = "XLM";
@@ -55,10 +56,7 @@ pub fn token_metadata(
     native_token_address: &Address,
 ) -> Result<TokenMetadata, ContractError> {
     let token = token::Client::new(env, token_address);
-    let decimals = token
-        .try_decimals()
-        .map_err(|_| ContractError::InvalidTokenAddress)?
-        .map_err(|_| ContractError::TokenInvocationError)?;
+    let decimals = token_decimals(env, token_address)?;
 
     if token_address == native_token_address {
         // Stellar's native token sets the name and symbol to 'native'. Override it to make it more readable
@@ -86,6 +84,30 @@ pub fn token_metadata(
     TokenMetadata::new(name, symbol, decimals)
 }
 
+/// Returns the metadata of the token, with the name and symbol replaced by the `metadata_override` if provided.
+///
+/// The decimals are always read from the token, since they determine how amounts are interpreted.
+pub fn token_metadata_with_override(
+    env: &Env,
+    token_address: &Address,
+    native_token_address: &Address,
+    metadata_override: Option<TokenMetadataOverride>,
+) -> Result<TokenMetadata, ContractError> {
+    match metadata_override {
+        Some(TokenMetadataOverride { name, symbol }) => {
+            TokenMetadata::new(name, symbol, token_decimals(env, token_address)?)
+        }
+        None => token_metadata(env, token_address, native_token_address),
+    }
+}
+
+fn token_decimals(env: &Env, token_address: &Address) -> Result<u32, ContractError> {
+    token::Client::new(env, token_address)
+        .try_decimals()
+        .map_err(|_| ContractError::InvalidTokenAddress)?
+        .map_err(|_| ContractError::TokenInvocationError)
+}
+
 #[cfg(test)]
 mod tests {
     use stellar_axelar_std::assert_ok;
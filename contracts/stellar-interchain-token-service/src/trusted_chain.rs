@@ -0,0 +1,136 @@
+use stellar_axelar_std::events::Event;
+use stellar_axelar_std::{ensure, BytesN, Env, String};
+
+use crate::error::ContractError;
+use crate::event::{MaxTransferAmountSetEvent, TrustedChainConfigSetEvent};
+use crate::storage;
+use crate::types::{Message, TrustedChainConfig, TrustedChainStatus};
+
+const DEFAULT_CONFIG: TrustedChainConfig = TrustedChainConfig {
+    status: TrustedChainStatus::Active,
+};
+
+/// Returns the config of the trusted `chain`, or the retained config of a previously trusted `chain`.
+pub fn trusted_chain_config(env: &Env, chain: String) -> Result<TrustedChainConfig, ContractError> {
+    storage::try_trusted_chain_config(env, chain.clone())
+        .or_else(|| storage::is_trusted_chain(env, chain).then_some(DEFAULT_CONFIG))
+        .ok_or(ContractError::TrustedChainNotSet)
+}
+
+pub fn set_trusted_chain_config(
+    env: &Env,
+    chain: String,
+    config: TrustedChainConfig,
+) -> Result<(), ContractError> {
+    ensure!(
+        storage::is_trusted_chain(env, chain.clone()),
+        ContractError::TrustedChainNotSet
+    );
+
+    storage::set_trusted_chain_config(env, chain.clone(), &config);
+
+    TrustedChainConfigSetEvent {
+        chain,
+        status: config.status,
+    }
+    .emit(env);
+
+    Ok(())
+}
+
+/// Stores the config of the trusted `chain`, so that it is retained when the chain is removed.
+pub fn retain_trusted_chain_config(env: &Env, chain: String) {
+    if storage::try_trusted_chain_config(env, chain.clone()).is_none() {
+        storage::set_trusted_chain_config(env, chain, &DEFAULT_CONFIG);
+    }
+}
+
+pub fn max_transfer_amount(env: &Env, token_id: BytesN<32>, chain: String) -> Option<i128> {
+    storage::try_max_transfer_amount(env, token_id, chain)
+}
+
+pub fn set_max_transfer_amount(
+    env: &Env,
+    token_id: BytesN<32>,
+    chain: String,
+    max_transfer_amount: Option<i128>,
+) -> Result<(), ContractError> {
+    match max_transfer_amount {
+        Some(amount) => {
+            ensure!(amount > 0, ContractError::InvalidAmount);
+
+            storage::set_max_transfer_amount(env, token_id.clone(), chain.clone(), &amount);
+        }
+        None => storage::remove_max_transfer_amount(env, token_id.clone(), chain.clone()),
+    }
+
+    MaxTransferAmountSetEvent {
+        token_id,
+        chain,
+        max_transfer_amount,
+    }
+    .emit(env);
+
+    Ok(())
+}
+
+/// Ensures that the `message` can be sent to the trusted `destination_chain`.
+pub fn ensure_outbound_allowed(
+    env: &Env,
+    destination_chain: String,
+    message: &Message,
+) -> Result<(), ContractError> {
+    ensure!(
+        storage::is_trusted_chain(env, destination_chain.clone()),
+        ContractError::UntrustedChain
+    );
+
+    ensure!(
+        trusted_chain_config(env, destination_chain.clone())?
+            .status
+            .allows_outbound(),
+        ContractError::TrustedChainOutboundDisabled
+    );
+
+    ensure_within_max_transfer_amount(env, destination_chain, message)
+}
+
+/// Ensures that the `message` can be received from the trusted `source_chain`.
+pub fn ensure_inbound_allowed(
+    env: &Env,
+    source_chain: String,
+    message: &Message,
+) -> Result<(), ContractError> {
+    ensure!(
+        storage::is_trusted_chain(env, source_chain.clone()),
+        ContractError::UntrustedChain
+    );
+
+    ensure!(
+        trusted_chain_config(env, source_chain.clone())?
+            .status
+            .allows_inbound(),
+        ContractError::TrustedChainInboundDisabled
+    );
+
+    ensure_within_max_transfer_amount(env, source_chain, message)
+}
+
+fn ensure_within_max_transfer_amount(
+    env: &Env,
+    chain: String,
+    message: &Message,
+) -> Result<(), ContractError> {
+    let Message::InterchainTransfer(transfer) = message else {
+        return Ok(());
+    };
+
+    if let Some(max_transfer_amount) = max_transfer_amount(env, transfer.token_id.clone(), chain) {
+        ensure!(
+            transfer.amount <= max_transfer_amount,
+            ContractError::TransferAmountExceedsChainLimit
+        );
+    }
+
+    Ok(())
+}
@@ -1,10 +1,13 @@
-use stellar_axelar_std::{contracttype, soroban_sdk, Bytes, BytesN, String};
+use soroban_token_sdk::metadata::TokenMetadata;
+use stellar_axelar_std::types::Token;
+use stellar_axelar_std::{contracttype, soroban_sdk, Address, Bytes, BytesN, String};
 
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub enum Message {
     InterchainTransfer(InterchainTransfer),
     DeployInterchainToken(DeployInterchainToken),
     LinkToken(LinkToken),
+    UpdateTokenMetadata(UpdateTokenMetadata),
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -40,6 +43,13 @@ pub struct LinkToken {
     pub params: Option<Bytes>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct UpdateTokenMetadata {
+    pub token_id: BytesN<32>,
+    pub name: String,
+    pub symbol: String,
+}
+
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub enum HubMessage {
     SendToHub {
@@ -67,3 +77,88 @@ pub enum TokenManagerType {
     // LockUnlockFee = 3,
     MintBurn = 4,
 }
+
+/// The name and symbol used instead of the token's own metadata when deploying a canonical token to other chains.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TokenMetadataOverride {
+    pub name: String,
+    pub symbol: String,
+}
+
+/// The aggregated state of a token registered with the interchain token service.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TokenInfo {
+    pub token_address: Address,
+    pub token_manager: Address,
+    pub token_manager_type: TokenManagerType,
+    pub metadata: TokenMetadata,
+    pub flow_limit: Option<i128>,
+    pub flow_in_amount: i128,
+    pub flow_out_amount: i128,
+}
+
+/// The locked amount of a `LockUnlock` token compared with the actual balance of its token manager.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LockedBalance {
+    /// The net amount of tokens locked in the token manager by interchain transfers.
+    pub locked_amount: i128,
+    /// The token balance of the token manager.
+    pub token_manager_balance: i128,
+    /// The token manager balance in excess of the locked amount, e.g. tokens sent to the token manager directly.
+    /// A negative value means the token manager holds less than the locked amount.
+    pub surplus: i128,
+}
+
+/// The direction of interchain traffic allowed for a trusted chain.
+#[contracttype]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TrustedChainStatus {
+    Active,
+    InboundOnly,
+    OutboundOnly,
+    Paused,
+}
+
+impl TrustedChainStatus {
+    pub const fn allows_inbound(self) -> bool {
+        matches!(self, Self::Active | Self::InboundOnly)
+    }
+
+    pub const fn allows_outbound(self) -> bool {
+        matches!(self, Self::Active | Self::OutboundOnly)
+    }
+}
+
+/// The settings applied to the interchain traffic with a trusted chain.
+///
+/// Trusted chains without explicit settings are [`TrustedChainStatus::Active`].
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TrustedChainConfig {
+    pub status: TrustedChainStatus,
+}
+
+/// The gas payment for the execution of a cross-chain message, forwarded to the gas service.
+///
+/// The `spender` pays the gas, and any gas refund issued by the gas service goes to the `refund_address`.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct GasPayment {
+    pub spender: Address,
+    pub token: Token,
+    pub metadata: Bytes,
+    pub refund_address: Address,
+}
+
+/// The recovery of an inbound interchain transfer that cannot be executed.
+#[contracttype]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TransferRecovery {
+    /// Gives the tokens to the recovery address registered by the destination address.
+    RecoveryAddress,
+    /// Sends the tokens back to the source address on the source chain.
+    ReturnToSender,
+}
@@ -3,6 +3,7 @@ use soroban_token_sdk::metadata::TokenMetadata;
 use soroban_token_sdk::TokenUtils;
 use stellar_axelar_std::events::Event;
 use stellar_axelar_std::interfaces::OwnableInterface;
+use stellar_axelar_std::string::StringExt;
 use stellar_axelar_std::token::StellarAssetInterface;
 use stellar_axelar_std::{
     assert_with_error, contract, contractimpl, ensure, interfaces, only_owner, soroban_sdk,
@@ -10,10 +11,13 @@ use stellar_axelar_std::{
 };
 
 use crate::error::ContractError;
-use crate::event::{MinterAddedEvent, MinterRemovedEvent, SetAdminEvent};
+use crate::event::{MetadataUpdatedEvent, MinterAddedEvent, MinterRemovedEvent, SetAdminEvent};
 use crate::interface::InterchainTokenInterface;
 use crate::storage::{self, AllowanceDataKey, AllowanceValue};
 
+const MAX_NAME_LENGTH: u32 = 32;
+const MAX_SYMBOL_LENGTH: u32 = 32;
+
 #[contract]
 #[derive(Upgradable)]
 pub struct InterchainToken;
@@ -238,6 +242,43 @@ impl InterchainTokenInterface for InterchainToken {
 
         MinterRemovedEvent { minter }.emit(env);
     }
+
+    fn update_metadata(
+        env: &Env,
+        caller: Address,
+        name: String,
+        symbol: String,
+    ) -> Result<(), ContractError> {
+        caller.require_auth();
+
+        ensure!(
+            caller == Self::owner(env) || Self::is_minter(env, caller),
+            ContractError::NotOwnerOrMinter
+        );
+
+        // Same limits as the metadata validated by the interchain token service on deployment
+        ensure!(
+            !name.is_empty() && name.len() <= MAX_NAME_LENGTH && name.is_ascii(),
+            ContractError::InvalidTokenName
+        );
+        ensure!(
+            !symbol.is_empty() && symbol.len() <= MAX_SYMBOL_LENGTH && symbol.is_ascii(),
+            ContractError::InvalidTokenSymbol
+        );
+
+        Self::write_metadata(
+            env,
+            TokenMetadata {
+                decimal: Self::decimals(env.clone()),
+                name: name.clone(),
+                symbol: symbol.clone(),
+            },
+        );
+
+        MetadataUpdatedEvent { name, symbol }.emit(env);
+
+        Ok(())
+    }
 }
 
 impl InterchainToken {
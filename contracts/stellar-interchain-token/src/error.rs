@@ -12,4 +12,7 @@ pub enum ContractError {
     InsufficientBalance = 6,
     MigrationInProgress = 7,
     MinterAlreadyExists = 8,
+    NotOwnerOrMinter = 9,
+    InvalidTokenName = 10,
+    InvalidTokenSymbol = 11,
 }
@@ -1,4 +1,4 @@
-use stellar_axelar_std::{Address, IntoEvent};
+use stellar_axelar_std::{Address, IntoEvent, String};
 
 #[derive(Debug, PartialEq, Eq, IntoEvent)]
 pub struct MinterAddedEvent {
@@ -10,6 +10,12 @@ pub struct MinterRemovedEvent {
     pub minter: Address,
 }
 
+#[derive(Debug, PartialEq, Eq, IntoEvent)]
+pub struct MetadataUpdatedEvent {
+    pub name: String,
+    pub symbol: String,
+}
+
 #[derive(Debug, PartialEq, Eq, IntoEvent)]
 pub struct SetAdminEvent {
     pub admin: Address,
@@ -1,6 +1,6 @@
 use stellar_axelar_std::interfaces::{OwnableInterface, UpgradableInterface};
 use stellar_axelar_std::token::StellarAssetInterface;
-use stellar_axelar_std::{contractclient, soroban_sdk, Address, BytesN, Env};
+use stellar_axelar_std::{contractclient, soroban_sdk, Address, BytesN, Env, String};
 
 use crate::error::ContractError;
 
@@ -51,4 +51,25 @@ pub trait InterchainTokenInterface:
     /// # Authorization
     /// - [`OwnableInterface::owner`] must authorize.
     fn remove_minter(env: &Env, minter: Address);
+
+    /// Updates the name and symbol of the token. The decimals cannot be changed.
+    ///
+    /// # Arguments
+    /// * `caller` - The owner or a minter of the token.
+    /// * `name` - The new name of the token.
+    /// * `symbol` - The new symbol of the token.
+    ///
+    /// # Errors
+    /// - [`ContractError::NotOwnerOrMinter`]: If the caller is neither the owner nor a minter.
+    /// - [`ContractError::InvalidTokenName`]: If the name is empty, longer than 32 characters, or not ASCII.
+    /// - [`ContractError::InvalidTokenSymbol`]: If the symbol is empty, longer than 32 characters, or not ASCII.
+    ///
+    /// # Authorization
+    /// - The `caller` must authorize.
+    fn update_metadata(
+        env: &Env,
+        caller: Address,
+        name: String,
+        symbol: String,
+    ) -> Result<(), ContractError>;
 }
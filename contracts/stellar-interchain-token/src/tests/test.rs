@@ -5,11 +5,14 @@ use soroban_token_sdk::metadata::TokenMetadata;
 use stellar_axelar_std::events::{fmt_emitted_event_at_idx, fmt_last_emitted_event};
 use stellar_axelar_std::interfaces::OwnershipTransferredEvent;
 use stellar_axelar_std::testutils::{Address as _, BytesN as _, Ledger};
-use stellar_axelar_std::{assert_auth, assert_auth_err, Address, BytesN, Env, IntoVal as _};
+use stellar_axelar_std::{
+    assert_auth, assert_auth_err, assert_contract_err, Address, BytesN, Env, IntoVal as _, String,
+};
 
+use crate::error::ContractError;
 use crate::event::{
-    ApproveEvent, BurnEvent, MintEvent, MinterAddedEvent, MinterRemovedEvent, SetAdminEvent,
-    TransferEvent,
+    ApproveEvent, BurnEvent, MetadataUpdatedEvent, MintEvent, MinterAddedEvent, MinterRemovedEvent,
+    SetAdminEvent, TransferEvent,
 };
 use crate::{InterchainToken, InterchainTokenClient};
 
@@ -722,3 +725,86 @@ fn allowance_preserves_expiration_when_expired() {
         .mock_all_auths()
         .approve(&user1, &user2, &amount, &expiration_ledger);
 }
+
+#[test]
+fn update_metadata_succeeds_by_minter() {
+    let env = Env::default();
+    let (token, minter) = setup_token(&env);
+    let name: String = "new name".into_val(&env);
+    let symbol: String = "NEW".into_val(&env);
+
+    assert_auth!(minter, token.update_metadata(&minter, &name, &symbol));
+
+    goldie::assert!(fmt_last_emitted_event::<MetadataUpdatedEvent>(&env));
+
+    assert_eq!(token.name(), name);
+    assert_eq!(token.symbol(), symbol);
+    assert_eq!(token.decimals(), 6);
+}
+
+#[test]
+fn update_metadata_succeeds_by_owner() {
+    let env = Env::default();
+    let (token, _) = setup_token(&env);
+    let owner = token.owner();
+    let name: String = "new name".into_val(&env);
+    let symbol: String = "NEW".into_val(&env);
+
+    assert_auth!(owner, token.update_metadata(&owner, &name, &symbol));
+
+    assert_eq!(token.name(), name);
+    assert_eq!(token.symbol(), symbol);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #9)")] // NotOwnerOrMinter
+fn update_metadata_fails_if_not_owner_or_minter() {
+    let env = Env::default();
+    let (token, _) = setup_token(&env);
+
+    token.mock_all_auths().update_metadata(
+        &Address::generate(&env),
+        &"new name".into_val(&env),
+        &"NEW".into_val(&env),
+    );
+}
+
+#[test]
+fn update_metadata_fails_with_invalid_name() {
+    let env = Env::default();
+    let (token, minter) = setup_token(&env);
+    let symbol: String = "NEW".into_val(&env);
+
+    for name in [
+        "",
+        "a name that is longer than thirty-two characters",
+        "nàme",
+    ] {
+        assert_contract_err!(
+            token
+                .mock_all_auths()
+                .try_update_metadata(&minter, &name.into_val(&env), &symbol),
+            ContractError::InvalidTokenName
+        );
+    }
+}
+
+#[test]
+fn update_metadata_fails_with_invalid_symbol() {
+    let env = Env::default();
+    let (token, minter) = setup_token(&env);
+    let name: String = "new name".into_val(&env);
+
+    for symbol in [
+        "",
+        "A SYMBOL THAT IS LONGER THAN THIRTY-TWO CHARACTERS",
+        "SŸM",
+    ] {
+        assert_contract_err!(
+            token
+                .mock_all_auths()
+                .try_update_metadata(&minter, &name, &symbol.into_val(&env)),
+            ContractError::InvalidTokenSymbol
+        );
+    }
+}
@@ -32,6 +32,7 @@ pub use soroban_sdk::testutils::*;
 ///         destination_address,
 ///         payload,
 ///         &user,
+///         &user,
 ///         gas_token,
 ///         &Bytes::new(&env)
 ///     ),